@@ -0,0 +1,5 @@
+//! 命令行工具入口，用法见 `json-tool --help`
+
+fn main() {
+    std::process::exit(json_tool_core::cli::run_from_env());
+}
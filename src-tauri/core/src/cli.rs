@@ -0,0 +1,899 @@
+//! 命令行模式
+//! 提供与桌面应用相同的验证、格式化、压缩、转换和批量处理规则，供 git 钩子和 CI 使用。
+//! 未指定文件时读取标准输入；结果写入标准输出，诊断信息写入标准错误
+
+use crate::models::analysis::{AnalysisOptions, AnalysisResult};
+use crate::models::batch::{BatchFileResult, BatchOperation, BatchOptions};
+use crate::models::conversion::ConversionResult;
+use crate::models::file::{EncodingOptions, WriteOptions};
+use crate::models::formatting::{FormattingOptions, FormattingResult};
+use crate::models::validation::{ValidationOptions, ValidationResult};
+use crate::services::encoding::{self, DecodedText};
+use crate::services::operation::{OperationContext, CANCELLED_MESSAGE};
+use crate::services::{
+    batch, csv_converter, file_io, json_analyzer, json_formatter, json_parser, linter,
+    toml_converter, xml_converter, yaml_converter,
+};
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// 退出码：成功
+pub const EXIT_OK: i32 = 0;
+/// 退出码：JSON 无效、格式不一致或批量处理中有文件失败
+pub const EXIT_INVALID: i32 = 1;
+/// 退出码：命令行参数错误
+pub const EXIT_USAGE: i32 = 2;
+/// 退出码：读写文件失败
+pub const EXIT_IO: i32 = 3;
+
+/// 表示标准输入的文件名
+const STDIN: &str = "-";
+
+const HELP: &str = "\
+json-tool - JSON 验证、格式化与转换 (与桌面应用使用相同的规则)
+
+用法:
+  json-tool validate [选项] [文件...]        验证 JSON
+  json-tool format [选项] [文件...]          格式化 JSON
+  json-tool minify [选项] [文件...]          压缩 JSON
+  json-tool convert (--from <格式> | --to <格式>) [文件]
+                                             在 JSON 与 YAML/TOML/CSV/XML 之间转换
+  json-tool analyze [文件]                   输出结构分析结果 (JSON)
+  json-tool batch <validate|format|minify> <文件夹>
+                                             并行处理文件夹中匹配的文件
+
+未指定文件或文件为 - 时读取标准输入。
+
+选项:
+  -w, --write                   原地覆盖文件 (保留原编码，内容未变化时不写入)
+      --check                   只检查文件是否已格式化，不一致时退出码为 1
+  -o, --output <文件>           将结果写入文件而不是标准输出
+      --indent <2|4>            缩进空格数 (默认 2)
+      --no-trailing-newline     格式化输出末尾不添加换行
+      --ascii-only              将非 ASCII 字符转义为 \\uXXXX
+      --escape-slash            将 / 转义为 \\/
+      --escape-html             转义 <、>、& 以及 U+2028/U+2029
+      --uppercase-hex           \\uXXXX 转义使用大写十六进制
+      --duplicate-keys <策略>   重复键: error、keep-first、keep-last (默认)
+      --strict <模式>           严格模式: off (默认)、rfc8259、ijson
+      --lint                    从文件所在目录向上查找 .jsonlint.json 并检查风格 (batch 不支持)
+      --lint-config <文件>      使用指定的风格检查配置
+      --deny-warnings           存在重复键或风格警告时退出码为 1
+      --from <格式>             转换的输入格式: yaml、toml、csv、xml (输出为 JSON)
+      --to <格式>               转换的输出格式: yaml、toml、csv、xml (输入为 JSON)
+      --options <JSON>          转换或分析选项 (与桌面应用的选项结构相同)
+      --pattern <glob>          批量处理包含的文件，可重复 (默认 **/*.json)
+      --exclude <glob>          批量处理排除的文件或目录，可重复
+      --output-dir <文件夹>     批量处理的输出文件夹 (默认原地覆盖)
+      --threads <数量>          批量处理的线程数 (默认 CPU 核数)
+      --json                    以 JSON 输出批量处理报告
+  -q, --quiet                   不输出成功信息
+  -h, --help                    显示帮助
+  -V, --version                 显示版本
+
+退出码:
+  0  成功
+  1  JSON 无效、格式不一致或有文件处理失败
+  2  参数错误
+  3  读写文件失败
+";
+
+/// 使用进程的命令行参数和标准输入输出运行
+///
+/// # Returns
+/// 进程退出码
+pub fn run_from_env() -> i32 {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    run(
+        &args,
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock(),
+    )
+}
+
+/// 运行命令
+///
+/// # Arguments
+/// * `args` - 命令行参数 (不含程序名)
+/// * `stdin` / `stdout` / `stderr` - 标准输入输出
+///
+/// # Returns
+/// 进程退出码，见 [`EXIT_OK`] 等常量
+pub fn run(
+    args: &[String],
+    stdin: &mut dyn Read,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> i32 {
+    let mut io = Io {
+        stdin,
+        stdout,
+        stderr,
+    };
+    let result = parse_args(args).and_then(|args| execute(&args, &mut io));
+    let code = match result {
+        Ok(code) => code,
+        Err(failure) => {
+            io.diagnostic(format!("错误: {}", failure.message));
+            if failure.code == EXIT_USAGE {
+                io.diagnostic("使用 json-tool --help 查看用法");
+            }
+            failure.code
+        }
+    };
+    let _ = io.stdout.flush();
+    code
+}
+
+/// 命令执行失败的退出码和消息
+#[derive(Debug)]
+struct Failure {
+    code: i32,
+    message: String,
+}
+
+impl Failure {
+    fn usage(message: impl Into<String>) -> Self {
+        Self {
+            code: EXIT_USAGE,
+            message: message.into(),
+        }
+    }
+
+    fn invalid(message: impl Into<String>) -> Self {
+        Self {
+            code: EXIT_INVALID,
+            message: message.into(),
+        }
+    }
+
+    fn io(message: impl Into<String>) -> Self {
+        Self {
+            code: EXIT_IO,
+            message: message.into(),
+        }
+    }
+}
+
+struct Io<'a> {
+    stdin: &'a mut dyn Read,
+    stdout: &'a mut dyn Write,
+    stderr: &'a mut dyn Write,
+}
+
+impl Io<'_> {
+    /// 向标准输出写入结果
+    fn print(&mut self, text: &str) -> Result<(), Failure> {
+        self.stdout
+            .write_all(text.as_bytes())
+            .map_err(|e| Failure::io(format!("写入标准输出失败: {}", e)))
+    }
+
+    /// 向标准错误写入一行诊断信息 (写入失败时忽略)
+    fn diagnostic(&mut self, message: impl Display) {
+        let _ = writeln!(self.stderr, "{}", message);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Help,
+    Version,
+    Validate,
+    Format,
+    Minify,
+    Convert,
+    Analyze,
+    Batch(BatchOperation),
+}
+
+/// 解析后的命令行参数
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    inputs: Vec<String>,
+    write: bool,
+    check: bool,
+    output: Option<String>,
+    formatting: FormattingOptions,
+    validation: ValidationOptions,
+    lint: bool,
+    lint_config: Option<String>,
+    deny_warnings: bool,
+    from: Option<String>,
+    to: Option<String>,
+    options: Option<String>,
+    patterns: Vec<String>,
+    exclude: Vec<String>,
+    output_dir: Option<String>,
+    threads: Option<usize>,
+    json: bool,
+    quiet: bool,
+}
+
+impl Args {
+    fn new(command: Command) -> Self {
+        Self {
+            command,
+            inputs: Vec::new(),
+            write: false,
+            check: false,
+            output: None,
+            formatting: FormattingOptions::default(),
+            validation: ValidationOptions::default(),
+            lint: false,
+            lint_config: None,
+            deny_warnings: false,
+            from: None,
+            to: None,
+            options: None,
+            patterns: Vec::new(),
+            exclude: Vec::new(),
+            output_dir: None,
+            threads: None,
+            json: false,
+            quiet: false,
+        }
+    }
+
+    /// 输入文件，未指定时为标准输入
+    fn input_paths(&self) -> Vec<&str> {
+        if self.inputs.is_empty() {
+            vec![STDIN]
+        } else {
+            self.inputs.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// 只接受一个输入的命令使用的输入文件
+    fn single_input(&self) -> Result<&str, Failure> {
+        match self.input_paths()[..] {
+            [path] => Ok(path),
+            _ => Err(Failure::usage("该命令只接受一个输入文件")),
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Failure> {
+    let mut iter = args.iter();
+    let command = match iter.next().map(String::as_str) {
+        None | Some("help" | "-h" | "--help") => return Ok(Args::new(Command::Help)),
+        Some("-V" | "--version") => return Ok(Args::new(Command::Version)),
+        Some("validate") => Command::Validate,
+        Some("format") => Command::Format,
+        Some("minify") => Command::Minify,
+        Some("convert") => Command::Convert,
+        Some("analyze") => Command::Analyze,
+        Some("batch") => {
+            let operation = iter
+                .next()
+                .ok_or_else(|| Failure::usage("batch 需要指定操作: validate、format 或 minify"))?;
+            Command::Batch(parse_enum("batch", operation)?)
+        }
+        Some(other) => return Err(Failure::usage(format!("未知的命令 \"{}\"", other))),
+    };
+
+    let mut parsed = Args::new(command);
+    let mut options_ended = false;
+    while let Some(arg) = iter.next() {
+        if options_ended || arg == STDIN || !arg.starts_with('-') {
+            parsed.inputs.push(arg.clone());
+            continue;
+        }
+
+        // 支持 `--name=value` 形式
+        let (flag, mut inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline
+                .take()
+                .or_else(|| iter.next().cloned())
+                .ok_or_else(|| Failure::usage(format!("{} 需要一个值", flag)))
+        };
+
+        match flag {
+            "--" => options_ended = true,
+            "-h" | "--help" => parsed.command = Command::Help,
+            "-w" | "--write" => parsed.write = true,
+            "--check" => parsed.check = true,
+            "-o" | "--output" => parsed.output = Some(value()?),
+            "--indent" => {
+                parsed.formatting.indent = match value()?.as_str() {
+                    "2" => 2,
+                    "4" => 4,
+                    other => {
+                        return Err(Failure::usage(format!(
+                            "不支持的缩进值 \"{}\"，仅支持 2 或 4",
+                            other
+                        )))
+                    }
+                }
+            }
+            "--no-trailing-newline" => parsed.formatting.trailing_newline = false,
+            "--ascii-only" => parsed.formatting.escape.ascii_only = true,
+            "--escape-slash" => parsed.formatting.escape.escape_slash = true,
+            "--escape-html" => parsed.formatting.escape.escape_html = true,
+            "--uppercase-hex" => parsed.formatting.escape.uppercase_hex = true,
+            "--duplicate-keys" => {
+                let policy = parse_enum(flag, &value()?)?;
+                parsed.formatting.duplicate_keys = policy;
+                parsed.validation.duplicate_keys = policy;
+            }
+            "--strict" => parsed.validation.strict = parse_enum(flag, &value()?)?,
+            "--lint" => parsed.lint = true,
+            "--lint-config" => parsed.lint_config = Some(value()?),
+            "--deny-warnings" => parsed.deny_warnings = true,
+            "--from" => parsed.from = Some(value()?.to_lowercase()),
+            "--to" => parsed.to = Some(value()?.to_lowercase()),
+            "--options" => parsed.options = Some(value()?),
+            "--pattern" => parsed.patterns.push(value()?),
+            "--exclude" => parsed.exclude.push(value()?),
+            "--output-dir" => parsed.output_dir = Some(value()?),
+            "--threads" => {
+                let text = value()?;
+                let threads = text
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| Failure::usage(format!("无效的线程数 \"{}\"", text)))?;
+                parsed.threads = Some(threads);
+            }
+            "--json" => parsed.json = true,
+            "-q" | "--quiet" => parsed.quiet = true,
+            _ => return Err(Failure::usage(format!("未知的选项 \"{}\"", flag))),
+        }
+        if inline.is_some() {
+            return Err(Failure::usage(format!("{} 不接受值", flag)));
+        }
+    }
+    Ok(parsed)
+}
+
+/// 按 serde 名称解析枚举选项 (`-` 与 `_` 等价，如 `keep-first`)
+fn parse_enum<T: DeserializeOwned>(flag: &str, value: &str) -> Result<T, Failure> {
+    let name = serde_json::Value::String(value.replace('-', "_"));
+    serde_json::from_value(name)
+        .map_err(|_| Failure::usage(format!("{} 的值 \"{}\" 无效", flag, value)))
+}
+
+fn execute(args: &Args, io: &mut Io) -> Result<i32, Failure> {
+    match args.command {
+        Command::Help => io.print(HELP).map(|_| EXIT_OK),
+        Command::Version => io
+            .print(&format!("json-tool {}\n", env!("CARGO_PKG_VERSION")))
+            .map(|_| EXIT_OK),
+        Command::Validate => validate(args, io),
+        Command::Format | Command::Minify => format(args, io),
+        Command::Convert => convert(args, io),
+        Command::Analyze => analyze(args, io),
+        Command::Batch(operation) => run_batch(args, operation, io),
+    }
+}
+
+/// 读取的输入
+struct Input {
+    /// 诊断信息中显示的名称
+    name: String,
+    /// 文件路径 (标准输入为空)
+    path: Option<PathBuf>,
+    text: DecodedText,
+}
+
+/// 读取文件或标准输入，自动识别编码
+fn read_input(path: &str, stdin: &mut dyn Read) -> Result<Input, Failure> {
+    let (name, path, bytes) = if path == STDIN {
+        let mut bytes = Vec::new();
+        stdin
+            .read_to_end(&mut bytes)
+            .map_err(|e| Failure::io(format!("读取标准输入失败: {}", e)))?;
+        ("<stdin>".to_string(), None, bytes)
+    } else {
+        let bytes =
+            fs::read(path).map_err(|e| Failure::io(format!("无法读取文件 {}: {}", path, e)))?;
+        (path.to_string(), Some(PathBuf::from(path)), bytes)
+    };
+    let text =
+        encoding::decode(&bytes).map_err(|e| Failure::invalid(format!("{}: {}", name, e)))?;
+    Ok(Input { name, path, text })
+}
+
+/// 依次处理每个输入；单个输入失败时输出错误并继续，返回最严重的退出码
+fn for_each_input(
+    args: &Args,
+    io: &mut Io,
+    mut process: impl FnMut(&Input, &mut Io) -> Result<i32, Failure>,
+) -> Result<i32, Failure> {
+    let mut code = EXIT_OK;
+    for path in args.input_paths() {
+        let result = read_input(path, &mut *io.stdin).and_then(|input| process(&input, io));
+        let input_code = match result {
+            Ok(input_code) => input_code,
+            Err(failure) => {
+                io.diagnostic(&failure.message);
+                failure.code
+            }
+        };
+        code = code.max(input_code);
+    }
+    Ok(code)
+}
+
+/// 诊断信息中的位置前缀 (`文件:行:列`)
+fn location(name: &str, line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!("{}:{}:{}", name, line, column),
+        (Some(line), None) => format!("{}:{}", name, line),
+        _ => name.to_string(),
+    }
+}
+
+fn validate(args: &Args, io: &mut Io) -> Result<i32, Failure> {
+    let lint_config = match &args.lint_config {
+        Some(path) => Some(linter::load_config(Path::new(path)).map_err(Failure::usage)?),
+        None => None,
+    };
+
+    for_each_input(args, io, |input, io| {
+        let mut options = args.validation.clone();
+        options.lint = match &lint_config {
+            Some(config) => Some(config.clone()),
+            None if args.lint => {
+                let start = input.path.as_ref().map_or_else(
+                    || ".".to_string(),
+                    |path| path.to_string_lossy().to_string(),
+                );
+                linter::find_config(&start)
+                    .map_err(|e| Failure::usage(format!("{}: {}", input.name, e)))?
+                    .map(|file| file.config)
+            }
+            None => None,
+        };
+
+        let result = json_parser::validate_json_with_context(
+            &input.text.content,
+            &options,
+            &OperationContext::detached(),
+        );
+        match result {
+            ValidationResult::Success {
+                duplicate_keys,
+                warnings,
+                ..
+            } => {
+                for key in &duplicate_keys {
+                    io.diagnostic(format!(
+                        "{}: 警告: 重复的键 \"{}\" (第一次出现在第 {} 行第 {} 列)",
+                        location(&input.name, Some(key.line), Some(key.column)),
+                        key.key,
+                        key.first_line,
+                        key.first_column
+                    ));
+                }
+                for warning in &warnings {
+                    let pointer = if warning.pointer.is_empty() {
+                        "根"
+                    } else {
+                        warning.pointer.as_str()
+                    };
+                    io.diagnostic(format!(
+                        "{}: 警告: {} (位置 {})",
+                        input.name, warning.message, pointer
+                    ));
+                }
+                if !args.quiet {
+                    io.diagnostic(format!("{}: 有效", input.name));
+                }
+                let warned = !duplicate_keys.is_empty() || !warnings.is_empty();
+                Ok(if args.deny_warnings && warned {
+                    EXIT_INVALID
+                } else {
+                    EXIT_OK
+                })
+            }
+            ValidationResult::Error {
+                message,
+                line,
+                column,
+            } => {
+                io.diagnostic(format!(
+                    "{}: {}",
+                    location(&input.name, line, column),
+                    message
+                ));
+                Ok(EXIT_INVALID)
+            }
+            ValidationResult::Cancelled => Err(Failure::invalid(CANCELLED_MESSAGE)),
+        }
+    })
+}
+
+fn format(args: &Args, io: &mut Io) -> Result<i32, Failure> {
+    let paths = args.input_paths();
+    if args.write && args.check {
+        return Err(Failure::usage("--write 和 --check 不能同时使用"));
+    }
+    if args.write && paths.contains(&STDIN) {
+        return Err(Failure::usage("--write 不能用于标准输入"));
+    }
+    if paths.len() > 1 && !args.write && !args.check {
+        return Err(Failure::usage("处理多个文件时需要使用 --write 或 --check"));
+    }
+    if args.output.is_some() && (args.write || args.check) {
+        return Err(Failure::usage(
+            "--output 不能与 --write 或 --check 同时使用",
+        ));
+    }
+
+    let minify = args.command == Command::Minify;
+    for_each_input(args, io, |input, io| {
+        let content = &input.text.content;
+        let result = if minify {
+            json_formatter::minify_json(
+                content,
+                &args.formatting.escape,
+                args.formatting.duplicate_keys,
+            )
+        } else {
+            json_formatter::format_json(content, &args.formatting)
+        };
+        let formatted = match result {
+            FormattingResult::Success { formatted, .. } => formatted,
+            FormattingResult::Error { message } => {
+                return Err(Failure::invalid(format!("{}: {}", input.name, message)))
+            }
+            FormattingResult::Cancelled => return Err(Failure::invalid(CANCELLED_MESSAGE)),
+        };
+
+        if args.check {
+            if formatted == *content {
+                return Ok(EXIT_OK);
+            }
+            io.diagnostic(format!("{}: 格式不一致", input.name));
+            return Ok(EXIT_INVALID);
+        }
+        if let (true, Some(path)) = (args.write, &input.path) {
+            if formatted != *content {
+                let encoding = EncodingOptions {
+                    encoding: input.text.encoding,
+                    bom: input.text.bom,
+                };
+                write_file(path, &formatted, &encoding)?;
+                if !args.quiet {
+                    io.diagnostic(format!("已更新: {}", input.name));
+                }
+            }
+            return Ok(EXIT_OK);
+        }
+        emit(args, &formatted, io).map(|_| EXIT_OK)
+    })
+}
+
+fn convert(args: &Args, io: &mut Io) -> Result<i32, Failure> {
+    let direction = match (args.from.as_deref(), args.to.as_deref()) {
+        (Some(from), None | Some("json")) if from != "json" => (from, "json"),
+        (None | Some("json"), Some(to)) if to != "json" => ("json", to),
+        (None, None) => return Err(Failure::usage("convert 需要指定 --from 或 --to")),
+        _ => return Err(Failure::usage("--from 和 --to 必须有一个是 json")),
+    };
+    let options = args.options.as_deref().unwrap_or("{}");
+    let input = read_input(args.single_input()?, io.stdin)?;
+    let content = &input.text.content;
+
+    let result = match direction {
+        ("json", "yaml") => yaml_converter::json_to_yaml(content, &parse_options(options)?),
+        ("yaml", "json") => yaml_converter::yaml_to_json(content, &parse_options(options)?),
+        ("json", "toml") => toml_converter::json_to_toml(content, &parse_options(options)?),
+        ("toml", "json") => toml_converter::toml_to_json(content, &parse_options(options)?),
+        ("json", "csv") => csv_converter::json_to_csv(content, &parse_options(options)?),
+        ("csv", "json") => csv_converter::csv_to_json(content, &parse_options(options)?),
+        ("json", "xml") => xml_converter::json_to_xml(content, &parse_options(options)?),
+        ("xml", "json") => xml_converter::xml_to_json(content, &parse_options(options)?),
+        (from, to) => {
+            return Err(Failure::usage(format!(
+                "不支持从 {} 转换为 {}，支持的格式: yaml、toml、csv、xml",
+                from, to
+            )))
+        }
+    };
+
+    match result {
+        ConversionResult::Success {
+            output, warnings, ..
+        } => {
+            for warning in warnings {
+                io.diagnostic(format!("{}: 警告: {}", input.name, warning));
+            }
+            emit(args, &output, io).map(|_| EXIT_OK)
+        }
+        ConversionResult::Error {
+            message,
+            line,
+            column,
+        } => Err(Failure::invalid(format!(
+            "{}: {}",
+            location(&input.name, line, column),
+            message
+        ))),
+    }
+}
+
+fn analyze(args: &Args, io: &mut Io) -> Result<i32, Failure> {
+    let options: AnalysisOptions = parse_options(args.options.as_deref().unwrap_or("{}"))?;
+    let input = read_input(args.single_input()?, io.stdin)?;
+    let result = json_analyzer::analyze_json(&input.text.content, &options);
+    let code = match &result {
+        AnalysisResult::Success { .. } => EXIT_OK,
+        AnalysisResult::Error { .. } => EXIT_INVALID,
+    };
+    emit(args, &to_json(&result)?, io).map(|_| code)
+}
+
+fn run_batch(args: &Args, operation: BatchOperation, io: &mut Io) -> Result<i32, Failure> {
+    let root = match &args.inputs[..] {
+        [root] if root != STDIN => root.clone(),
+        _ => return Err(Failure::usage("batch 需要指定一个文件夹")),
+    };
+    if args.lint {
+        return Err(Failure::usage(
+            "batch 不支持 --lint，请使用 --lint-config 指定风格检查配置",
+        ));
+    }
+    let mut validation = args.validation.clone();
+    if let Some(path) = &args.lint_config {
+        validation.lint = Some(linter::load_config(Path::new(path)).map_err(Failure::usage)?);
+    }
+    let options = BatchOptions {
+        root,
+        patterns: args.patterns.clone(),
+        exclude: args.exclude.clone(),
+        operation,
+        output_dir: args.output_dir.clone(),
+        formatting: args.formatting.clone(),
+        validation,
+        threads: args.threads,
+    };
+    // 选项错误属于参数错误，其余 (文件夹不存在或无法读取等) 属于读写失败
+    batch::check_options(&options).map_err(Failure::usage)?;
+    let report = batch::batch_process(&options, &OperationContext::detached())
+        .map_err(|e| Failure::io(e.to_string()))?;
+
+    if args.json {
+        io.print(&to_json(&report)?)?;
+    } else {
+        for file in &report.files {
+            match file {
+                BatchFileResult::Error {
+                    path,
+                    message,
+                    line,
+                    column,
+                } => io.diagnostic(format!("{}: {}", location(path, *line, *column), message)),
+                BatchFileResult::Success {
+                    path,
+                    output_path: Some(_),
+                    ..
+                } if !args.quiet => io.diagnostic(format!("已更新: {}", path)),
+                BatchFileResult::Success { .. } => {}
+            }
+        }
+        if !args.quiet {
+            io.diagnostic(format!(
+                "共 {} 个文件: 成功 {}，失败 {}，内容变化 {} ({} ms)",
+                report.total,
+                report.succeeded,
+                report.failed,
+                report.changed,
+                report.processing_time_ms
+            ));
+        }
+    }
+    Ok(if report.failed > 0 {
+        EXIT_INVALID
+    } else {
+        EXIT_OK
+    })
+}
+
+/// 解析 `--options` 中的 JSON 选项，未指定的字段使用默认值
+fn parse_options<T: DeserializeOwned>(text: &str) -> Result<T, Failure> {
+    serde_json::from_str(text).map_err(|e| Failure::usage(format!("--options 无效: {}", e)))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, Failure> {
+    serde_json::to_string_pretty(value)
+        .map(|json| json + "\n")
+        .map_err(|e| Failure::io(format!("序列化失败: {}", e)))
+}
+
+/// 输出结果：指定了 `--output` 时写入文件 (UTF-8)，否则写入标准输出
+fn emit(args: &Args, text: &str, io: &mut Io) -> Result<(), Failure> {
+    match &args.output {
+        Some(path) => write_file(Path::new(path), text, &EncodingOptions::default()),
+        None => io.print(text),
+    }
+}
+
+fn write_file(path: &Path, text: &str, encoding: &EncodingOptions) -> Result<(), Failure> {
+    let bytes = encoding::encode(text, encoding);
+    file_io::write_atomic(path, &bytes, &WriteOptions::default())
+        .map(|_| ())
+        .map_err(Failure::io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::file::TextEncoding;
+
+    /// 运行命令，返回退出码、标准输出和标准错误
+    fn run_cli(args: &[&str], stdin: &str) -> (i32, String, String) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        let code = run(&args, &mut stdin.as_bytes(), &mut stdout, &mut stderr);
+        (
+            code,
+            String::from_utf8(stdout).unwrap(),
+            String::from_utf8(stderr).unwrap(),
+        )
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cli-test-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_stdin_to_stdout() {
+        let (code, stdout, _) = run_cli(&["format", "--indent", "4"], "{\"a\":[1]}");
+        assert_eq!(code, EXIT_OK);
+        assert_eq!(stdout, "{\n    \"a\": [\n        1\n    ]\n}\n");
+
+        let (code, stdout, _) = run_cli(&["minify", "--ascii-only", "-"], "{ \"a\": \"é\" }");
+        assert_eq!(code, EXIT_OK);
+        assert_eq!(stdout, "{\"a\":\"\\u00e9\"}");
+
+        let (code, _, stderr) = run_cli(&["validate"], "{\"a\": 1,}");
+        assert_eq!(code, EXIT_INVALID);
+        assert!(stderr.starts_with("<stdin>:1:9: "), "{}", stderr);
+
+        let (code, _, stderr) = run_cli(
+            &["validate", "--duplicate-keys=keep-first", "--deny-warnings"],
+            "{\"a\": 1, \"a\": 2}",
+        );
+        assert_eq!(code, EXIT_INVALID);
+        assert!(stderr.contains("重复的键 \"a\""));
+
+        // 压缩同样按 --duplicate-keys 处理重复键
+        let (code, _, stderr) = run_cli(
+            &["minify", "--duplicate-keys", "error"],
+            "{\"a\": 1, \"a\": 2}",
+        );
+        assert_eq!(code, EXIT_INVALID);
+        assert!(stderr.contains("重复的键 \"a\""), "{}", stderr);
+        let (code, stdout, _) = run_cli(
+            &["minify", "--duplicate-keys=keep-first"],
+            "{\"a\": 1, \"a\": 2}",
+        );
+        assert_eq!(code, EXIT_OK);
+        assert_eq!(stdout, "{\"a\":1}");
+    }
+
+    #[test]
+    fn test_check_and_write_files() {
+        let dir = temp_dir("files");
+        let formatted = dir.join("formatted.json");
+        let compact = dir.join("compact.json");
+        fs::write(&formatted, "{\n  \"a\": 1\n}\n").unwrap();
+        let utf16 = EncodingOptions {
+            encoding: TextEncoding::Utf16Le,
+            bom: true,
+        };
+        fs::write(&compact, encoding::encode("{\"a\":1}", &utf16)).unwrap();
+        let paths = [formatted.to_str().unwrap(), compact.to_str().unwrap()];
+
+        let (code, _, stderr) = run_cli(&["format", "--check", paths[0], paths[1]], "");
+        assert_eq!(code, EXIT_INVALID);
+        assert!(stderr.contains("compact.json: 格式不一致") && !stderr.contains("formatted"));
+
+        let (code, _, _) = run_cli(&["format", "-w", "-q", paths[0], paths[1]], "");
+        assert_eq!(code, EXIT_OK);
+        // 保留原文件的编码和 BOM
+        let decoded = encoding::decode(&fs::read(&compact).unwrap()).unwrap();
+        assert_eq!(decoded.content, "{\n  \"a\": 1\n}\n");
+        assert_eq!(
+            (decoded.encoding, decoded.bom),
+            (TextEncoding::Utf16Le, true)
+        );
+        assert_eq!(
+            run_cli(&["format", "--check", paths[0], paths[1]], "").0,
+            EXIT_OK
+        );
+
+        // 文件不存在时继续处理其他文件，返回最严重的退出码
+        let missing = dir.join("missing.json");
+        let (code, _, stderr) = run_cli(&["validate", paths[0], missing.to_str().unwrap()], "");
+        assert_eq!(code, EXIT_IO);
+        assert!(stderr.contains("formatted.json: 有效") && stderr.contains("无法读取文件"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_convert_and_analyze() {
+        let (code, stdout, _) = run_cli(&["convert", "--to", "yaml"], "{\"a\": [1, 2]}");
+        assert_eq!(code, EXIT_OK);
+        assert!(stdout.contains("a:") && stdout.contains("- 1"));
+
+        let (code, stdout, _) = run_cli(
+            &["convert", "--from", "yaml", "--options", "{\"indent\": 4}"],
+            "a: 1\n",
+        );
+        assert_eq!(code, EXIT_OK);
+        assert_eq!(stdout.trim_end(), "{\n    \"a\": 1\n}");
+
+        let (code, stdout, _) = run_cli(&["analyze"], "[[1], {\"a\": true}]");
+        assert_eq!(code, EXIT_OK);
+        let result: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(result["type"], "Success");
+        assert_eq!(result["max_depth"], 2);
+    }
+
+    #[test]
+    fn test_batch() {
+        let dir = temp_dir("batch");
+        fs::write(dir.join("ok.json"), "[1]").unwrap();
+        fs::write(dir.join("bad.json"), "[1,]").unwrap();
+        let root = dir.to_str().unwrap();
+
+        let (code, stdout, _) = run_cli(&["batch", "validate", root, "--json"], "");
+        assert_eq!(code, EXIT_INVALID);
+        let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(
+            (report["total"].as_u64(), report["failed"].as_u64()),
+            (Some(2), Some(1))
+        );
+
+        let (code, _, stderr) = run_cli(&["batch", "minify", root, "--exclude", "bad.json"], "");
+        assert_eq!(code, EXIT_OK);
+        assert!(stderr.contains("共 1 个文件"));
+
+        // 文件夹不存在属于读写失败
+        let missing = dir.join("missing");
+        let (code, _, stderr) = run_cli(&["batch", "validate", missing.to_str().unwrap()], "");
+        assert_eq!(code, EXIT_IO);
+        assert!(stderr.contains("文件夹不存在") && !stderr.contains("--help"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        for args in [
+            &["frobnicate"][..],
+            &["format", "--bogus"],
+            &["format", "--indent", "3"],
+            &["format", "--write"],
+            &["format", "--check=yes"],
+            &["validate", "--strict", "loose"],
+            &["convert", "--from", "yaml", "--to", "toml"],
+            &["batch", "validate"],
+            &["batch", "validate", ".", "--lint"],
+            &["batch", "validate", ".", "--pattern", "["],
+        ] {
+            let (code, _, stderr) = run_cli(args, "{}");
+            assert_eq!(code, EXIT_USAGE, "{:?}", args);
+            assert!(stderr.contains("--help"), "{:?}", args);
+        }
+
+        let (code, stdout, _) = run_cli(&[], "");
+        assert_eq!(code, EXIT_OK);
+        assert!(stdout.contains("退出码"));
+    }
+}
@@ -0,0 +1,45 @@
+//! JSON 工具核心库
+//!
+//! 桌面应用和 `json-tool` 命令行共用的验证、格式化、转换与文件处理逻辑，不依赖 Tauri，
+//! 其他 Rust 程序可以直接使用，得到与桌面应用完全相同的结果。
+//!
+//! - [`models`]：各服务的选项和结果类型，均可通过 serde 序列化 (与前端的 TypeScript 类型一一对应)
+//! - [`services`]：验证、格式化、格式转换、编码识别、文件读写、批量处理等服务
+//! - [`cli`]：`json-tool` 命令行的实现
+//!
+//! 常用的函数和类型在 crate 根部重新导出：
+//!
+//! ```
+//! use json_tool_core::{format_json, validate_json, FormattingOptions, FormattingResult, ValidationResult};
+//!
+//! assert!(matches!(validate_json(r#"{"a": 1}"#), ValidationResult::Success { .. }));
+//!
+//! match validate_json(r#"{"a": 1,}"#) {
+//!     ValidationResult::Error { line, column, .. } => assert_eq!((line, column), (Some(1), Some(9))),
+//!     other => panic!("{:?}", other),
+//! }
+//!
+//! match format_json(r#"{"a":[1]}"#, &FormattingOptions::default()) {
+//!     FormattingResult::Success { formatted, .. } => {
+//!         assert_eq!(formatted, "{\n  \"a\": [\n    1\n  ]\n}\n");
+//!     }
+//!     other => panic!("{:?}", other),
+//! }
+//! ```
+//!
+//! 耗时较长的操作 (以 `_with_context` 结尾的函数、批量处理等) 接受 [`OperationContext`]，
+//! 用于取消操作和接收进度；不需要时使用 [`OperationContext::detached`]。
+//! 错误以 `Result<_, String>` 或结果枚举的 `Error` 变体返回，消息为面向用户的中文文本；
+//! 可取消的操作被取消时返回结果枚举的 `Cancelled` 变体或 [`OperationError::Cancelled`]
+
+pub mod cli;
+pub mod models;
+pub mod services;
+
+pub use models::formatting::{EscapeOptions, FormattingOptions, FormattingResult};
+pub use models::validation::{DuplicateKeyPolicy, StrictMode, ValidationOptions, ValidationResult};
+pub use services::json_formatter::{
+    format_json, format_json_with_context, minify_json, minify_json_with_context,
+};
+pub use services::json_parser::{validate_json, validate_json_with_context};
+pub use services::operation::{CancellationToken, OperationContext, OperationError};
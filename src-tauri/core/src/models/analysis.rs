@@ -0,0 +1,120 @@
+use crate::models::document::TypeCounts;
+use serde::{Deserialize, Serialize};
+
+/// 结构分析选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisOptions {
+    /// 各排行榜 (最大数组、最长字符串、键、重复路径) 保留的条目数
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+}
+
+impl Default for AnalysisOptions {
+    fn default() -> Self {
+        Self {
+            top_n: default_top_n(),
+        }
+    }
+}
+
+fn default_top_n() -> usize {
+    10
+}
+
+/// 数组长度统计
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArrayStat {
+    /// 数组的 JSON Pointer
+    pub pointer: String,
+    /// 元素数量
+    pub length: usize,
+}
+
+/// 字符串长度统计
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StringStat {
+    /// 字符串的 JSON Pointer
+    pub pointer: String,
+    /// 字符数
+    pub length: usize,
+    /// UTF-8 字节数
+    pub bytes: usize,
+    /// 开头部分的预览
+    pub preview: String,
+}
+
+/// 对象键出现次数统计
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeyStat {
+    /// 键名
+    pub key: String,
+    /// 出现次数
+    pub count: usize,
+}
+
+/// 重复出现的键路径统计
+///
+/// 路径中的数组下标统一替换为 `*`，例如 `/items/*/id`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeyPathStat {
+    /// 归一化后的路径
+    pub path: String,
+    /// 出现次数
+    pub count: usize,
+    /// 该路径的键名在序列化文本中占用的总字节数 (含引号)
+    pub key_bytes: usize,
+}
+
+/// JSON 结构分析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum AnalysisResult {
+    /// 分析成功
+    Success {
+        /// 输入大小（字节）
+        size: usize,
+        /// 最大嵌套深度 (根为对象或数组时为 1，根为标量时为 0)
+        max_depth: usize,
+        /// 节点总数
+        node_count: usize,
+        /// 各类型节点数量
+        type_counts: TypeCounts,
+        /// 对象键总数
+        total_keys: usize,
+        /// 不同键名的数量
+        distinct_keys: usize,
+        /// 出现次数最多的键
+        top_keys: Vec<KeyStat>,
+        /// 元素最多的数组
+        largest_arrays: Vec<ArrayStat>,
+        /// 最长的字符串值
+        longest_strings: Vec<StringStat>,
+        /// 重复次数最多的键路径 (按键名占用字节数排序)
+        repeated_paths: Vec<KeyPathStat>,
+        /// 全部键名在序列化文本中占用的字节数 (含引号)
+        key_bytes: usize,
+        /// 解析为内存中的值后估算占用的字节数
+        estimated_memory_bytes: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+    },
+    /// 分析失败
+    Error {
+        /// 错误消息
+        message: String,
+        /// 错误所在行号
+        line: Option<usize>,
+        /// 错误所在列号
+        column: Option<usize>,
+    },
+}
+
+impl AnalysisResult {
+    pub fn error(message: impl Into<String>) -> Self {
+        AnalysisResult::Error {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::models::formatting::FormattingOptions;
+use crate::models::lint::LintWarning;
+use crate::models::validation::{DuplicateKey, ValidationOptions};
+use serde::{Deserialize, Serialize};
+
+/// 批量处理的操作
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOperation {
+    /// 只验证，不写入文件
+    Validate,
+    /// 格式化
+    Format,
+    /// 压缩
+    Minify,
+}
+
+/// 批量处理选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchOptions {
+    /// 要处理的文件夹
+    pub root: String,
+
+    /// 包含的文件 (相对于 `root` 的 glob 模式，如 `**/*.json`)，为空时处理所有 `.json` 文件
+    #[serde(default)]
+    pub patterns: Vec<String>,
+
+    /// 排除的文件或目录 (glob 模式，如 `node_modules/**`)
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// 操作
+    pub operation: BatchOperation,
+
+    /// 输出文件夹，按相对路径镜像输出；为空时原地覆盖 (验证操作不写入)
+    #[serde(default)]
+    pub output_dir: Option<String>,
+
+    /// 格式化/压缩选项 (压缩只使用其中的转义选项)
+    #[serde(default)]
+    pub formatting: FormattingOptions,
+
+    /// 验证选项
+    #[serde(default)]
+    pub validation: ValidationOptions,
+
+    /// 并行处理的线程数，为空时使用 CPU 核数
+    #[serde(default)]
+    pub threads: Option<usize>,
+}
+
+/// 单个文件的处理结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum BatchFileResult {
+    /// 处理成功
+    Success {
+        /// 相对于 `root` 的路径
+        path: String,
+        /// 写入的文件路径 (验证操作为空)
+        output_path: Option<String>,
+        /// 输出内容是否与原文件不同
+        changed: bool,
+        /// 输入大小 (字节)
+        size: usize,
+        /// 重复键 (按验证选项的策略处理)
+        #[serde(default)]
+        duplicate_keys: Vec<DuplicateKey>,
+        /// 风格检查警告
+        #[serde(default)]
+        lint_warnings: Vec<LintWarning>,
+        /// 读取文件时的警告 (如扩展名不在允许列表中)
+        #[serde(default)]
+        warnings: Vec<String>,
+    },
+    /// 处理失败
+    Error {
+        /// 相对于 `root` 的路径
+        path: String,
+        /// 错误消息
+        message: String,
+        /// 错误行号
+        line: Option<usize>,
+        /// 错误列号
+        column: Option<usize>,
+    },
+}
+
+/// 批量处理报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchReport {
+    /// 各文件的结果 (按路径排序)
+    pub files: Vec<BatchFileResult>,
+    /// 匹配的文件数
+    pub total: usize,
+    /// 成功数
+    pub succeeded: usize,
+    /// 失败数
+    pub failed: usize,
+    /// 内容发生变化的文件数
+    pub changed: usize,
+    /// 处理时间 (毫秒)
+    pub processing_time_ms: u64,
+}
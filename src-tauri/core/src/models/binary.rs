@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// 二进制 JSON 等价格式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryFormat {
+    /// MessagePack
+    Msgpack,
+    /// CBOR (RFC 8949)
+    Cbor,
+    /// BSON (根节点必须是文档)
+    Bson,
+}
+
+/// 二进制数据的文本编码
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryTextEncoding {
+    /// Base64 (解码时同时接受 URL 安全字母表)
+    #[default]
+    Base64,
+    /// 十六进制 (解码时忽略空白和 `0x` 前缀)
+    Hex,
+}
+
+/// 无法用 JSON 精确表示的值的类别
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LossyKind {
+    /// 二进制数据，已转换为 Base64 字符串
+    Binary,
+    /// MessagePack 扩展类型 / CBOR 标签 / BSON 特殊类型
+    Extension,
+    /// 日期时间，已转换为 RFC 3339 字符串
+    Date,
+    /// 超出 64 位范围的整数或高精度小数，已转换为字符串
+    Number,
+    /// NaN 或无穷大，已转换为 null
+    NonFiniteFloat,
+    /// 非字符串的映射键，已转换为字符串
+    Key,
+    /// 描述符中未定义的 Protobuf 字段，已忽略
+    UnknownField,
+}
+
+/// 转换中有损的值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LossyValue {
+    /// 值所在路径 (如 `$.items[0].data`)
+    pub path: String,
+    /// 类别
+    pub kind: LossyKind,
+    /// 说明 (原始类型及转换方式)
+    pub description: String,
+}
+
+/// 二进制解码结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum BinaryDecodeResult {
+    /// 解码成功
+    Success {
+        /// 格式化后的 JSON
+        output: String,
+        /// 二进制输入大小（字节）
+        size: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+        /// 有损转换的值
+        lossy: Vec<LossyValue>,
+    },
+    /// 解码失败
+    Error {
+        /// 错误消息
+        message: String,
+    },
+}
+
+impl BinaryDecodeResult {
+    pub fn error(message: impl Into<String>) -> Self {
+        BinaryDecodeResult::Error {
+            message: message.into(),
+        }
+    }
+}
+
+/// Protobuf 转 JSON 选项 (proto3 规范 JSON 映射)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtobufJsonOptions {
+    /// 使用 .proto 中的原始字段名 (默认使用 lowerCamelCase 的 JSON 名称)
+    #[serde(default)]
+    pub use_proto_field_names: bool,
+
+    /// 枚举输出为数字 (默认输出枚举名)
+    #[serde(default)]
+    pub use_enum_numbers: bool,
+
+    /// 输出值为默认值的字段 (默认省略)
+    #[serde(default)]
+    pub emit_default_fields: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for ProtobufJsonOptions {
+    fn default() -> Self {
+        Self {
+            use_proto_field_names: false,
+            use_enum_numbers: false,
+            emit_default_fields: false,
+            indent: default_indent(),
+        }
+    }
+}
+
+fn default_indent() -> u8 {
+    2
+}
@@ -0,0 +1,478 @@
+use serde::{Deserialize, Serialize};
+
+/// 格式转换结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ConversionResult {
+    /// 转换成功
+    Success {
+        /// 转换后的文本
+        output: String,
+        /// 输出大小（字节）
+        size: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+        /// 转换过程中的提示 (如被丢弃的标签)
+        warnings: Vec<String>,
+    },
+    /// 转换失败
+    Error {
+        /// 错误消息
+        message: String,
+        /// 错误行号（从 1 开始）
+        line: Option<usize>,
+        /// 错误列号（从 1 开始）
+        column: Option<usize>,
+    },
+}
+
+impl ConversionResult {
+    /// 不带位置信息的错误
+    pub fn error(message: impl Into<String>) -> Self {
+        ConversionResult::Error {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+/// YAML 输出风格
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum YamlStyle {
+    /// 块风格 (缩进表示层级)
+    #[default]
+    Block,
+    /// 流风格 (`{a: 1, b: [1, 2]}`)
+    Flow,
+}
+
+/// YAML 字符串引号策略
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum YamlQuoting {
+    /// 仅在必要时加引号
+    #[default]
+    Auto,
+    /// 所有字符串使用单引号 (含控制字符时退回双引号)
+    Single,
+    /// 所有字符串使用双引号
+    Double,
+}
+
+/// JSON 转 YAML 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonToYamlOptions {
+    /// 输出风格
+    #[serde(default)]
+    pub style: YamlStyle,
+
+    /// 字符串引号策略
+    #[serde(default)]
+    pub quoting: YamlQuoting,
+
+    /// 块风格的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for JsonToYamlOptions {
+    fn default() -> Self {
+        Self {
+            style: YamlStyle::default(),
+            quoting: YamlQuoting::default(),
+            indent: default_indent(),
+        }
+    }
+}
+
+/// YAML 多文档流的处理方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiDocumentHandling {
+    /// 转换为 JSON 数组，每个文档一个元素
+    #[default]
+    Array,
+    /// 只保留第一个文档
+    First,
+    /// 出现多个文档时报错
+    Error,
+}
+
+/// YAML 标签的处理方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TagHandling {
+    /// 丢弃标签，只保留值 (产生警告)
+    #[default]
+    Drop,
+    /// 保留为 `{"!tag": value}` 形式
+    Wrap,
+    /// 出现标签时报错
+    Error,
+}
+
+/// YAML 转 JSON 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YamlToJsonOptions {
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+
+    /// 多文档流的处理方式
+    #[serde(default)]
+    pub multi_document: MultiDocumentHandling,
+
+    /// 标签的处理方式
+    #[serde(default)]
+    pub tags: TagHandling,
+
+    /// 是否展开合并键 (`<<: *anchor`)；锚点引用总是会被展开
+    #[serde(default = "default_merge_keys")]
+    pub merge_keys: bool,
+}
+
+impl Default for YamlToJsonOptions {
+    fn default() -> Self {
+        Self {
+            indent: default_indent(),
+            multi_document: MultiDocumentHandling::default(),
+            tags: TagHandling::default(),
+            merge_keys: default_merge_keys(),
+        }
+    }
+}
+
+fn default_indent() -> u8 {
+    2
+}
+
+fn default_merge_keys() -> bool {
+    true
+}
+
+/// TOML 嵌套对象的输出方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TomlTableStyle {
+    /// 嵌套对象输出为标准表 (`[a.b]`)，对象数组输出为表数组 (`[[a.b]]`)
+    #[default]
+    Standard,
+    /// 嵌套对象输出为内联表 (`a = { b = 1 }`)
+    Inline,
+}
+
+/// JSON null 的处理方式 (TOML 没有 null)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NullHandling {
+    /// 出现 null 时报错
+    #[default]
+    Error,
+    /// 跳过值为 null 的键和数组元素 (产生警告)
+    Skip,
+}
+
+/// JSON 转 TOML 选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JsonToTomlOptions {
+    /// 嵌套对象的输出方式
+    #[serde(default)]
+    pub table_style: TomlTableStyle,
+
+    /// null 的处理方式
+    #[serde(default)]
+    pub null_handling: NullHandling,
+
+    /// 是否允许元素类型不一致的数组 (TOML 1.0 允许，旧版解析器不支持)
+    #[serde(default)]
+    pub allow_mixed_arrays: bool,
+}
+
+/// TOML 转 JSON 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TomlToJsonOptions {
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for TomlToJsonOptions {
+    fn default() -> Self {
+        Self {
+            indent: default_indent(),
+        }
+    }
+}
+
+/// CSV 字段分隔符
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvDelimiter {
+    /// 逗号 (CSV)
+    #[default]
+    Comma,
+    /// 制表符 (TSV)
+    Tab,
+    /// 分号 (部分地区的 Excel 默认值)
+    Semicolon,
+    /// 竖线
+    Pipe,
+}
+
+impl CsvDelimiter {
+    pub fn as_byte(self) -> u8 {
+        match self {
+            CsvDelimiter::Comma => b',',
+            CsvDelimiter::Tab => b'\t',
+            CsvDelimiter::Semicolon => b';',
+            CsvDelimiter::Pipe => b'|',
+        }
+    }
+}
+
+/// CSV 字段引号策略
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvQuoting {
+    /// 仅在字段包含分隔符、引号或换行时加引号
+    #[default]
+    Necessary,
+    /// 所有字段加引号
+    Always,
+    /// 非数字字段加引号
+    NonNumeric,
+    /// 从不加引号 (字段包含特殊字符时会产生无法解析的输出)
+    Never,
+}
+
+/// 列的排列顺序
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvHeaderOrder {
+    /// 按列首次出现的顺序
+    #[default]
+    FirstSeen,
+    /// 按列名字母顺序
+    Alphabetical,
+}
+
+/// 嵌套数组的展开方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvArrayHandling {
+    /// 整个数组作为 JSON 文本写入单元格
+    #[default]
+    Json,
+    /// 按下标展开为多列 (`tags.0`, `tags.1`)
+    Index,
+}
+
+/// JSON 转 CSV 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonToCsvOptions {
+    /// 字段分隔符
+    #[serde(default)]
+    pub delimiter: CsvDelimiter,
+
+    /// 引号策略
+    #[serde(default)]
+    pub quoting: CsvQuoting,
+
+    /// 列顺序 (未指定 `columns` 时生效)
+    #[serde(default)]
+    pub header_order: CsvHeaderOrder,
+
+    /// 显式指定输出的列及顺序 (点号路径)，为空时输出所有列
+    #[serde(default)]
+    pub columns: Vec<String>,
+
+    /// 嵌套数组的展开方式
+    #[serde(default)]
+    pub array_handling: CsvArrayHandling,
+
+    /// 是否输出表头行
+    #[serde(default = "default_true")]
+    pub include_header: bool,
+}
+
+impl Default for JsonToCsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: CsvDelimiter::default(),
+            quoting: CsvQuoting::default(),
+            header_order: CsvHeaderOrder::default(),
+            columns: Vec::new(),
+            array_handling: CsvArrayHandling::default(),
+            include_header: true,
+        }
+    }
+}
+
+/// CSV 转 JSON 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvToJsonOptions {
+    /// 字段分隔符
+    #[serde(default)]
+    pub delimiter: CsvDelimiter,
+
+    /// 第一行是否为表头；否则每行输出为数组
+    #[serde(default = "default_true")]
+    pub has_header: bool,
+
+    /// 是否推断数字、布尔值、null 以及 JSON 数组/对象，否则全部作为字符串
+    #[serde(default = "default_true")]
+    pub infer_types: bool,
+
+    /// 是否将点号路径的列名还原为嵌套对象
+    #[serde(default = "default_true")]
+    pub unflatten: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for CsvToJsonOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: CsvDelimiter::default(),
+            has_header: true,
+            infer_types: true,
+            unflatten: true,
+            indent: default_indent(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// XML 子元素转为 JSON 数组的规则
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum XmlArrayDetection {
+    /// 同名子元素出现多次时转为数组
+    #[default]
+    Auto,
+    /// 所有子元素都转为数组 (结构稳定，便于程序处理)
+    Always,
+}
+
+/// XML 命名空间的处理方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum XmlNamespaceHandling {
+    /// 保留源文档中的前缀 (`soap:Envelope`)，`xmlns` 声明作为属性保留
+    #[default]
+    Prefix,
+    /// 只保留本地名称，丢弃 `xmlns` 声明
+    Strip,
+    /// 使用展开名称 (`{http://...}Envelope`)
+    Expand,
+}
+
+/// XML 转 JSON 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XmlToJsonOptions {
+    /// 属性键的前缀
+    #[serde(default = "default_attribute_prefix")]
+    pub attribute_prefix: String,
+
+    /// 元素同时有属性或子元素时，文本内容使用的键
+    #[serde(default = "default_text_key")]
+    pub text_key: String,
+
+    /// 数组识别规则
+    #[serde(default)]
+    pub array_detection: XmlArrayDetection,
+
+    /// 始终转为数组的元素名 (与 `array_detection` 无关)
+    #[serde(default)]
+    pub force_array: Vec<String>,
+
+    /// 命名空间的处理方式
+    #[serde(default)]
+    pub namespaces: XmlNamespaceHandling,
+
+    /// 是否将文本和属性值推断为数字和布尔值，否则全部作为字符串
+    #[serde(default)]
+    pub infer_types: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for XmlToJsonOptions {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: default_attribute_prefix(),
+            text_key: default_text_key(),
+            array_detection: XmlArrayDetection::default(),
+            force_array: Vec::new(),
+            namespaces: XmlNamespaceHandling::default(),
+            infer_types: false,
+            indent: default_indent(),
+        }
+    }
+}
+
+/// JSON 转 XML 选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonToXmlOptions {
+    /// 以此前缀开头的键输出为属性
+    #[serde(default = "default_attribute_prefix")]
+    pub attribute_prefix: String,
+
+    /// 此键的值输出为元素的文本内容
+    #[serde(default = "default_text_key")]
+    pub text_key: String,
+
+    /// 根元素名称；JSON 根对象只有一个键时直接使用该键作为根元素
+    #[serde(default = "default_root_name")]
+    pub root_name: String,
+
+    /// 没有键名的数组元素 (如嵌套数组) 使用的元素名
+    #[serde(default = "default_item_name")]
+    pub item_name: String,
+
+    /// 是否输出 XML 声明
+    #[serde(default = "default_true")]
+    pub declaration: bool,
+
+    /// 缩进大小 (0 表示不换行，2 或 4 个空格)
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for JsonToXmlOptions {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: default_attribute_prefix(),
+            text_key: default_text_key(),
+            root_name: default_root_name(),
+            item_name: default_item_name(),
+            declaration: true,
+            indent: default_indent(),
+        }
+    }
+}
+
+fn default_attribute_prefix() -> String {
+    "@".to_string()
+}
+
+fn default_text_key() -> String {
+    "#text".to_string()
+}
+
+fn default_root_name() -> String {
+    "root".to_string()
+}
+
+fn default_item_name() -> String {
+    "item".to_string()
+}
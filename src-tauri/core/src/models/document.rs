@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+/// JSON 节点类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonNodeType {
+    Object,
+    Array,
+    String,
+    Number,
+    Boolean,
+    Null,
+}
+
+/// 节点类型计数
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TypeCounts {
+    pub object: usize,
+    pub array: usize,
+    pub string: usize,
+    pub number: usize,
+    pub boolean: usize,
+    pub null: usize,
+}
+
+/// 树节点摘要 (不包含子节点内容)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSummary {
+    /// 对象键 (父节点为对象时)
+    pub key: Option<String>,
+
+    /// 数组下标 (父节点为数组时)
+    pub index: Option<usize>,
+
+    /// 节点的 JSON Pointer (RFC 6901)
+    pub pointer: String,
+
+    /// 节点类型
+    pub node_type: JsonNodeType,
+
+    /// 子节点数量 (仅对象和数组)
+    pub child_count: Option<usize>,
+
+    /// 值预览 (标量为 JSON 文本，过长时截断；容器为空)
+    pub preview: Option<String>,
+
+    /// 预览是否被截断
+    pub truncated: bool,
+}
+
+/// 已加载文档的信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentInfo {
+    /// 文档 ID
+    pub doc_id: String,
+
+    /// 原始大小（字节）
+    pub size: usize,
+
+    /// 根节点摘要
+    pub root: NodeSummary,
+
+    /// 处理时间（毫秒）
+    pub processing_time_ms: u64,
+}
+
+/// 子节点分页结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildrenPage {
+    /// 父节点的 JSON Pointer
+    pub pointer: String,
+
+    /// 子节点总数
+    pub total: usize,
+
+    /// 本页起始偏移
+    pub offset: usize,
+
+    /// 本页子节点
+    pub children: Vec<NodeSummary>,
+
+    /// 全部直接子节点的类型统计
+    pub child_types: TypeCounts,
+}
@@ -0,0 +1,142 @@
+use crate::models::validation::{ValidationOptions, ValidationResult};
+use serde::{Deserialize, Serialize};
+
+/// 文本文件的字符编码
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum TextEncoding {
+    #[default]
+    #[serde(rename = "utf-8")]
+    Utf8,
+    #[serde(rename = "utf-16le")]
+    Utf16Le,
+    #[serde(rename = "utf-16be")]
+    Utf16Be,
+    #[serde(rename = "utf-32le")]
+    Utf32Le,
+    #[serde(rename = "utf-32be")]
+    Utf32Be,
+}
+
+impl TextEncoding {
+    /// 编码名称 (如 `UTF-16LE`)
+    pub fn label(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+            TextEncoding::Utf32Le => "UTF-32LE",
+            TextEncoding::Utf32Be => "UTF-32BE",
+        }
+    }
+
+    /// 该编码的字节顺序标记
+    pub fn bom(self) -> &'static [u8] {
+        match self {
+            TextEncoding::Utf8 => &[0xEF, 0xBB, 0xBF],
+            TextEncoding::Utf16Le => &[0xFF, 0xFE],
+            TextEncoding::Utf16Be => &[0xFE, 0xFF],
+            TextEncoding::Utf32Le => &[0xFF, 0xFE, 0x00, 0x00],
+            TextEncoding::Utf32Be => &[0x00, 0x00, 0xFE, 0xFF],
+        }
+    }
+}
+
+/// 导出文件的编码选项
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct EncodingOptions {
+    /// 输出编码，默认 UTF-8
+    #[serde(default)]
+    pub encoding: TextEncoding,
+
+    /// 是否在文件开头写入字节顺序标记 (BOM)
+    #[serde(default)]
+    pub bom: bool,
+}
+
+/// 导入导出接受的文件类型
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FileTypeOptions {
+    /// 接受的扩展名 (不含点，不区分大小写)，为空时使用内置列表
+    #[serde(default)]
+    pub extensions: Vec<String>,
+}
+
+/// 文件在某一时刻的状态，用于导出时检测文件是否已被其他程序修改
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileSnapshot {
+    /// 文件的规范化路径
+    pub path: String,
+    /// 修改时间 (Unix 毫秒时间戳，文件系统不支持时为空)
+    pub modified_ms: Option<u64>,
+    /// 文件大小 (字节)
+    pub size: u64,
+    /// 文件内容的 64 位 FNV-1a 哈希 (十六进制)，算法固定，可跨会话比较
+    pub hash: String,
+}
+
+/// 写入文件的选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WriteOptions {
+    /// 覆盖已有文件前将其复制为 `<文件名>.bak`
+    #[serde(default)]
+    pub backup: bool,
+
+    /// 导入时记录的文件状态；写入同一路径时，若文件已被修改则拒绝覆盖
+    #[serde(default)]
+    pub expected: Option<FileSnapshot>,
+}
+
+/// 文件写入结果
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileWriteResult {
+    /// 实际写入的文件路径
+    pub path: String,
+    /// 备份文件路径 (未备份时为空)
+    pub backup_path: Option<String>,
+    /// 写入后的文件状态，供下一次导出检测冲突
+    pub snapshot: FileSnapshot,
+    /// 导出警告 (如扩展名不在允许列表中)
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// 文件监视选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchOptions {
+    /// 文件变化后自动重新读取并验证，结果随事件一起发送
+    #[serde(default)]
+    pub revalidate: bool,
+
+    /// 重新验证使用的验证选项
+    #[serde(default)]
+    pub validation: ValidationOptions,
+
+    /// 重新读取时允许的扩展名
+    #[serde(default)]
+    pub file_types: FileTypeOptions,
+}
+
+/// 文件变化类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    /// 内容被修改 (包括被删除后重新创建)
+    Modified,
+    /// 文件被删除或移走
+    Removed,
+}
+
+/// 文件变化事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChangeEvent {
+    /// 监视 ID
+    pub watch_id: String,
+    /// 文件路径
+    pub path: String,
+    /// 变化类型
+    pub kind: FileChangeKind,
+    /// 变化后的文件状态 (文件被删除时为空)
+    pub snapshot: Option<FileSnapshot>,
+    /// 自动重新验证的结果 (未启用或文件被删除时为空)
+    pub validation: Option<ValidationResult>,
+}
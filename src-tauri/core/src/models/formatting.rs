@@ -0,0 +1,92 @@
+use crate::models::validation::DuplicateKeyPolicy;
+use serde::{Deserialize, Serialize};
+
+/// JSON 格式化选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormattingOptions {
+    /// 缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+
+    /// 是否在输出末尾添加换行符
+    #[serde(default = "default_trailing_newline")]
+    pub trailing_newline: bool,
+
+    /// 字符串转义选项 (与其他字段位于同一层级)
+    #[serde(flatten)]
+    pub escape: EscapeOptions,
+
+    /// 重复键的处理策略
+    #[serde(default)]
+    pub duplicate_keys: DuplicateKeyPolicy,
+}
+
+impl Default for FormattingOptions {
+    fn default() -> Self {
+        Self {
+            indent: default_indent(),
+            trailing_newline: default_trailing_newline(),
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        }
+    }
+}
+
+/// 字符串转义选项，格式化与压缩共用
+///
+/// 默认只转义 JSON 要求转义的字符，其余字符原样输出
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct EscapeOptions {
+    /// 是否只输出 ASCII（非 ASCII 字符转义为 `\uXXXX`，BMP 以外的字符使用代理对）
+    #[serde(default)]
+    pub ascii_only: bool,
+
+    /// 是否将 `/` 转义为 `\/`
+    #[serde(default)]
+    pub escape_slash: bool,
+
+    /// 是否转义 HTML 敏感字符 `<`、`>`、`&` 以及 U+2028/U+2029，便于嵌入 `<script>` 标签
+    #[serde(default)]
+    pub escape_html: bool,
+
+    /// `\uXXXX` 转义是否使用大写十六进制
+    #[serde(default)]
+    pub uppercase_hex: bool,
+}
+
+impl EscapeOptions {
+    /// 是否需要额外转义 (否则可直接使用 serde_json 的默认输出)
+    pub fn is_plain(&self) -> bool {
+        !self.ascii_only && !self.escape_slash && !self.escape_html && !self.uppercase_hex
+    }
+}
+
+fn default_indent() -> u8 {
+    2
+}
+
+fn default_trailing_newline() -> bool {
+    true
+}
+
+/// JSON 格式化结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum FormattingResult {
+    /// 格式化成功
+    Success {
+        /// 格式化后的 JSON 字符串
+        formatted: String,
+        /// 输出大小（字节）
+        size: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+    },
+    /// 格式化失败
+    Error {
+        /// 错误消息
+        message: String,
+    },
+    /// 格式化被取消
+    Cancelled,
+}
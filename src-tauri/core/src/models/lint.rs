@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+/// 对象键的命名风格
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyNamingConvention {
+    /// `camelCase`
+    CamelCase,
+    /// `snake_case`
+    SnakeCase,
+}
+
+/// 检查规则配置，未配置的规则不启用
+///
+/// 可以写在项目目录的 `.jsonlint.json` 中，例如：
+/// `{"key_naming": "camel_case", "max_depth": 8, "empty_containers": true}`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// 对象键的命名风格 (以 `$`、`@` 开头的键不检查，如 `$schema`、`@context`)
+    #[serde(default)]
+    pub key_naming: Option<KeyNamingConvention>,
+
+    /// 最大嵌套深度 (根为对象或数组时为 1)
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// 报告空对象和空数组
+    #[serde(default)]
+    pub empty_containers: bool,
+
+    /// 报告元素类型不一致的数组 (忽略 `null`)
+    #[serde(default)]
+    pub mixed_type_arrays: bool,
+
+    /// 报告内容是数字的字符串 (如 `"42"`)
+    #[serde(default)]
+    pub numbers_as_strings: bool,
+
+    /// 字符串的最大字符数
+    #[serde(default)]
+    pub max_string_length: Option<usize>,
+
+    /// 报告不是 Unicode NFC 规范化形式的键和字符串
+    #[serde(default)]
+    pub unicode_nfc: bool,
+}
+
+/// 检查规则
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    KeyNaming,
+    MaxDepth,
+    EmptyContainer,
+    MixedTypeArray,
+    NumberAsString,
+    LongString,
+    UnicodeNfc,
+}
+
+/// 检查警告
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LintWarning {
+    /// 触发的规则
+    pub rule: LintRule,
+    /// 节点的 JSON Pointer
+    pub pointer: String,
+    /// 警告消息
+    pub message: String,
+}
+
+/// 从项目目录找到的检查配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintConfigFile {
+    /// 配置文件路径
+    pub path: String,
+    /// 配置内容
+    pub config: LintConfig,
+}
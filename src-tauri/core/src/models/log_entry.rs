@@ -33,6 +33,8 @@ pub enum OperationType {
     Validate, // 验证
     Format,   // 格式化
     Minify,   // 压缩
+    Load,     // 加载文档
+    Batch,    // 批量处理
 }
 
 impl OperationType {
@@ -41,6 +43,8 @@ impl OperationType {
             OperationType::Validate => "验证",
             OperationType::Format => "格式化",
             OperationType::Minify => "压缩",
+            OperationType::Load => "加载文档",
+            OperationType::Batch => "批量处理",
         }
     }
 }
@@ -49,8 +53,9 @@ impl OperationType {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum OperationResult {
-    Success, // 成功
-    Error,   // 失败
+    Success,   // 成功
+    Error,     // 失败
+    Cancelled, // 已取消
 }
 
 impl OperationResult {
@@ -58,6 +63,7 @@ impl OperationResult {
         match self {
             OperationResult::Success => "成功",
             OperationResult::Error => "失败",
+            OperationResult::Cancelled => "已取消",
         }
     }
 }
@@ -74,7 +80,10 @@ pub struct LogStatistics {
     /// 失败数
     pub error_count: usize,
 
-    /// 成功率 (0-100)
+    /// 取消数
+    pub cancelled_count: usize,
+
+    /// 成功率 (0-100，不计入已取消的操作)
     pub success_rate: f64,
 
     /// 验证操作数
@@ -0,0 +1,16 @@
+//! 各服务的选项和结果类型
+
+pub mod validation;
+pub mod formatting;
+pub mod log_entry;
+pub mod operation;
+pub mod document;
+pub mod ndjson;
+pub mod sequence;
+pub mod conversion;
+pub mod binary;
+pub mod transform;
+pub mod analysis;
+pub mod lint;
+pub mod file;
+pub mod batch;
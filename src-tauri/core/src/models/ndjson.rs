@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// NDJSON 单行错误
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineError {
+    /// 行号（从 1 开始）
+    pub line: usize,
+    /// 列号（从 1 开始）
+    pub column: Option<usize>,
+    /// 错误消息
+    pub message: String,
+}
+
+/// NDJSON 验证结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NdjsonValidationResult {
+    /// 记录总数 (不含空行)
+    pub total_records: usize,
+    /// 有效记录数
+    pub valid_records: usize,
+    /// 各行的错误 (最多保留前 1000 条)
+    pub errors: Vec<LineError>,
+    /// 错误是否因数量过多被截断
+    pub errors_truncated: bool,
+    /// 输入大小（字节）
+    pub size: usize,
+    /// 处理时间（毫秒）
+    pub processing_time_ms: u64,
+}
+
+/// NDJSON 输出模式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NdjsonOutputMode {
+    /// 逐条美化输出
+    Pretty,
+    /// 每条记录压缩为一行 (标准 NDJSON)
+    Minify,
+    /// 转换为 JSON 数组
+    JsonArray,
+}
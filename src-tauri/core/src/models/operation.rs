@@ -0,0 +1,15 @@
+use crate::models::log_entry::OperationType;
+use serde::{Deserialize, Serialize};
+
+/// 长耗时操作的进度事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationProgress {
+    /// 操作 ID
+    pub operation_id: String,
+
+    /// 操作类型
+    pub operation: OperationType,
+
+    /// 完成百分比 (0-100)
+    pub percent: u8,
+}
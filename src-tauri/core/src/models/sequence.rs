@@ -0,0 +1,42 @@
+use crate::models::validation::ValidationResult;
+use serde::{Deserialize, Serialize};
+
+/// 多文档输入格式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SequenceFormat {
+    /// 首尾相接的多个 JSON 值 (`{...}{...}`)
+    Concatenated,
+    /// RFC 7464 JSON 文本序列 (每条记录以 0x1E 开头，以换行结束)
+    JsonSeq,
+}
+
+/// 单个文档的验证结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceDocument {
+    /// 文档序号（从 0 开始）
+    pub index: usize,
+    /// 文档起始行号（从 1 开始）
+    pub line: usize,
+    /// 文档起始列号（从 1 开始）
+    pub column: usize,
+    /// 验证结果 (错误位置相对于整个输入)
+    pub result: ValidationResult,
+}
+
+/// 多文档验证结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceValidationResult {
+    /// 实际使用的输入格式
+    pub format: SequenceFormat,
+    /// 文档总数
+    pub total_documents: usize,
+    /// 有效文档数
+    pub valid_documents: usize,
+    /// 各文档的验证结果
+    pub documents: Vec<SequenceDocument>,
+    /// 输入大小（字节）
+    pub size: usize,
+    /// 处理时间（毫秒）
+    pub processing_time_ms: u64,
+}
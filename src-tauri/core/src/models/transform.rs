@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+
+/// 扁平化路径中数组下标的表示方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayNotation {
+    /// 方括号下标 (`a.b[0].c`)
+    #[default]
+    Brackets,
+    /// 与对象键相同，用分隔符连接 (`a.b.0.c`)；还原时连续的数字键视为数组
+    Separator,
+}
+
+/// 扁平化 / 还原选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlattenOptions {
+    /// 路径分隔符 (默认 `.`，可使用 `/`、`__` 等)
+    #[serde(default = "default_separator")]
+    pub separator: String,
+
+    /// 数组下标的表示方式
+    #[serde(default)]
+    pub array_notation: ArrayNotation,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        Self {
+            separator: default_separator(),
+            array_notation: ArrayNotation::default(),
+            indent: default_indent(),
+        }
+    }
+}
+
+/// 展开嵌入 JSON 字符串的选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpandOptions {
+    /// 继续展开解析结果中的嵌入字符串 (包括多次转义的字符串)
+    #[serde(default)]
+    pub recursive: bool,
+
+    /// 只展开解析结果为对象或数组的字符串 (不把 `"123"`、`"true"` 转换为数字或布尔值)
+    #[serde(default = "default_true")]
+    pub only_containers: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for ExpandOptions {
+    fn default() -> Self {
+        Self {
+            recursive: false,
+            only_containers: true,
+            indent: default_indent(),
+        }
+    }
+}
+
+/// 结构变换结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TransformResult {
+    /// 变换成功
+    Success {
+        /// 变换后的 JSON
+        output: String,
+        /// 输出大小（字节）
+        size: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+        /// 被修改的节点 (JSON Pointer)
+        changed: Vec<String>,
+    },
+    /// 变换失败
+    Error {
+        /// 错误消息
+        message: String,
+        /// 错误行号（从 1 开始）
+        line: Option<usize>,
+        /// 错误列号（从 1 开始）
+        column: Option<usize>,
+    },
+}
+
+impl TransformResult {
+    /// 不带位置信息的错误
+    pub fn error(message: impl Into<String>) -> Self {
+        TransformResult::Error {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+/// 解码 Base64 字符串值的选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Base64DecodeOptions {
+    /// 解码结果不是 JSON 但是可读文本时，也替换为解码后的文本 (默认只替换为 JSON)
+    #[serde(default)]
+    pub decode_text: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for Base64DecodeOptions {
+    fn default() -> Self {
+        Self {
+            decode_text: false,
+            indent: default_indent(),
+        }
+    }
+}
+
+/// URL 百分号解码选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PercentDecodeOptions {
+    /// 将 `+` 解码为空格 (`application/x-www-form-urlencoded`)
+    #[serde(default)]
+    pub plus_as_space: bool,
+
+    /// 输出 JSON 的缩进大小（2 或 4 个空格）
+    #[serde(default = "default_indent")]
+    pub indent: u8,
+}
+
+impl Default for PercentDecodeOptions {
+    fn default() -> Self {
+        Self {
+            plus_as_space: false,
+            indent: default_indent(),
+        }
+    }
+}
+
+/// JWT 中的时间声明
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwtTimestamp {
+    /// 声明名称 (`exp`、`iat`、`nbf`)
+    pub claim: String,
+    /// 原始值 (Unix 秒)
+    pub value: i64,
+    /// RFC 3339 格式的 UTC 时间
+    pub datetime: String,
+}
+
+/// JWT 解码结果 (不验证签名)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum JwtDecodeResult {
+    /// 解码成功
+    Success {
+        /// 格式化后的头部 JSON
+        header: String,
+        /// 格式化后的载荷 JSON
+        payload: String,
+        /// 签名段 (Base64URL 原文)
+        signature: String,
+        /// 签名算法 (头部的 `alg`)
+        algorithm: Option<String>,
+        /// 载荷中的时间声明
+        timestamps: Vec<JwtTimestamp>,
+        /// 是否已过期 (没有 `exp` 时为空)
+        expired: Option<bool>,
+    },
+    /// 解码失败
+    Error {
+        /// 错误消息
+        message: String,
+    },
+}
+
+impl JwtDecodeResult {
+    pub fn error(message: impl Into<String>) -> Self {
+        JwtDecodeResult::Error {
+            message: message.into(),
+        }
+    }
+}
+
+fn default_separator() -> String {
+    ".".to_string()
+}
+
+fn default_indent() -> u8 {
+    2
+}
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,92 @@
+use crate::models::lint::{LintConfig, LintWarning};
+use serde::{Deserialize, Serialize};
+
+/// JSON 验证结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ValidationResult {
+    Success {
+        /// 解析后的 JSON 值
+        data: serde_json::Value,
+        /// 输入大小（字节）
+        size: usize,
+        /// 处理时间（毫秒）
+        processing_time_ms: u64,
+        /// 重复的对象键 (按策略保留其中一个值后作为警告返回)
+        #[serde(default)]
+        duplicate_keys: Vec<DuplicateKey>,
+        /// 风格检查警告 (未启用检查时为空)
+        #[serde(default)]
+        warnings: Vec<LintWarning>,
+    },
+    Error {
+        /// 错误消息
+        message: String,
+        /// 错误行号（从 1 开始）
+        line: Option<usize>,
+        /// 错误列号（从 1 开始）
+        column: Option<usize>,
+    },
+    /// 验证被取消
+    Cancelled,
+}
+
+/// 对象中出现重复键时的处理策略
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateKeyPolicy {
+    /// 视为错误
+    Error,
+    /// 保留第一次出现的值
+    KeepFirst,
+    /// 保留最后一次出现的值 (与 serde_json 的默认行为一致)
+    #[default]
+    KeepLast,
+}
+
+/// 重复的对象键
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateKey {
+    /// 键名
+    pub key: String,
+    /// 该成员的 JSON Pointer
+    pub pointer: String,
+    /// 第一次出现的行号（从 1 开始）
+    pub first_line: usize,
+    /// 第一次出现的列号（从 1 开始）
+    pub first_column: usize,
+    /// 重复出现的行号（从 1 开始）
+    pub line: usize,
+    /// 重复出现的列号（从 1 开始）
+    pub column: usize,
+}
+
+/// 严格模式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StrictMode {
+    /// 只按 serde_json 的规则解析
+    #[default]
+    Off,
+    /// RFC 8259：拒绝 BOM、孤立代理项和超出双精度范围的数字
+    Rfc8259,
+    /// I-JSON (RFC 7493)：在 RFC 8259 的基础上拒绝 Unicode 非字符、超出 ±(2^53-1) 的整数和重复键
+    #[serde(rename = "ijson")]
+    IJson,
+}
+
+/// 验证选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationOptions {
+    /// 重复键的处理策略 (I-JSON 模式下总是视为错误)
+    #[serde(default)]
+    pub duplicate_keys: DuplicateKeyPolicy,
+
+    /// 风格检查配置，为空时不检查
+    #[serde(default)]
+    pub lint: Option<LintConfig>,
+
+    /// 严格模式
+    #[serde(default)]
+    pub strict: StrictMode,
+}
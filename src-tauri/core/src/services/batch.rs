@@ -0,0 +1,489 @@
+//! 批量处理服务
+//! 按 glob 模式匹配文件夹中的文件，并行验证、格式化或压缩，原地覆盖或镜像输出到另一个文件夹
+
+use crate::models::batch::{BatchFileResult, BatchOperation, BatchOptions, BatchReport};
+use crate::models::file::{EncodingOptions, FileTypeOptions, WriteOptions};
+use crate::models::validation::{ValidationOptions, ValidationResult};
+use crate::services::encoding;
+use crate::services::file_io;
+use crate::services::json_formatter::pretty_print_escaped;
+use crate::services::json_parser::validate_json_with_context;
+use crate::services::operation::{OperationContext, OperationError, CANCELLED_MESSAGE};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+/// 未指定包含模式时使用的模式
+const DEFAULT_PATTERN: &str = "**/*.json";
+
+/// 单次最多处理的文件数
+const MAX_FILES: usize = 10_000;
+
+/// 批量处理文件夹中的 JSON 文件
+///
+/// 每个文件的读取、验证、写入错误记录在报告中，不影响其他文件；
+/// 选项无效 (见 [`check_options`])、文件夹不存在或无法读取、匹配的文件过多或操作被取消时返回错误
+///
+/// # Arguments
+/// * `options` - 文件夹、匹配模式、操作及输出位置
+/// * `context` - 操作上下文，按已完成的文件数上报进度
+pub fn batch_process(
+    options: &BatchOptions,
+    context: &OperationContext,
+) -> Result<BatchReport, OperationError> {
+    let start = Instant::now();
+    let root = Path::new(&options.root);
+    if !root.is_dir() {
+        return Err(format!("文件夹不存在: {}", options.root).into());
+    }
+    let (include, exclude) = check_options(options)?;
+
+    // 输出文件夹位于输入文件夹内时不处理其中的文件
+    let output_dir = options.output_dir.as_deref().map(PathBuf::from);
+    let skip_dir = output_dir
+        .as_deref()
+        .and_then(|dir| dir.canonicalize().ok());
+
+    let mut files = Vec::new();
+    collect_files(
+        root,
+        root,
+        &include,
+        &exclude,
+        skip_dir.as_deref(),
+        &mut files,
+    )?;
+    files.sort();
+
+    let total = files.len();
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<BatchFileResult>>> = Mutex::new(vec![None; total]);
+    let threads = options
+        .threads
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+        .clamp(1, total.max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                if context.is_cancelled() {
+                    break;
+                }
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(relative) = files.get(index) else {
+                    break;
+                };
+                let result = process_file(root, relative, output_dir.as_deref(), options);
+                results.lock().unwrap()[index] = Some(result);
+                let finished = done.fetch_add(1, Ordering::SeqCst) + 1;
+                context.report_range(finished, total, 0, 100);
+            });
+        }
+    });
+
+    context.check()?;
+    context.report(100);
+
+    let files: Vec<BatchFileResult> = results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect();
+    let failed = files
+        .iter()
+        .filter(|file| matches!(file, BatchFileResult::Error { .. }))
+        .count();
+    let changed = files
+        .iter()
+        .filter(|file| matches!(file, BatchFileResult::Success { changed: true, .. }))
+        .count();
+    Ok(BatchReport {
+        total,
+        succeeded: total - failed,
+        failed,
+        changed,
+        files,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// 检查与文件系统无关的选项 (缩进、匹配模式)
+///
+/// # Returns
+/// 包含模式与排除模式；选项无效时返回错误，不访问文件夹
+pub fn check_options(options: &BatchOptions) -> Result<(GlobSet, GlobSet), String> {
+    if options.operation == BatchOperation::Format
+        && options.formatting.indent != 2
+        && options.formatting.indent != 4
+    {
+        return Err(format!(
+            "不支持的缩进值 {}，仅支持 2 或 4 个空格",
+            options.formatting.indent
+        ));
+    }
+
+    let patterns: Vec<&str> = if options.patterns.is_empty() {
+        vec![DEFAULT_PATTERN]
+    } else {
+        options.patterns.iter().map(String::as_str).collect()
+    };
+    Ok((
+        build_glob_set(&patterns)?,
+        build_glob_set(&options.exclude)?,
+    ))
+}
+
+fn build_glob_set<S: AsRef<str>>(patterns: &[S]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.as_ref();
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| format!("无效的匹配模式 \"{}\": {}", pattern, e))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("无效的匹配模式: {}", e))
+}
+
+/// 递归收集匹配的文件 (相对于 `root`，以 `/` 分隔)，不进入符号链接的目录
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    include: &GlobSet,
+    exclude: &GlobSet,
+    skip_dir: Option<&Path>,
+    files: &mut Vec<String>,
+) -> Result<(), String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("无法读取文件夹 {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("无法读取文件夹 {}: {}", dir.display(), e))?;
+        let path = entry.path();
+        let relative = relative_path(root, &path);
+        if exclude.is_match(&relative) {
+            continue;
+        }
+
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("无法获取文件类型 {}: {}", path.display(), e))?;
+        if file_type.is_dir() {
+            if skip_dir.is_some_and(|skip| path.canonicalize().is_ok_and(|p| p == skip)) {
+                continue;
+            }
+            collect_files(root, &path, include, exclude, skip_dir, files)?;
+        } else if path.is_file() && include.is_match(&relative) {
+            if files.len() >= MAX_FILES {
+                return Err(format!("匹配的文件超过 {} 个，请缩小匹配范围", MAX_FILES));
+            }
+            files.push(relative);
+        }
+    }
+    Ok(())
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 处理单个文件，输出时保留原文件的编码和 BOM
+fn process_file(
+    root: &Path,
+    relative: &str,
+    output_dir: Option<&Path>,
+    options: &BatchOptions,
+) -> BatchFileResult {
+    let error =
+        |message: String, line: Option<usize>, column: Option<usize>| BatchFileResult::Error {
+            path: relative.to_string(),
+            message,
+            line,
+            column,
+        };
+
+    let source = root.join(relative);
+    let file = match file_io::read_json_file(&source.to_string_lossy(), &FileTypeOptions::default())
+    {
+        Ok(file) => file,
+        Err(message) => return error(message, None, None),
+    };
+
+    // 格式化与压缩按格式化选项处理重复键，与 json_formatter::format_json 一致
+    let validation = match options.operation {
+        BatchOperation::Validate => Cow::Borrowed(&options.validation),
+        BatchOperation::Format | BatchOperation::Minify => Cow::Owned(ValidationOptions {
+            duplicate_keys: options.formatting.duplicate_keys,
+            ..options.validation.clone()
+        }),
+    };
+    let (value, duplicate_keys, lint_warnings) =
+        match validate_json_with_context(&file.content, &validation, &OperationContext::detached())
+        {
+            ValidationResult::Success {
+                data,
+                duplicate_keys,
+                warnings,
+                ..
+            } => (data, duplicate_keys, warnings),
+            ValidationResult::Error {
+                message,
+                line,
+                column,
+            } => return error(message, line, column),
+            ValidationResult::Cancelled => return error(CANCELLED_MESSAGE.to_string(), None, None),
+        };
+
+    let output = match options.operation {
+        BatchOperation::Validate => None,
+        BatchOperation::Format => {
+            let formatting = &options.formatting;
+            match pretty_print_escaped(&value, formatting.indent, &formatting.escape) {
+                Ok(formatted) if formatting.trailing_newline => Some(formatted + "\n"),
+                Ok(formatted) => Some(formatted),
+                Err(message) => return error(message, None, None),
+            }
+        }
+        BatchOperation::Minify => {
+            match pretty_print_escaped(&value, 0, &options.formatting.escape) {
+                Ok(minified) => Some(minified),
+                Err(message) => return error(message, None, None),
+            }
+        }
+    };
+
+    let changed = output
+        .as_ref()
+        .is_some_and(|output| *output != file.content);
+    let target = match (&output, output_dir) {
+        (Some(_), Some(dir)) => Some(dir.join(relative)),
+        // 原地覆盖时跳过内容未变化的文件
+        (Some(_), None) if changed => Some(source),
+        _ => None,
+    };
+
+    let mut output_path = None;
+    if let (Some(output), Some(target)) = (&output, target) {
+        if let Some(parent) = target.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                return error(format!("无法创建目录: {}", e), None, None);
+            }
+        }
+        let bytes = encoding::encode(
+            output,
+            &EncodingOptions {
+                encoding: file.encoding,
+                bom: file.bom,
+            },
+        );
+        match file_io::write_atomic(&target, &bytes, &WriteOptions::default()) {
+            Ok(written) => output_path = Some(written.path),
+            Err(message) => return error(message, None, None),
+        }
+    }
+
+    BatchFileResult::Success {
+        path: relative.to_string(),
+        output_path,
+        changed,
+        size: file.content.len(),
+        duplicate_keys,
+        lint_warnings,
+        warnings: file.warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::validation::DuplicateKeyPolicy;
+    use crate::services::operation::CancellationToken;
+    use std::sync::Arc;
+
+    fn setup(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("batch-test-{}-{}", name, std::process::id()));
+        fs::create_dir_all(root.join("nested/deep")).unwrap();
+        fs::create_dir_all(root.join("node_modules")).unwrap();
+        fs::write(root.join("a.json"), "{\"b\":1,\"a\":[1,2]}").unwrap();
+        fs::write(root.join("nested/ok.json"), "[\n  1\n]\n").unwrap();
+        fs::write(root.join("nested/deep/bad.json"), "{\"a\": 1,}").unwrap();
+        fs::write(root.join("nested/notes.txt"), "not json").unwrap();
+        fs::write(root.join("node_modules/dep.json"), "{}").unwrap();
+        root
+    }
+
+    fn options(root: &Path, operation: BatchOperation) -> BatchOptions {
+        BatchOptions {
+            root: root.to_string_lossy().to_string(),
+            patterns: Vec::new(),
+            exclude: vec!["node_modules".to_string()],
+            operation,
+            output_dir: None,
+            formatting: Default::default(),
+            validation: Default::default(),
+            threads: Some(2),
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_each_file() {
+        let root = setup("validate");
+        let report = batch_process(
+            &options(&root, BatchOperation::Validate),
+            &OperationContext::detached(),
+        )
+        .unwrap();
+
+        let paths: Vec<&str> = report
+            .files
+            .iter()
+            .map(|file| match file {
+                BatchFileResult::Success { path, .. } | BatchFileResult::Error { path, .. } => {
+                    path.as_str()
+                }
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["a.json", "nested/deep/bad.json", "nested/ok.json"]
+        );
+        assert_eq!((report.total, report.succeeded, report.failed), (3, 2, 1));
+        match &report.files[1] {
+            BatchFileResult::Error { line, column, .. } => {
+                assert_eq!((*line, *column), (Some(1), Some(9)));
+            }
+            other => panic!("Expected Error result, got {:?}", other),
+        }
+        // 验证不写入文件
+        assert_eq!(
+            fs::read_to_string(root.join("a.json")).unwrap(),
+            "{\"b\":1,\"a\":[1,2]}"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_format_in_place_and_mirror() {
+        let root = setup("format");
+        let report = batch_process(
+            &options(&root, BatchOperation::Format),
+            &OperationContext::detached(),
+        )
+        .unwrap();
+        assert_eq!(report.changed, 1);
+        assert_eq!(
+            fs::read_to_string(root.join("a.json")).unwrap(),
+            "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": 1\n}\n"
+        );
+        // 内容未变化的文件不重写
+        match &report.files[2] {
+            BatchFileResult::Success {
+                output_path,
+                changed,
+                ..
+            } => assert!(output_path.is_none() && !changed),
+            other => panic!("Expected Success result, got {:?}", other),
+        }
+
+        // 镜像输出到输入文件夹内的子文件夹，再次处理时不会包含输出文件
+        let mirror = root.join("out");
+        let mut minify = options(&root, BatchOperation::Minify);
+        minify.patterns = vec!["**/*.json".to_string(), "*.txt".to_string()];
+        minify.output_dir = Some(mirror.to_string_lossy().to_string());
+        for _ in 0..2 {
+            let report = batch_process(&minify, &OperationContext::detached()).unwrap();
+            assert_eq!(report.total, 3);
+        }
+        assert_eq!(
+            fs::read_to_string(mirror.join("nested/ok.json")).unwrap(),
+            "[1]"
+        );
+        assert!(!mirror.join("nested/deep/bad.json").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("nested/ok.json")).unwrap(),
+            "[\n  1\n]\n"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_format_uses_formatting_duplicate_key_policy() {
+        let root = setup("duplicates");
+        fs::write(root.join("a.json"), "{\"a\":1,\"a\":2}").unwrap();
+        let mut format = options(&root, BatchOperation::Format);
+        format.patterns = vec!["a.json".to_string()];
+
+        // 验证选项要求重复键报错，但格式化按格式化选项 (默认保留最后的值) 处理
+        format.validation.duplicate_keys = DuplicateKeyPolicy::Error;
+        let report = batch_process(&format, &OperationContext::detached()).unwrap();
+        assert!(matches!(report.files[0], BatchFileResult::Success { .. }));
+        assert_eq!(
+            fs::read_to_string(root.join("a.json")).unwrap(),
+            "{\n  \"a\": 2\n}\n"
+        );
+
+        fs::write(root.join("a.json"), "{\"a\":1,\"a\":2}").unwrap();
+        format.validation.duplicate_keys = DuplicateKeyPolicy::default();
+        format.formatting.duplicate_keys = DuplicateKeyPolicy::Error;
+        let report = batch_process(&format, &OperationContext::detached()).unwrap();
+        assert!(matches!(report.files[0], BatchFileResult::Error { .. }));
+        assert_eq!(
+            fs::read_to_string(root.join("a.json")).unwrap(),
+            "{\"a\":1,\"a\":2}"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_progress_cancel_and_errors() {
+        let root = setup("progress");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let context = {
+            let seen = seen.clone();
+            OperationContext::new(
+                CancellationToken::new(),
+                Some(Arc::new(move |percent| seen.lock().unwrap().push(percent))),
+            )
+        };
+        batch_process(&options(&root, BatchOperation::Validate), &context).unwrap();
+        assert_eq!(seen.lock().unwrap().last(), Some(&100));
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let cancelled = OperationContext::new(token, None);
+        let result = batch_process(&options(&root, BatchOperation::Validate), &cancelled);
+        assert_eq!(result.unwrap_err(), OperationError::Cancelled);
+
+        let mut invalid = options(&root, BatchOperation::Validate);
+        invalid.patterns = vec!["[".to_string()];
+        assert!(batch_process(&invalid, &OperationContext::detached())
+            .unwrap_err()
+            .to_string()
+            .contains("无效的匹配模式"));
+        invalid.root = root.join("missing").to_string_lossy().to_string();
+        assert!(batch_process(&invalid, &OperationContext::detached())
+            .unwrap_err()
+            .to_string()
+            .contains("文件夹不存在"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,788 @@
+//! 二进制格式编解码服务
+//! MessagePack、CBOR、BSON 与 JSON 互相转换，报告无法用 JSON 精确表示的值 (二进制、扩展类型、日期等)
+
+use crate::models::binary::{
+    BinaryDecodeResult, BinaryFormat, BinaryTextEncoding, LossyKind, LossyValue,
+};
+use crate::models::conversion::ConversionResult;
+use crate::services::bson::{self, BsonValue};
+use crate::services::file_io;
+use crate::services::json_formatter::pretty_print;
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use serde_json::{Map, Number, Value};
+use std::time::Instant;
+
+/// 文本输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 解码时不要求填充的 Base64 引擎
+const BASE64_LENIENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// 解码时不要求填充的 URL 安全 Base64 引擎
+const BASE64_URL_LENIENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// 解码 Base64/十六进制文本形式的二进制数据
+///
+/// # Arguments
+/// * `input` - Base64 或十六进制文本
+/// * `encoding` - 文本编码
+/// * `format` - 二进制格式
+/// * `indent` - 输出 JSON 的缩进
+pub fn decode_text(
+    input: &str,
+    encoding: BinaryTextEncoding,
+    format: BinaryFormat,
+    indent: u8,
+) -> BinaryDecodeResult {
+    if input.len() > MAX_INPUT_SIZE {
+        return BinaryDecodeResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    match text_to_bytes(input, encoding) {
+        Ok(bytes) => decode_bytes(&bytes, format, indent),
+        Err(message) => BinaryDecodeResult::error(message),
+    }
+}
+
+/// 读取并解码二进制文件
+pub fn decode_file(file_path: &str, format: BinaryFormat, indent: u8) -> BinaryDecodeResult {
+    match file_io::read_binary_file(file_path) {
+        Ok(bytes) => decode_bytes(&bytes, format, indent),
+        Err(message) => BinaryDecodeResult::error(message),
+    }
+}
+
+/// 解码二进制数据为格式化的 JSON
+pub fn decode_bytes(bytes: &[u8], format: BinaryFormat, indent: u8) -> BinaryDecodeResult {
+    let start = Instant::now();
+
+    if bytes.is_empty() {
+        return BinaryDecodeResult::error("输入为空，请提供有效的内容");
+    }
+
+    let mut lossy = Vec::new();
+    let value = match format {
+        BinaryFormat::Msgpack => decode_msgpack(bytes, &mut lossy),
+        BinaryFormat::Cbor => decode_cbor(bytes, &mut lossy),
+        BinaryFormat::Bson => decode_bson(bytes, &mut lossy),
+    };
+    let value = match value {
+        Ok(value) => value,
+        Err(message) => return BinaryDecodeResult::error(message),
+    };
+
+    match pretty_print(&value, indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            BinaryDecodeResult::Success {
+                output,
+                size: bytes.len(),
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                lossy,
+            }
+        }
+        Err(message) => BinaryDecodeResult::error(message),
+    }
+}
+
+/// 将 JSON 编码为二进制格式，以 Base64/十六进制文本输出
+///
+/// # Returns
+/// 转换结果，`size` 为二进制数据的字节数
+pub fn encode_text(
+    input: &str,
+    format: BinaryFormat,
+    encoding: BinaryTextEncoding,
+) -> ConversionResult {
+    let start = Instant::now();
+
+    let bytes = match encode(input, format) {
+        Ok(bytes) => bytes,
+        Err(result) => return result,
+    };
+    let output = match encoding {
+        BinaryTextEncoding::Base64 => STANDARD.encode(&bytes),
+        BinaryTextEncoding::Hex => hex::encode(&bytes),
+    };
+
+    ConversionResult::Success {
+        output,
+        size: bytes.len(),
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings: Vec::new(),
+    }
+}
+
+/// 将 JSON 编码为二进制格式并写入文件
+///
+/// # Returns
+/// 成功时返回保存的文件路径
+pub fn encode_file(input: &str, format: BinaryFormat, file_path: &str) -> Result<String, String> {
+    let bytes = encode(input, format).map_err(|result| match result {
+        ConversionResult::Error { message, .. } => message,
+        ConversionResult::Success { .. } => unreachable!(),
+    })?;
+    file_io::write_binary_file(file_path, &bytes)
+}
+
+/// 解析 JSON 并编码为二进制
+fn encode(input: &str, format: BinaryFormat) -> Result<Vec<u8>, ConversionResult> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(ConversionResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        )));
+    }
+    if input.trim().is_empty() {
+        return Err(ConversionResult::error("输入为空，请提供有效的内容"));
+    }
+
+    let value = serde_json::from_str::<Value>(input).map_err(|e| ConversionResult::Error {
+        message: format!("JSON 解析失败: {}", e),
+        line: Some(e.line()),
+        column: Some(e.column()),
+    })?;
+
+    let mut bytes = Vec::new();
+    match format {
+        BinaryFormat::Msgpack => rmpv::encode::write_value(&mut bytes, &json_to_msgpack(&value))
+            .map_err(|e| ConversionResult::error(format!("MessagePack 编码失败: {}", e)))?,
+        BinaryFormat::Cbor => ciborium::ser::into_writer(&value, &mut bytes)
+            .map_err(|e| ConversionResult::error(format!("CBOR 编码失败: {}", e)))?,
+        BinaryFormat::Bson => {
+            let map = value
+                .as_object()
+                .ok_or_else(|| ConversionResult::error("BSON 的根节点必须是对象"))?;
+            bytes = bson::write_document(map)
+                .map_err(|e| ConversionResult::error(format!("BSON 编码失败: {}", e)))?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// 将 Base64/十六进制文本转换为字节
+pub fn text_to_bytes(input: &str, encoding: BinaryTextEncoding) -> Result<Vec<u8>, String> {
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    match encoding {
+        BinaryTextEncoding::Base64 => {
+            let engine = if compact.contains(['-', '_']) {
+                &BASE64_URL_LENIENT
+            } else {
+                &BASE64_LENIENT
+            };
+            engine
+                .decode(&compact)
+                .map_err(|e| format!("Base64 解码失败: {}", e))
+        }
+        BinaryTextEncoding::Hex => {
+            let digits = compact
+                .strip_prefix("0x")
+                .or_else(|| compact.strip_prefix("0X"))
+                .unwrap_or(&compact);
+            hex::decode(digits).map_err(|e| format!("十六进制解码失败: {}", e))
+        }
+    }
+}
+
+/// 有损转换记录
+fn report(lossy: &mut Vec<LossyValue>, path: &str, kind: LossyKind, description: String) {
+    lossy.push(LossyValue {
+        path: path.to_string(),
+        kind,
+        description,
+    });
+}
+
+/// 浮点数转换，NaN 与无穷大转换为 null
+fn float(f: f64, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    match Number::from_f64(f) {
+        Some(n) => Value::Number(n),
+        None => {
+            report(
+                lossy,
+                path,
+                LossyKind::NonFiniteFloat,
+                format!("浮点数 {} 已转换为 null", f),
+            );
+            Value::Null
+        }
+    }
+}
+
+fn binary(bytes: &[u8], path: &str, lossy: &mut Vec<LossyValue>, description: &str) -> Value {
+    report(
+        lossy,
+        path,
+        LossyKind::Binary,
+        format!(
+            "{} ({} 字节) 已转换为 Base64 字符串",
+            description,
+            bytes.len()
+        ),
+    );
+    Value::String(STANDARD.encode(bytes))
+}
+
+/// 非字符串的映射键转换为 JSON 文本
+fn object_key(key: Value, path: &str, lossy: &mut Vec<LossyValue>) -> String {
+    match key {
+        Value::String(s) => s,
+        other => {
+            let text = other.to_string();
+            report(
+                lossy,
+                path,
+                LossyKind::Key,
+                format!("非字符串键 {} 已转换为字符串", text),
+            );
+            text
+        }
+    }
+}
+
+/// Unix 时间戳转换为 RFC 3339 字符串
+fn timestamp(seconds: i64, nanos: u32) -> Option<String> {
+    chrono::DateTime::from_timestamp(seconds, nanos)
+        .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+}
+
+/// 检查解码后是否还有剩余字节
+fn check_trailing(remaining: usize) -> Result<(), String> {
+    if remaining > 0 {
+        Err(format!(
+            "数据末尾有 {} 字节未解码，输入可能包含多个值或已损坏",
+            remaining
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn decode_msgpack(bytes: &[u8], lossy: &mut Vec<LossyValue>) -> Result<Value, String> {
+    let mut reader = bytes;
+    let value = rmpv::decode::read_value(&mut reader)
+        .map_err(|e| format!("MessagePack 解码失败: {}", e))?;
+    check_trailing(reader.len())?;
+    Ok(msgpack_to_json(value, "$", lossy))
+}
+
+fn msgpack_to_json(value: rmpv::Value, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(b) => Value::Bool(b),
+        rmpv::Value::Integer(i) => match (i.as_i64(), i.as_u64()) {
+            (Some(n), _) => Value::from(n),
+            (None, Some(n)) => Value::from(n),
+            _ => Value::Null,
+        },
+        rmpv::Value::F32(f) => float(f as f64, path, lossy),
+        rmpv::Value::F64(f) => float(f, path, lossy),
+        rmpv::Value::String(s) => match s.as_str() {
+            Some(text) => Value::String(text.to_string()),
+            None => {
+                report(
+                    lossy,
+                    path,
+                    LossyKind::Binary,
+                    "无效的 UTF-8 字符串已替换非法字符".to_string(),
+                );
+                Value::String(String::from_utf8_lossy(s.as_bytes()).into_owned())
+            }
+        },
+        rmpv::Value::Binary(bytes) => binary(&bytes, path, lossy, "二进制数据"),
+        rmpv::Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| msgpack_to_json(item, &format!("{}[{}]", path, i), lossy))
+                .collect(),
+        ),
+        rmpv::Value::Map(entries) => {
+            let mut map = Map::new();
+            for (key, child) in entries {
+                let key = object_key(msgpack_to_json(key, path, lossy), path, lossy);
+                let child_path = format!("{}.{}", path, key);
+                map.insert(key, msgpack_to_json(child, &child_path, lossy));
+            }
+            Value::Object(map)
+        }
+        rmpv::Value::Ext(-1, data) => match msgpack_timestamp(&data) {
+            Some(time) => {
+                report(
+                    lossy,
+                    path,
+                    LossyKind::Date,
+                    "MessagePack 时间戳已转换为 RFC 3339 字符串".to_string(),
+                );
+                Value::String(time)
+            }
+            None => binary(&data, path, lossy, "无效的 MessagePack 时间戳"),
+        },
+        rmpv::Value::Ext(kind, data) => {
+            report(
+                lossy,
+                path,
+                LossyKind::Extension,
+                format!(
+                    "扩展类型 {} ({} 字节) 已转换为 Base64 字符串",
+                    kind,
+                    data.len()
+                ),
+            );
+            Value::String(STANDARD.encode(&data))
+        }
+    }
+}
+
+/// 解析 MessagePack 时间戳扩展 (类型 -1，32/64/96 位三种格式)
+fn msgpack_timestamp(data: &[u8]) -> Option<String> {
+    match data.len() {
+        4 => timestamp(u32::from_be_bytes(data.try_into().ok()?) as i64, 0),
+        8 => {
+            let raw = u64::from_be_bytes(data.try_into().ok()?);
+            timestamp((raw & 0x3_ffff_ffff) as i64, (raw >> 34) as u32)
+        }
+        12 => {
+            let nanos = u32::from_be_bytes(data[..4].try_into().ok()?);
+            let seconds = i64::from_be_bytes(data[4..].try_into().ok()?);
+            timestamp(seconds, nanos)
+        }
+        _ => None,
+    }
+}
+
+fn decode_cbor(bytes: &[u8], lossy: &mut Vec<LossyValue>) -> Result<Value, String> {
+    let mut reader = bytes;
+    let value: ciborium::Value =
+        ciborium::de::from_reader(&mut reader).map_err(|e| format!("CBOR 解码失败: {}", e))?;
+    check_trailing(reader.len())?;
+    Ok(cbor_to_json(value, "$", lossy))
+}
+
+fn cbor_to_json(value: ciborium::Value, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    match value {
+        ciborium::Value::Null => Value::Null,
+        ciborium::Value::Bool(b) => Value::Bool(b),
+        ciborium::Value::Integer(i) => big_integer(i128::from(i), path, lossy),
+        ciborium::Value::Float(f) => float(f, path, lossy),
+        ciborium::Value::Text(s) => Value::String(s),
+        ciborium::Value::Bytes(bytes) => binary(&bytes, path, lossy, "字节串"),
+        ciborium::Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| cbor_to_json(item, &format!("{}[{}]", path, i), lossy))
+                .collect(),
+        ),
+        ciborium::Value::Map(entries) => {
+            let mut map = Map::new();
+            for (key, child) in entries {
+                let key = object_key(cbor_to_json(key, path, lossy), path, lossy);
+                let child_path = format!("{}.{}", path, key);
+                map.insert(key, cbor_to_json(child, &child_path, lossy));
+            }
+            Value::Object(map)
+        }
+        ciborium::Value::Tag(tag, inner) => cbor_tag(tag, *inner, path, lossy),
+        _ => Value::Null,
+    }
+}
+
+/// 处理 CBOR 标签：日期时间 (0/1)、大整数 (2/3)，其他标签保留内部值
+fn cbor_tag(tag: u64, inner: ciborium::Value, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    match (tag, inner) {
+        (0, ciborium::Value::Text(time)) => {
+            report(
+                lossy,
+                path,
+                LossyKind::Date,
+                "CBOR 日期时间 (标签 0) 已转换为字符串".to_string(),
+            );
+            Value::String(time)
+        }
+        (1, epoch @ (ciborium::Value::Integer(_) | ciborium::Value::Float(_))) => {
+            let seconds = match epoch {
+                ciborium::Value::Integer(i) => i128::from(i) as f64,
+                ciborium::Value::Float(f) => f,
+                _ => unreachable!(),
+            };
+            let time = if seconds.is_finite() {
+                timestamp(seconds.floor() as i64, (seconds.fract() * 1e9) as u32)
+            } else {
+                None
+            };
+            report(
+                lossy,
+                path,
+                LossyKind::Date,
+                "CBOR 时间戳 (标签 1) 已转换为 RFC 3339 字符串".to_string(),
+            );
+            time.map(Value::String).unwrap_or(Value::Null)
+        }
+        (2 | 3, ciborium::Value::Bytes(bytes)) if bytes.len() <= 16 => {
+            let mut buffer = [0u8; 16];
+            buffer[16 - bytes.len()..].copy_from_slice(&bytes);
+            let magnitude = u128::from_be_bytes(buffer);
+            let text = if tag == 2 {
+                magnitude.to_string()
+            } else {
+                // 标签 3 表示 -1 - n
+                match magnitude.checked_add(1) {
+                    Some(n) => format!("-{}", n),
+                    None => "-340282366920938463463374607431768211456".to_string(),
+                }
+            };
+            report(
+                lossy,
+                path,
+                LossyKind::Number,
+                format!("CBOR 大整数 {} 已转换为字符串", text),
+            );
+            Value::String(text)
+        }
+        (tag, inner) => {
+            report(
+                lossy,
+                path,
+                LossyKind::Extension,
+                format!("已丢弃 CBOR 标签 {}", tag),
+            );
+            cbor_to_json(inner, path, lossy)
+        }
+    }
+}
+
+/// 超出 64 位范围的整数转换为字符串
+fn big_integer(n: i128, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    if let Ok(n) = i64::try_from(n) {
+        Value::from(n)
+    } else if let Ok(n) = u64::try_from(n) {
+        Value::from(n)
+    } else {
+        report(
+            lossy,
+            path,
+            LossyKind::Number,
+            format!("整数 {} 超出 64 位范围，已转换为字符串", n),
+        );
+        Value::String(n.to_string())
+    }
+}
+
+fn decode_bson(bytes: &[u8], lossy: &mut Vec<LossyValue>) -> Result<Value, String> {
+    let (document, read) =
+        bson::read_document(bytes).map_err(|e| format!("BSON 解码失败: {}", e))?;
+    check_trailing(bytes.len() - read)?;
+    Ok(bson_to_json(BsonValue::Document(document), "$", lossy))
+}
+
+fn bson_to_json(value: BsonValue, path: &str, lossy: &mut Vec<LossyValue>) -> Value {
+    let extension = |lossy: &mut Vec<LossyValue>, description: &str| {
+        report(lossy, path, LossyKind::Extension, description.to_string());
+    };
+
+    match value {
+        BsonValue::Null => Value::Null,
+        BsonValue::Boolean(b) => Value::Bool(b),
+        BsonValue::Int32(n) => Value::from(n),
+        BsonValue::Int64(n) => Value::from(n),
+        BsonValue::Double(f) => float(f, path, lossy),
+        BsonValue::String(s) => Value::String(s),
+        BsonValue::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| bson_to_json(item, &format!("{}[{}]", path, i), lossy))
+                .collect(),
+        ),
+        BsonValue::Document(document) => {
+            let mut map = Map::new();
+            for (key, child) in document {
+                let child_path = format!("{}.{}", path, key);
+                map.insert(key, bson_to_json(child, &child_path, lossy));
+            }
+            Value::Object(map)
+        }
+        BsonValue::Binary { subtype, bytes } => binary(
+            &bytes,
+            path,
+            lossy,
+            &format!("二进制数据 (子类型 0x{:02X})", subtype),
+        ),
+        BsonValue::DateTime(millis) => {
+            report(
+                lossy,
+                path,
+                LossyKind::Date,
+                "BSON 日期时间已转换为 RFC 3339 字符串".to_string(),
+            );
+            match chrono::DateTime::from_timestamp_millis(millis) {
+                Some(time) => {
+                    Value::String(time.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+                }
+                None => Value::from(millis),
+            }
+        }
+        BsonValue::Decimal128(bytes) => {
+            report(
+                lossy,
+                path,
+                LossyKind::Number,
+                "Decimal128 已转换为字符串".to_string(),
+            );
+            Value::String(bson::decimal128_to_string(&bytes))
+        }
+        BsonValue::ObjectId(id) => {
+            extension(lossy, "ObjectId 已转换为十六进制字符串");
+            Value::String(hex::encode(id))
+        }
+        BsonValue::Regex { pattern, options } => {
+            extension(lossy, "正则表达式已转换为 /pattern/options 字符串");
+            Value::String(format!("/{}/{}", pattern, options))
+        }
+        BsonValue::Timestamp { time, increment } => {
+            extension(lossy, "BSON 时间戳已转换为 {time, increment} 对象");
+            let mut map = Map::new();
+            map.insert("time".to_string(), Value::from(time));
+            map.insert("increment".to_string(), Value::from(increment));
+            Value::Object(map)
+        }
+        BsonValue::JavaScript(code) | BsonValue::Symbol(code) => {
+            extension(lossy, "JavaScript 代码/符号已转换为字符串");
+            Value::String(code)
+        }
+        BsonValue::JavaScriptWithScope { code, scope } => {
+            extension(
+                lossy,
+                "带作用域的 JavaScript 代码已转换为 {code, scope} 对象",
+            );
+            let scope = bson_to_json(
+                BsonValue::Document(scope),
+                &format!("{}.scope", path),
+                lossy,
+            );
+            let mut map = Map::new();
+            map.insert("code".to_string(), Value::String(code));
+            map.insert("scope".to_string(), scope);
+            Value::Object(map)
+        }
+        BsonValue::Undefined | BsonValue::MaxKey | BsonValue::MinKey | BsonValue::DbPointer => {
+            extension(lossy, "undefined/MinKey/MaxKey/DBPointer 已转换为 null");
+            Value::Null
+        }
+    }
+}
+
+/// 将 JSON 值转换为 MessagePack 值
+fn json_to_msgpack(value: &Value) -> rmpv::Value {
+    match value {
+        Value::Null => rmpv::Value::Nil,
+        Value::Bool(b) => rmpv::Value::Boolean(*b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                rmpv::Value::from(i)
+            } else if let Some(u) = n.as_u64() {
+                rmpv::Value::from(u)
+            } else {
+                rmpv::Value::F64(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::String(s) => rmpv::Value::from(s.as_str()),
+        Value::Array(items) => rmpv::Value::Array(items.iter().map(json_to_msgpack).collect()),
+        Value::Object(map) => rmpv::Value::Map(
+            map.iter()
+                .map(|(key, child)| (rmpv::Value::from(key.as_str()), json_to_msgpack(child)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn decode(bytes: &[u8], format: BinaryFormat) -> (Value, Vec<LossyValue>) {
+        match decode_bytes(bytes, format, 2) {
+            BinaryDecodeResult::Success { output, lossy, .. } => {
+                (serde_json::from_str(&output).unwrap(), lossy)
+            }
+            BinaryDecodeResult::Error { message } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn encode_hex(input: &str, format: BinaryFormat) -> String {
+        match encode_text(input, format, BinaryTextEncoding::Hex) {
+            ConversionResult::Success { output, .. } => output,
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_all_formats() {
+        let input = json!({
+            "id": 42,
+            "big": 18446744073709551615u64,
+            "neg": -7,
+            "ratio": 0.5,
+            "name": "测试",
+            "tags": ["a", null, true],
+            "nested": {"empty": {}}
+        });
+        for format in [BinaryFormat::Msgpack, BinaryFormat::Cbor] {
+            let hex = encode_hex(&input.to_string(), format);
+            let (value, lossy) = decode(&hex::decode(hex).unwrap(), format);
+            assert_eq!(value, input, "{:?}", format);
+            assert!(lossy.is_empty());
+        }
+
+        // BSON 不支持 u64
+        let mut bson_input = input.clone();
+        bson_input.as_object_mut().unwrap().remove("big");
+        let hex = encode_hex(&bson_input.to_string(), BinaryFormat::Bson);
+        let (value, _) = decode(&hex::decode(hex).unwrap(), BinaryFormat::Bson);
+        assert_eq!(value, bson_input);
+    }
+
+    #[test]
+    fn test_msgpack_known_bytes() {
+        // {"a": 1} 的标准编码
+        assert_eq!(encode_hex(r#"{"a":1}"#, BinaryFormat::Msgpack), "81a16101");
+        assert_eq!(encode_hex(r#"{"a":1}"#, BinaryFormat::Cbor), "a1616101");
+    }
+
+    #[test]
+    fn test_msgpack_lossy_report() {
+        // {"bin": <bin 2>, "ext": <ext 5>, "ts": <timestamp32 0>, 1: "int key"}
+        let bytes = [
+            0x84, 0xa3, b'b', b'i', b'n', 0xc4, 0x02, 0xde, 0xad, 0xa3, b'e', b'x', b't', 0xd4,
+            0x05, 0x01, 0xa2, b't', b's', 0xd6, 0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0xa7, b'i',
+            b'n', b't', b' ', b'k', b'e', b'y',
+        ];
+        let (value, lossy) = decode(&bytes, BinaryFormat::Msgpack);
+        assert_eq!(value["bin"], json!("3q0="));
+        assert_eq!(value["ext"], json!("AQ=="));
+        assert_eq!(value["ts"], json!("1970-01-01T00:00:00Z"));
+        assert_eq!(value["1"], json!("int key"));
+
+        let kinds: Vec<(String, LossyKind)> = lossy.into_iter().map(|l| (l.path, l.kind)).collect();
+        assert!(kinds.contains(&("$.bin".to_string(), LossyKind::Binary)));
+        assert!(kinds.contains(&("$.ext".to_string(), LossyKind::Extension)));
+        assert!(kinds.contains(&("$.ts".to_string(), LossyKind::Date)));
+        assert!(kinds.contains(&("$".to_string(), LossyKind::Key)));
+    }
+
+    #[test]
+    fn test_cbor_tags() {
+        // [1(0), 2(h'010000000000000000'), 0("2024-01-01T00:00:00Z"), h'ff', NaN]
+        let mut bytes = vec![0x85, 0xc1, 0x00, 0xc2, 0x49, 0x01];
+        bytes.extend([0u8; 8]);
+        bytes.extend([0xc0, 0x74]);
+        bytes.extend(b"2024-01-01T00:00:00Z");
+        bytes.extend([0x41, 0xff, 0xf9, 0x7e, 0x00]);
+
+        let (value, lossy) = decode(&bytes, BinaryFormat::Cbor);
+        assert_eq!(
+            value,
+            json!([
+                "1970-01-01T00:00:00Z",
+                "18446744073709551616",
+                "2024-01-01T00:00:00Z",
+                "/w==",
+                null
+            ])
+        );
+        let kinds: Vec<LossyKind> = lossy.iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LossyKind::Date,
+                LossyKind::Number,
+                LossyKind::Date,
+                LossyKind::Binary,
+                LossyKind::NonFiniteFloat
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bson_special_types() {
+        // {"_id": ObjectId, "at": DateTime(0), "data": Binary(0x00, [1, 2])}
+        let mut bytes = vec![0u8; 4];
+        bytes.extend(b"\x07_id\x00");
+        bytes.extend(hex::decode("65a1b2c3d4e5f60718293a4b").unwrap());
+        bytes.extend(b"\x09at\x00");
+        bytes.extend(0i64.to_le_bytes());
+        bytes.extend(b"\x05data\x00\x02\x00\x00\x00\x00\x01\x02");
+        bytes.push(0);
+        let length = bytes.len() as i32;
+        bytes[..4].copy_from_slice(&length.to_le_bytes());
+
+        let (value, lossy) = decode(&bytes, BinaryFormat::Bson);
+        assert_eq!(value["_id"], json!("65a1b2c3d4e5f60718293a4b"));
+        assert_eq!(value["at"], json!("1970-01-01T00:00:00Z"));
+        assert_eq!(value["data"], json!("AQI="));
+        assert_eq!(lossy.len(), 3);
+    }
+
+    #[test]
+    fn test_text_input_encodings() {
+        match decode_text(
+            "gaFhAQ",
+            BinaryTextEncoding::Base64,
+            BinaryFormat::Msgpack,
+            2,
+        ) {
+            BinaryDecodeResult::Success { output, .. } => assert!(output.contains("\"a\": 1")),
+            BinaryDecodeResult::Error { message } => panic!("{}", message),
+        }
+        match decode_text(
+            "0x81 a1 61 01",
+            BinaryTextEncoding::Hex,
+            BinaryFormat::Msgpack,
+            2,
+        ) {
+            BinaryDecodeResult::Success { size, .. } => assert_eq!(size, 4),
+            BinaryDecodeResult::Error { message } => panic!("{}", message),
+        }
+        assert!(matches!(
+            decode_text("zz", BinaryTextEncoding::Hex, BinaryFormat::Msgpack, 2),
+            BinaryDecodeResult::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // 截断的数据
+        assert!(matches!(
+            decode_bytes(&[0x82, 0xa1], BinaryFormat::Msgpack, 2),
+            BinaryDecodeResult::Error { .. }
+        ));
+        // 多余的字节
+        match decode_bytes(&[0x01, 0x02], BinaryFormat::Cbor, 2) {
+            BinaryDecodeResult::Error { message } => assert!(message.contains("1 字节未解码")),
+            _ => panic!("Expected Error"),
+        }
+        // BSON 根节点必须是对象
+        assert!(matches!(
+            encode_text("[1]", BinaryFormat::Bson, BinaryTextEncoding::Base64),
+            ConversionResult::Error { .. }
+        ));
+    }
+}
@@ -0,0 +1,407 @@
+//! BSON 读写
+//! 按 BSON 1.1 规范 (bsonspec.org) 解析与生成文档
+//!
+//! 不使用 `bson` crate：它会开启 serde_json 的 `preserve_order` 特性，改变全局的键排序行为
+
+use serde_json::{Map, Value};
+
+/// 文档最大嵌套深度
+const MAX_DEPTH: usize = 128;
+
+/// BSON 值
+#[derive(Debug, Clone, PartialEq)]
+pub enum BsonValue {
+    Double(f64),
+    String(String),
+    Document(Vec<(String, BsonValue)>),
+    Array(Vec<BsonValue>),
+    Binary {
+        subtype: u8,
+        bytes: Vec<u8>,
+    },
+    Undefined,
+    ObjectId([u8; 12]),
+    Boolean(bool),
+    /// UTC 毫秒时间戳
+    DateTime(i64),
+    Null,
+    Regex {
+        pattern: String,
+        options: String,
+    },
+    DbPointer,
+    JavaScript(String),
+    Symbol(String),
+    JavaScriptWithScope {
+        code: String,
+        scope: Vec<(String, BsonValue)>,
+    },
+    Int32(i32),
+    Timestamp {
+        time: u32,
+        increment: u32,
+    },
+    Int64(i64),
+    Decimal128([u8; 16]),
+    MinKey,
+    MaxKey,
+}
+
+/// 解析一个 BSON 文档
+///
+/// # 返回
+/// 文档内容和实际读取的字节数
+pub fn read_document(bytes: &[u8]) -> Result<(Vec<(String, BsonValue)>, usize), String> {
+    let mut reader = Reader { bytes, position: 0 };
+    let document = reader.document(0)?;
+    Ok((document, reader.position))
+}
+
+/// 将 JSON 对象写为 BSON 文档
+///
+/// 整数按范围写为 int32/int64，超出 int64 的整数报错
+pub fn write_document(map: &Map<String, Value>) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    write_map(&mut out, &mut map.iter().map(|(k, v)| (k.clone(), v)), "$")?;
+    Ok(out)
+}
+
+/// 按 IEEE 754-2008 BID 编码将 Decimal128 转换为字符串
+pub fn decimal128_to_string(bytes: &[u8; 16]) -> String {
+    let bits = u128::from_le_bytes(*bytes);
+    let negative = bits >> 127 == 1;
+    let sign = if negative { "-" } else { "" };
+
+    let combination = (bits >> 122) & 0x1f;
+    if combination == 0x1f {
+        return "NaN".to_string();
+    }
+    if combination == 0x1e {
+        return format!("{}Infinity", sign);
+    }
+
+    let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+        // 系数超出 113 位的编码是非规范的，按 0 处理
+        (((bits >> 111) & 0x3fff) as i32, 0u128)
+    } else {
+        (((bits >> 113) & 0x3fff) as i32, bits & ((1u128 << 113) - 1))
+    };
+    let exponent = exponent - 6176;
+    let digits = coefficient.to_string();
+    let adjusted = exponent + digits.len() as i32 - 1;
+
+    if exponent <= 0 && adjusted >= -6 {
+        if exponent == 0 {
+            return format!("{}{}", sign, digits);
+        }
+        let scale = (-exponent) as usize;
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (int_part, frac_part) = padded.split_at(padded.len() - scale);
+        return format!("{}{}.{}", sign, int_part, frac_part);
+    }
+
+    let mantissa = if digits.len() > 1 {
+        format!("{}.{}", &digits[..1], &digits[1..])
+    } else {
+        digits
+    };
+    format!("{}{}E{:+}", sign, mantissa, adjusted)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() - self.position < n {
+            return Err(format!("BSON 数据在偏移 {} 处意外结束", self.position));
+        }
+        let bytes: &'a [u8] = self.bytes;
+        let slice = &bytes[self.position..self.position + n];
+        self.position += n;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn cstring(&mut self) -> Result<String, String> {
+        let start = self.position;
+        let end = self.bytes[start..]
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or_else(|| format!("BSON 数据在偏移 {} 处缺少字符串结束符", start))?;
+        let text = utf8(start, &self.bytes[start..start + end])?;
+        self.position = start + end + 1;
+        Ok(text)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let start = self.position;
+        let length = self.i32()?;
+        if length < 1 {
+            return Err(format!("BSON 字符串在偏移 {} 处的长度无效", start));
+        }
+        let data = self.take(length as usize)?;
+        if data[data.len() - 1] != 0 {
+            return Err(format!("BSON 字符串在偏移 {} 处缺少结束符", start));
+        }
+        utf8(start, &data[..data.len() - 1])
+    }
+
+    fn document(&mut self, depth: usize) -> Result<Vec<(String, BsonValue)>, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("BSON 文档嵌套超过 {} 层", MAX_DEPTH));
+        }
+
+        let start = self.position;
+        let length = self.i32()?;
+        if length < 5 || start + length as usize > self.bytes.len() {
+            return Err(format!(
+                "BSON 文档在偏移 {} 处的长度 {} 无效",
+                start, length
+            ));
+        }
+        let end = start + length as usize;
+
+        let mut elements = Vec::new();
+        loop {
+            let element_type = self.byte()?;
+            if element_type == 0 {
+                break;
+            }
+            let key = self.cstring()?;
+            let value = self.value(element_type, depth)?;
+            elements.push((key, value));
+        }
+
+        if self.position != end {
+            return Err(format!(
+                "BSON 文档在偏移 {} 处声明的长度与实际内容不一致",
+                start
+            ));
+        }
+        Ok(elements)
+    }
+
+    fn value(&mut self, element_type: u8, depth: usize) -> Result<BsonValue, String> {
+        Ok(match element_type {
+            0x01 => BsonValue::Double(f64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            0x02 => BsonValue::String(self.string()?),
+            0x03 => BsonValue::Document(self.document(depth + 1)?),
+            0x04 => BsonValue::Array(
+                self.document(depth + 1)?
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .collect(),
+            ),
+            0x05 => {
+                let length = self.i32()?;
+                if length < 0 {
+                    return Err(format!(
+                        "BSON 二进制数据在偏移 {} 处的长度无效",
+                        self.position
+                    ));
+                }
+                let subtype = self.byte()?;
+                let bytes = self.take(length as usize)?.to_vec();
+                BsonValue::Binary { subtype, bytes }
+            }
+            0x06 => BsonValue::Undefined,
+            0x07 => BsonValue::ObjectId(self.take(12)?.try_into().unwrap()),
+            0x08 => BsonValue::Boolean(self.byte()? != 0),
+            0x09 => BsonValue::DateTime(self.i64()?),
+            0x0A => BsonValue::Null,
+            0x0B => BsonValue::Regex {
+                pattern: self.cstring()?,
+                options: self.cstring()?,
+            },
+            0x0C => {
+                self.string()?;
+                self.take(12)?;
+                BsonValue::DbPointer
+            }
+            0x0D => BsonValue::JavaScript(self.string()?),
+            0x0E => BsonValue::Symbol(self.string()?),
+            0x0F => {
+                self.i32()?;
+                BsonValue::JavaScriptWithScope {
+                    code: self.string()?,
+                    scope: self.document(depth + 1)?,
+                }
+            }
+            0x10 => BsonValue::Int32(self.i32()?),
+            0x11 => {
+                let raw = self.i64()? as u64;
+                BsonValue::Timestamp {
+                    time: (raw >> 32) as u32,
+                    increment: raw as u32,
+                }
+            }
+            0x12 => BsonValue::Int64(self.i64()?),
+            0x13 => BsonValue::Decimal128(self.take(16)?.try_into().unwrap()),
+            0xFF => BsonValue::MinKey,
+            0x7F => BsonValue::MaxKey,
+            other => {
+                return Err(format!(
+                    "偏移 {} 处存在未知的 BSON 类型 0x{:02X}",
+                    self.position - 1,
+                    other
+                ));
+            }
+        })
+    }
+}
+
+fn utf8(offset: usize, data: &[u8]) -> Result<String, String> {
+    String::from_utf8(data.to_vec())
+        .map_err(|_| format!("BSON 字符串在偏移 {} 处不是有效的 UTF-8", offset))
+}
+
+/// 写入文档：先占位长度，写完后回填
+fn write_map(
+    out: &mut Vec<u8>,
+    entries: &mut dyn Iterator<Item = (String, &Value)>,
+    path: &str,
+) -> Result<(), String> {
+    let start = out.len();
+    out.extend_from_slice(&[0; 4]);
+
+    for (key, value) in entries {
+        let child_path = format!("{}.{}", path, key);
+        if key.contains('\0') {
+            return Err(format!("{} 的键包含空字符，BSON 不支持", child_path));
+        }
+
+        let type_position = out.len();
+        out.push(0);
+        out.extend_from_slice(key.as_bytes());
+        out.push(0);
+
+        out[type_position] = match value {
+            Value::Null => 0x0A,
+            Value::Bool(b) => {
+                out.push(*b as u8);
+                0x08
+            }
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    match i32::try_from(i) {
+                        Ok(small) => {
+                            out.extend_from_slice(&small.to_le_bytes());
+                            0x10
+                        }
+                        Err(_) => {
+                            out.extend_from_slice(&i.to_le_bytes());
+                            0x12
+                        }
+                    }
+                } else if n.is_u64() {
+                    return Err(format!(
+                        "{} 处的整数 {} 超出 BSON 支持的 64 位有符号整数范围",
+                        child_path, n
+                    ));
+                } else {
+                    out.extend_from_slice(&n.as_f64().unwrap_or(f64::NAN).to_le_bytes());
+                    0x01
+                }
+            }
+            Value::String(s) => {
+                out.extend_from_slice(&(s.len() as i32 + 1).to_le_bytes());
+                out.extend_from_slice(s.as_bytes());
+                out.push(0);
+                0x02
+            }
+            Value::Array(items) => {
+                write_map(
+                    out,
+                    &mut items.iter().enumerate().map(|(i, v)| (i.to_string(), v)),
+                    &child_path,
+                )?;
+                0x04
+            }
+            Value::Object(map) => {
+                write_map(
+                    out,
+                    &mut map.iter().map(|(k, v)| (k.clone(), v)),
+                    &child_path,
+                )?;
+                0x03
+            }
+        };
+    }
+
+    out.push(0);
+    let length = (out.len() - start) as i32;
+    out[start..start + 4].copy_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_known_encoding() {
+        // bsonspec.org 示例: {"hello": "world"}
+        let bytes = write_document(json!({"hello": "world"}).as_object().unwrap()).unwrap();
+        assert_eq!(
+            bytes,
+            b"\x16\x00\x00\x00\x02hello\x00\x06\x00\x00\x00world\x00\x00".to_vec()
+        );
+
+        let (document, read) = read_document(&bytes).unwrap();
+        assert_eq!(read, bytes.len());
+        assert_eq!(
+            document,
+            vec![("hello".to_string(), BsonValue::String("world".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_integer_widths() {
+        let bytes =
+            write_document(json!({"a": 1, "b": 5_000_000_000i64}).as_object().unwrap()).unwrap();
+        let (document, _) = read_document(&bytes).unwrap();
+        assert_eq!(document[0].1, BsonValue::Int32(1));
+        assert_eq!(document[1].1, BsonValue::Int64(5_000_000_000));
+
+        let error = write_document(json!({"n": u64::MAX}).as_object().unwrap()).unwrap_err();
+        assert!(error.contains("$.n"));
+    }
+
+    #[test]
+    fn test_truncated_and_corrupt() {
+        let bytes = write_document(json!({"a": [1, 2]}).as_object().unwrap()).unwrap();
+        assert!(read_document(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut corrupt = bytes.clone();
+        corrupt[4] = 0x42;
+        assert!(read_document(&corrupt).unwrap_err().contains("0x42"));
+    }
+
+    #[test]
+    fn test_decimal128_to_string() {
+        // 1.5 = 15 x 10^-1
+        let mut bits = 15u128 | ((6176u128 - 1) << 113);
+        assert_eq!(decimal128_to_string(&bits.to_le_bytes()), "1.5");
+
+        bits = 1u128 | ((6176u128 + 3) << 113) | (1u128 << 127);
+        assert_eq!(decimal128_to_string(&bits.to_le_bytes()), "-1E+3");
+
+        bits = 0x1fu128 << 122;
+        assert_eq!(decimal128_to_string(&bits.to_le_bytes()), "NaN");
+    }
+}
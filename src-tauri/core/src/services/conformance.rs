@@ -0,0 +1,211 @@
+//! 严格模式一致性检查
+//! 在 serde_json 解析之前扫描原始文本，找出 serde_json 容忍或无法准确报告的问题：
+//! BOM、孤立代理项、超出 IEEE 754 双精度范围的数字，以及 I-JSON (RFC 7493) 不允许的内容
+
+use crate::models::validation::StrictMode;
+
+/// I-JSON 允许的最大整数绝对值 (2^53 - 1)
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// 一致性问题及其位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub message: String,
+    /// 行号（从 1 开始）
+    pub line: usize,
+    /// 列号（从 1 开始，按字符计）
+    pub column: usize,
+}
+
+/// 按严格模式检查 JSON 文本，返回第一个问题
+///
+/// 语法错误不在这里报告，交给随后的 serde_json 解析
+pub fn check_conformance(input: &str, mode: StrictMode) -> Option<Violation> {
+    if mode == StrictMode::Off {
+        return None;
+    }
+
+    let mut scanner = Scanner {
+        input,
+        bytes: input.as_bytes(),
+        pos: 0,
+        line: 1,
+        column: 1,
+        ijson: mode == StrictMode::IJson,
+    };
+    if input.starts_with('\u{feff}') {
+        return Some(scanner.violation(
+            "输入以字节顺序标记 (BOM, U+FEFF) 开头，RFC 8259 不允许 JSON 文本包含 BOM".to_string(),
+        ));
+    }
+    scanner.scan().err()
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    column: usize,
+    ijson: bool,
+}
+
+impl Scanner<'_> {
+    fn scan(&mut self) -> Result<(), Violation> {
+        while self.pos < self.bytes.len() {
+            match self.bytes[self.pos] {
+                b'"' => {
+                    self.advance(1);
+                    self.string()?;
+                }
+                b'-' | b'0'..=b'9' => self.number()?,
+                _ => self.advance(1),
+            }
+        }
+        Ok(())
+    }
+
+    /// 扫描字符串内容 (开引号之后)，直到闭引号
+    fn string(&mut self) -> Result<(), Violation> {
+        while self.pos < self.bytes.len() {
+            match self.bytes[self.pos] {
+                b'"' => {
+                    self.advance(1);
+                    return Ok(());
+                }
+                b'\\' if self.bytes.get(self.pos + 1) == Some(&b'u') => self.unicode_escape()?,
+                b'\\' => self.advance(2),
+                byte if byte >= 0x80 => {
+                    let ch = self.input[self.pos..].chars().next().unwrap_or_default();
+                    if self.ijson && is_noncharacter(ch as u32) {
+                        return Err(self.noncharacter(ch as u32));
+                    }
+                    self.advance(ch.len_utf8());
+                }
+                _ => self.advance(1),
+            }
+        }
+        Ok(())
+    }
+
+    /// 检查 `\uXXXX` 转义 (可能是代理对)
+    fn unicode_escape(&mut self) -> Result<(), Violation> {
+        let Some(unit) = self.hex_unit(self.pos) else {
+            // 非法的转义交给 serde_json 报告
+            self.advance(2);
+            return Ok(());
+        };
+
+        let code = match unit {
+            0xD800..=0xDBFF => match self.hex_unit(self.pos + 6) {
+                Some(low @ 0xDC00..=0xDFFF) => {
+                    0x10000 + ((unit as u32 - 0xD800) << 10) + (low as u32 - 0xDC00)
+                }
+                _ => return Err(self.lone_surrogate(unit)),
+            },
+            0xDC00..=0xDFFF => return Err(self.lone_surrogate(unit)),
+            _ => unit as u32,
+        };
+        if self.ijson && is_noncharacter(code) {
+            return Err(self.noncharacter(code));
+        }
+
+        self.advance(if code > 0xFFFF { 12 } else { 6 });
+        Ok(())
+    }
+
+    /// 读取 `at` 处的 `\uXXXX` 转义的码元
+    fn hex_unit(&self, at: usize) -> Option<u16> {
+        let escape = self.bytes.get(at..at + 6)?;
+        if &escape[..2] != b"\\u" {
+            return None;
+        }
+        let hex = std::str::from_utf8(&escape[2..]).ok()?;
+        u16::from_str_radix(hex, 16).ok()
+    }
+
+    fn number(&mut self) -> Result<(), Violation> {
+        let start = self.pos;
+        let end = self.bytes[start..]
+            .iter()
+            .position(|b| !matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+            .map_or(self.bytes.len(), |offset| start + offset);
+        let literal = &self.input[start..end];
+
+        if let Some(message) = check_number(literal, self.ijson) {
+            return Err(self.violation(message));
+        }
+        self.advance(end - start);
+        Ok(())
+    }
+
+    fn lone_surrogate(&self, unit: u16) -> Violation {
+        self.violation(format!(
+            "字符串包含孤立的 UTF-16 代理项 \\u{:04x}，无法转换为合法的 Unicode 字符",
+            unit
+        ))
+    }
+
+    fn noncharacter(&self, code: u32) -> Violation {
+        self.violation(format!(
+            "字符串包含 Unicode 非字符 U+{:04X}，I-JSON 不允许",
+            code
+        ))
+    }
+
+    fn violation(&self, message: String) -> Violation {
+        Violation {
+            message,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// 前进 `count` 个字节，更新行列号
+    fn advance(&mut self, count: usize) {
+        let end = (self.pos + count).min(self.bytes.len());
+        for &byte in &self.bytes[self.pos..end] {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if byte & 0xC0 != 0x80 {
+                self.column += 1;
+            }
+        }
+        self.pos = end;
+    }
+}
+
+/// 检查数字字面量是否能用 IEEE 754 双精度表示
+fn check_number(literal: &str, ijson: bool) -> Option<String> {
+    // 语法错误交给 serde_json 报告
+    let value: f64 = literal.parse().ok()?;
+
+    if value.is_infinite() {
+        return Some(format!("数字 {} 超出 IEEE 754 双精度浮点数的范围", literal));
+    }
+    let mantissa = literal.split(['e', 'E']).next().unwrap_or_default();
+    if value == 0.0 && mantissa.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        return Some(format!(
+            "数字 {} 的绝对值过小，超出 IEEE 754 双精度浮点数的范围 (会被舍入为 0)",
+            literal
+        ));
+    }
+
+    let is_integer = !literal.contains(['.', 'e', 'E']);
+    if ijson && is_integer {
+        let digits = literal.trim_start_matches('-');
+        if !digits.parse::<u64>().is_ok_and(|n| n <= MAX_SAFE_INTEGER) {
+            return Some(format!(
+                "整数 {} 超出 I-JSON 允许的范围 [-(2^53)+1, (2^53)-1]，其他语言可能丢失精度",
+                literal
+            ));
+        }
+    }
+    None
+}
+
+/// Unicode 非字符 (U+FDD0..U+FDEF 以及每个平面的最后两个码位)
+fn is_noncharacter(code: u32) -> bool {
+    (0xFDD0..=0xFDEF).contains(&code) || code & 0xFFFE == 0xFFFE
+}
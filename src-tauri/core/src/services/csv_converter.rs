@@ -0,0 +1,616 @@
+//! CSV 转换服务
+//! 将对象数组展开为 CSV/TSV (嵌套字段使用点号路径作为列名)，以及将 CSV 解析回 JSON 并推断类型
+
+use crate::models::conversion::{
+    ConversionResult, CsvArrayHandling, CsvHeaderOrder, CsvQuoting, CsvToJsonOptions,
+    JsonToCsvOptions,
+};
+use crate::services::json_formatter::pretty_print;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 将 JSON 对象数组转换为 CSV/TSV
+///
+/// # Arguments
+/// * `input` - JSON 字符串，根节点为对象数组 (单个对象视为一行)
+/// * `options` - 分隔符、引号策略、列顺序与数组展开方式
+///
+/// # Returns
+/// 转换结果；JSON 解析失败时错误包含行列位置
+pub fn json_to_csv(input: &str, options: &JsonToCsvOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let value = match serde_json::from_str::<Value>(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return ConversionResult::Error {
+                message: format!("JSON 解析失败: {}", e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            };
+        }
+    };
+
+    let items = match value {
+        Value::Array(items) => items,
+        object @ Value::Object(_) => vec![object],
+        _ => return ConversionResult::error("CSV 转换需要对象数组作为根节点"),
+    };
+    if items.is_empty() {
+        return ConversionResult::error("数组为空，没有可导出的行");
+    }
+
+    let mut warnings = Vec::new();
+    let mut columns = Vec::new();
+    let mut seen = HashSet::new();
+    let mut rows = Vec::with_capacity(items.len());
+
+    for (index, item) in items.iter().enumerate() {
+        let map = match item {
+            Value::Object(map) => map,
+            other => {
+                return ConversionResult::error(format!(
+                    "$[{}] 不是对象 (当前为 {})，无法作为表格的一行",
+                    index,
+                    type_name(other)
+                ));
+            }
+        };
+
+        let mut row = Vec::new();
+        flatten_object(map, "", options.array_handling, &mut row, &mut warnings);
+        let mut cells = HashMap::with_capacity(row.len());
+        for (column, text) in row {
+            if seen.insert(column.clone()) {
+                columns.push(column.clone());
+            }
+            // 含点号的键 (如 "a.b") 与嵌套路径 a → b 展开后得到同一列名
+            if cells.insert(column.clone(), text).is_some() {
+                let warning = format!(
+                    "列 {} 重复 (含点号的键与嵌套路径冲突)，后出现的值覆盖了先前的值",
+                    column
+                );
+                if !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+        }
+        rows.push(cells);
+    }
+
+    if !options.columns.is_empty() {
+        for column in &options.columns {
+            if !seen.contains(column) {
+                warnings.push(format!("列 {} 在所有行中都不存在，将输出为空列", column));
+            }
+        }
+        columns = options.columns.clone();
+    } else if options.header_order == CsvHeaderOrder::Alphabetical {
+        columns.sort();
+    }
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(options.delimiter.as_byte())
+        .quote_style(quote_style(options.quoting))
+        .from_writer(Vec::new());
+
+    let result = (|| -> Result<(), csv::Error> {
+        if options.include_header {
+            writer.write_record(&columns)?;
+        }
+        for row in &rows {
+            writer.write_record(
+                columns
+                    .iter()
+                    .map(|column| row.get(column).map(String::as_str).unwrap_or("")),
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    })();
+    if let Err(e) = result {
+        return ConversionResult::error(format!("CSV 写入失败: {}", e));
+    }
+
+    let output = match writer.into_inner() {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Err(e) => return ConversionResult::error(format!("CSV 写入失败: {}", e)),
+    };
+
+    ConversionResult::Success {
+        size: output.len(),
+        output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings,
+    }
+}
+
+/// 将 CSV/TSV 转换为 JSON 数组
+///
+/// # Arguments
+/// * `input` - CSV 文本
+/// * `options` - 分隔符、表头、类型推断与嵌套还原设置
+///
+/// # Returns
+/// 转换结果；CSV 解析失败时错误包含行号
+pub fn csv_to_json(input: &str, options: &CsvToJsonOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter.as_byte())
+        .has_headers(options.has_header)
+        .from_reader(input.as_bytes());
+
+    let headers: Vec<String> = if options.has_header {
+        match reader.headers() {
+            Ok(headers) => headers.iter().map(str::to_string).collect(),
+            Err(e) => return csv_error(&e),
+        }
+    } else {
+        Vec::new()
+    };
+
+    let mut seen = HashSet::new();
+    for header in &headers {
+        if !seen.insert(header) {
+            return ConversionResult::Error {
+                message: format!("表头中存在重复的列名: {}", header),
+                line: Some(1),
+                column: None,
+            };
+        }
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => return csv_error(&e),
+        };
+        let line = record.position().map(|p| p.line() as usize);
+
+        if !options.has_header {
+            rows.push(Value::Array(
+                record
+                    .iter()
+                    .map(|cell| parse_cell(cell, options.infer_types))
+                    .collect(),
+            ));
+            continue;
+        }
+
+        let mut map = Map::new();
+        for (header, cell) in headers.iter().zip(record.iter()) {
+            let value = parse_cell(cell, options.infer_types);
+            if options.unflatten {
+                if let Err(message) = insert_path(&mut map, header, value) {
+                    return ConversionResult::Error {
+                        message,
+                        line,
+                        column: None,
+                    };
+                }
+            } else {
+                map.insert(header.clone(), value);
+            }
+        }
+
+        if options.unflatten {
+            map.values_mut().for_each(restore_arrays);
+        }
+        rows.push(Value::Object(map));
+    }
+
+    match pretty_print(&Value::Array(rows), options.indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            ConversionResult::Success {
+                size: output.len(),
+                output,
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                warnings: Vec::new(),
+            }
+        }
+        Err(message) => ConversionResult::error(message),
+    }
+}
+
+/// 将对象展开为 `(列名, 单元格文本)`，保持键的顺序
+fn flatten_object(
+    map: &Map<String, Value>,
+    prefix: &str,
+    arrays: CsvArrayHandling,
+    row: &mut Vec<(String, String)>,
+    warnings: &mut Vec<String>,
+) {
+    for (key, value) in map {
+        if key.contains('.') {
+            let warning = format!("键 \"{}\" 含有点号，转换回 JSON 时会被视为嵌套路径", key);
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        flatten_value(value, &join(prefix, key), arrays, row, warnings);
+    }
+}
+
+fn flatten_value(
+    value: &Value,
+    column: &str,
+    arrays: CsvArrayHandling,
+    row: &mut Vec<(String, String)>,
+    warnings: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) if !map.is_empty() => flatten_object(map, column, arrays, row, warnings),
+        Value::Array(items) if !items.is_empty() && arrays == CsvArrayHandling::Index => {
+            for (index, item) in items.iter().enumerate() {
+                flatten_value(
+                    item,
+                    &join(column, &index.to_string()),
+                    arrays,
+                    row,
+                    warnings,
+                );
+            }
+        }
+        other => {
+            row.push((column.to_string(), cell_text(other)));
+        }
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// 单元格文本：null 为空，字符串原样输出，其余使用 JSON 文本
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// 解析单元格，按需推断类型
+fn parse_cell(cell: &str, infer_types: bool) -> Value {
+    if !infer_types {
+        return Value::String(cell.to_string());
+    }
+
+    match cell {
+        "" => return Value::Null,
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+
+    let first = cell.as_bytes()[0];
+    if first == b'-' || first.is_ascii_digit() || first == b'[' || first == b'{' {
+        if let Ok(value) = serde_json::from_str::<Value>(cell) {
+            match &value {
+                // 超出 64 位范围的整数保留为字符串，避免丢失精度
+                Value::Number(n) if n.is_f64() && !cell.contains(['.', 'e', 'E']) => {}
+                Value::Number(_) | Value::Array(_) | Value::Object(_) => return value,
+                _ => {}
+            }
+        }
+    }
+
+    Value::String(cell.to_string())
+}
+
+/// 按点号路径插入值
+fn insert_path(map: &mut Map<String, Value>, column: &str, value: Value) -> Result<(), String> {
+    let segments: Vec<&str> = column.split('.').collect();
+    if segments.len() == 1 || segments.iter().any(|s| s.is_empty()) {
+        map.insert(column.to_string(), value);
+        return Ok(());
+    }
+
+    let conflict = || format!("列 {} 与其他列的路径冲突 (同一路径既是值又是对象)", column);
+    let (last, parents) = segments.split_last().unwrap();
+    let mut current = map;
+    for segment in parents {
+        let entry = current
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        current = match entry {
+            Value::Object(child) => child,
+            _ => return Err(conflict()),
+        };
+    }
+    if current.contains_key(*last) {
+        return Err(conflict());
+    }
+    current.insert(last.to_string(), value);
+    Ok(())
+}
+
+/// 键恰好为 0..n 的对象还原为数组
+fn restore_arrays(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for child in map.values_mut() {
+                restore_arrays(child);
+            }
+
+            let mut indices: Vec<usize> = map.keys().filter_map(|k| k.parse().ok()).collect();
+            if indices.is_empty() || indices.len() != map.len() {
+                return;
+            }
+            indices.sort_unstable();
+            if indices.iter().enumerate().any(|(i, &index)| i != index)
+                || map.keys().any(|k| k.len() > 1 && k.starts_with('0'))
+            {
+                return;
+            }
+
+            let items = indices
+                .iter()
+                .map(|index| map.remove(&index.to_string()).unwrap_or(Value::Null))
+                .collect();
+            *value = Value::Array(items);
+        }
+        Value::Array(items) => items.iter_mut().for_each(restore_arrays),
+        _ => {}
+    }
+}
+
+fn quote_style(quoting: CsvQuoting) -> csv::QuoteStyle {
+    match quoting {
+        CsvQuoting::Necessary => csv::QuoteStyle::Necessary,
+        CsvQuoting::Always => csv::QuoteStyle::Always,
+        CsvQuoting::NonNumeric => csv::QuoteStyle::NonNumeric,
+        CsvQuoting::Never => csv::QuoteStyle::Never,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "布尔值",
+        Value::Number(_) => "数字",
+        Value::String(_) => "字符串",
+        Value::Array(_) => "数组",
+        Value::Object(_) => "对象",
+    }
+}
+
+fn csv_error(error: &csv::Error) -> ConversionResult {
+    let message = match error.kind() {
+        csv::ErrorKind::UnequalLengths {
+            expected_len, len, ..
+        } => format!(
+            "CSV 解析失败: 该行有 {} 个字段，但前面的行有 {} 个字段",
+            len, expected_len
+        ),
+        csv::ErrorKind::Utf8 { .. } => "CSV 解析失败: 内容不是有效的 UTF-8".to_string(),
+        _ => format!("CSV 解析失败: {}", error),
+    };
+    ConversionResult::Error {
+        message,
+        line: error.position().map(|p| p.line() as usize),
+        column: None,
+    }
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::conversion::CsvDelimiter;
+    use serde_json::json;
+
+    fn to_csv(input: &str, options: &JsonToCsvOptions) -> String {
+        match json_to_csv(input, options) {
+            ConversionResult::Success { output, .. } => output,
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn to_json(input: &str, options: &CsvToJsonOptions) -> Value {
+        match csv_to_json(input, options) {
+            ConversionResult::Success { output, .. } => serde_json::from_str(&output).unwrap(),
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    #[test]
+    fn test_nested_fields_use_dotted_columns() {
+        let csv = to_csv(
+            r#"[{"id":1,"user":{"name":"a","geo":{"lat":1.5}},"tags":["x","y"]},{"id":2,"extra":null}]"#,
+            &JsonToCsvOptions::default(),
+        );
+        assert_eq!(
+            csv,
+            "id,tags,user.geo.lat,user.name,extra\n1,\"[\"\"x\"\",\"\"y\"\"]\",1.5,a,\n2,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_dotted_key_colliding_with_nested_path_warns() {
+        let result = json_to_csv(
+            r#"[{"a":{"b":1},"a.b":2},{"a":{"b":3}}]"#,
+            &JsonToCsvOptions::default(),
+        );
+        let ConversionResult::Success {
+            output, warnings, ..
+        } = result
+        else {
+            panic!("Expected Success result");
+        };
+        assert_eq!(output, "a.b\n2\n3\n");
+        assert!(warnings.iter().any(|w| w.contains("列 a.b 重复")));
+    }
+
+    #[test]
+    fn test_tsv_and_quoting() {
+        let options = JsonToCsvOptions {
+            delimiter: CsvDelimiter::Tab,
+            quoting: CsvQuoting::NonNumeric,
+            ..Default::default()
+        };
+        let tsv = to_csv(r#"[{"a":1,"b":"x,y"}]"#, &options);
+        assert_eq!(tsv, "\"a\"\t\"b\"\n1\t\"x,y\"\n");
+
+        let options = JsonToCsvOptions {
+            include_header: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_csv(r#"[{"a":"line\nbreak"}]"#, &options),
+            "\"line\nbreak\"\n"
+        );
+    }
+
+    #[test]
+    fn test_explicit_columns_and_order() {
+        let options = JsonToCsvOptions {
+            columns: vec!["b".to_string(), "a".to_string(), "missing".to_string()],
+            ..Default::default()
+        };
+        match json_to_csv(r#"[{"a":1,"b":2,"c":3}]"#, &options) {
+            ConversionResult::Success {
+                output, warnings, ..
+            } => {
+                assert_eq!(output, "b,a,missing\n2,1,\n");
+                assert_eq!(warnings.len(), 1);
+            }
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+
+        let options = JsonToCsvOptions {
+            header_order: CsvHeaderOrder::Alphabetical,
+            ..Default::default()
+        };
+        assert_eq!(to_csv(r#"[{"b":1},{"a":2}]"#, &options), "a,b\n,1\n2,\n");
+    }
+
+    #[test]
+    fn test_index_array_handling() {
+        let options = JsonToCsvOptions {
+            array_handling: CsvArrayHandling::Index,
+            ..Default::default()
+        };
+        let csv = to_csv(r#"[{"tags":["x","y"],"items":[{"n":1}]}]"#, &options);
+        assert_eq!(csv, "items.0.n,tags.0,tags.1\n1,x,y\n");
+
+        let value = to_json(&csv, &CsvToJsonOptions::default());
+        assert_eq!(value, json!([{"items": [{"n": 1}], "tags": ["x", "y"]}]));
+    }
+
+    #[test]
+    fn test_rows_must_be_objects() {
+        match json_to_csv("[{\"a\":1}, 2]", &JsonToCsvOptions::default()) {
+            ConversionResult::Error { message, .. } => assert!(message.contains("$[1]")),
+            _ => panic!("Expected Error"),
+        }
+        assert!(matches!(
+            json_to_csv("[]", &JsonToCsvOptions::default()),
+            ConversionResult::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn test_csv_type_inference() {
+        let value = to_json(
+            "id,name,active,score,zip,big,empty,list\n1,Ann,true,-2.5,007,123456789012345678901234,,\"[1,2]\"\n",
+            &CsvToJsonOptions::default(),
+        );
+        assert_eq!(
+            value,
+            json!([{
+                "id": 1,
+                "name": "Ann",
+                "active": true,
+                "score": -2.5,
+                "zip": "007",
+                "big": "123456789012345678901234",
+                "empty": null,
+                "list": [1, 2]
+            }])
+        );
+
+        let options = CsvToJsonOptions {
+            infer_types: false,
+            unflatten: false,
+            ..Default::default()
+        };
+        let value = to_json("a.b,c\n1,\n", &options);
+        assert_eq!(value, json!([{"a.b": "1", "c": ""}]));
+    }
+
+    #[test]
+    fn test_csv_without_header() {
+        let options = CsvToJsonOptions {
+            has_header: false,
+            delimiter: CsvDelimiter::Semicolon,
+            ..Default::default()
+        };
+        assert_eq!(to_json("1;x\n2;y\n", &options), json!([[1, "x"], [2, "y"]]));
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        match csv_to_json("a,b\n1,2\n3\n", &CsvToJsonOptions::default()) {
+            ConversionResult::Error { message, line, .. } => {
+                assert!(message.contains("字段"));
+                assert_eq!(line, Some(3));
+            }
+            _ => panic!("Expected Error"),
+        }
+        match csv_to_json("a,a\n1,2\n", &CsvToJsonOptions::default()) {
+            ConversionResult::Error { message, .. } => assert!(message.contains("重复")),
+            _ => panic!("Expected Error"),
+        }
+        match csv_to_json("a,a.b\n1,2\n", &CsvToJsonOptions::default()) {
+            ConversionResult::Error { message, line, .. } => {
+                assert!(message.contains("a.b"));
+                assert_eq!(line, Some(2));
+            }
+            _ => panic!("Expected Error"),
+        }
+    }
+
+    #[test]
+    fn test_json_csv_round_trip() {
+        let input = json!([
+            {"id": 1, "user": {"name": "a", "admin": false}, "note": "x, \"y\""},
+            {"id": 2, "user": {"name": "b", "admin": true}, "note": "multi\nline"}
+        ]);
+        let csv = to_csv(&input.to_string(), &JsonToCsvOptions::default());
+        assert_eq!(to_json(&csv, &CsvToJsonOptions::default()), input);
+    }
+}
@@ -0,0 +1,526 @@
+//! 大文档存储服务
+//! 在后端保存解析后的 JSON 树，按需分页返回子节点，避免通过 IPC 传输整个文档
+
+use crate::models::document::{ChildrenPage, DocumentInfo, JsonNodeType, NodeSummary, TypeCounts};
+use crate::services::operation::{OperationContext, OperationError, ProgressReader};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// 文档最大允许大小：1 GB
+const MAX_DOCUMENT_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// 标量预览的最大字符数
+const PREVIEW_MAX_CHARS: usize = 200;
+
+/// 单页最多返回的子节点数
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// 文档存储 (线程安全)
+#[derive(Default)]
+pub struct DocumentStore {
+    documents: Mutex<HashMap<String, StoredDocument>>,
+    counter: AtomicU64,
+}
+
+/// 已打开的文档及其节点索引缓存
+#[derive(Clone)]
+struct StoredDocument {
+    value: Arc<Value>,
+    /// 按 JSON Pointer 缓存的容器节点索引，文档修改后整体替换
+    indexes: Arc<Mutex<HashMap<String, Arc<NodeIndex>>>>,
+}
+
+impl StoredDocument {
+    fn new(value: Value) -> Self {
+        Self {
+            value: Arc::new(value),
+            indexes: Arc::default(),
+        }
+    }
+
+    /// 获取容器节点的索引，首次访问时遍历全部子节点建立
+    fn index(&self, pointer: &str, node: &Value) -> Arc<NodeIndex> {
+        if let Some(index) = self.indexes.lock().unwrap().get(pointer) {
+            return index.clone();
+        }
+        let index = Arc::new(NodeIndex::build(node));
+        self.indexes
+            .lock()
+            .unwrap()
+            .insert(pointer.to_string(), index.clone());
+        index
+    }
+}
+
+/// 容器节点的子节点索引
+struct NodeIndex {
+    /// 子节点类型计数
+    child_types: TypeCounts,
+    /// 对象的键 (按遍历顺序)，用于按偏移直接定位分页起点；数组为空
+    keys: Vec<String>,
+}
+
+impl NodeIndex {
+    fn build(node: &Value) -> Self {
+        match node {
+            Value::Object(map) => Self {
+                child_types: count_types(map.values()),
+                keys: map.keys().cloned().collect(),
+            },
+            Value::Array(items) => Self {
+                child_types: count_types(items.iter()),
+                keys: Vec::new(),
+            },
+            _ => Self {
+                child_types: TypeCounts::default(),
+                keys: Vec::new(),
+            },
+        }
+    }
+}
+
+impl DocumentStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 解析 JSON 文本并保存到存储中
+    pub fn open_text(
+        &self,
+        content: &str,
+        context: &OperationContext,
+    ) -> Result<DocumentInfo, OperationError> {
+        let start = Instant::now();
+        let value = parse_document(content.as_bytes(), context)?;
+        Ok(self.insert(value, content.len(), start))
+    }
+
+    /// 读取并解析 JSON 文件，保存到存储中 (不受导入大小限制)
+    pub fn open_file(
+        &self,
+        file_path: &str,
+        context: &OperationContext,
+    ) -> Result<DocumentInfo, OperationError> {
+        let start = Instant::now();
+        let path = Path::new(file_path);
+
+        if !path.is_file() {
+            return Err(format!("文件不存在: {}", file_path).into());
+        }
+
+        let metadata = std::fs::metadata(path).map_err(|e| format!("无法获取文件元数据: {}", e))?;
+        if metadata.len() > MAX_DOCUMENT_SIZE {
+            return Err(format!(
+                "文件太大 ({:.2} MB),最大支持 1024 MB",
+                metadata.len() as f64 / (1024.0 * 1024.0)
+            )
+            .into());
+        }
+
+        let bytes = std::fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+        let value = parse_document(&bytes, context)?;
+        Ok(self.insert(value, bytes.len(), start))
+    }
+
+    /// 获取节点摘要
+    pub fn get_node(&self, doc_id: &str, pointer: &str) -> Result<NodeSummary, String> {
+        let document = self.get(doc_id)?;
+        let node = resolve(&document.value, pointer)?;
+        Ok(summarize(node, pointer.to_string(), key_of(pointer), None))
+    }
+
+    /// 分页获取子节点摘要
+    ///
+    /// 子节点类型计数和对象键列表在首次访问节点时建立并缓存，
+    /// 之后翻页只处理本页的子节点
+    /// # 参数
+    /// * `doc_id` - 文档 ID
+    /// * `pointer` - 父节点的 JSON Pointer，根节点为空字符串
+    /// * `offset` - 起始偏移
+    /// * `limit` - 本页数量 (最多 1000)
+    pub fn get_children(
+        &self,
+        doc_id: &str,
+        pointer: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ChildrenPage, String> {
+        let document = self.get(doc_id)?;
+        let node = resolve(&document.value, pointer)?;
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let (total, children, index) = match node {
+            Value::Object(map) => {
+                let index = document.index(pointer, node);
+                let start = offset.min(index.keys.len());
+                let end = start.saturating_add(limit).min(index.keys.len());
+                let children = index.keys[start..end]
+                    .iter()
+                    .filter_map(|key| {
+                        let child = map.get(key)?;
+                        Some(summarize(
+                            child,
+                            child_pointer(pointer, key),
+                            Some(key.clone()),
+                            None,
+                        ))
+                    })
+                    .collect();
+                (map.len(), children, index)
+            }
+            Value::Array(items) => {
+                let start = offset.min(items.len());
+                let end = start.saturating_add(limit).min(items.len());
+                let children = items[start..end]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, child)| {
+                        let position = start + i;
+                        summarize(
+                            child,
+                            child_pointer(pointer, &position.to_string()),
+                            None,
+                            Some(position),
+                        )
+                    })
+                    .collect();
+                (items.len(), children, document.index(pointer, node))
+            }
+            _ => return Err(format!("节点不是对象或数组: {}", display_pointer(pointer))),
+        };
+
+        Ok(ChildrenPage {
+            pointer: pointer.to_string(),
+            total,
+            offset,
+            children,
+            child_types: index.child_types.clone(),
+        })
+    }
+
+    /// 就地修改文档，返回修改后节点的摘要
+    ///
+    /// # 参数
+    /// * `doc_id` - 文档 ID
+    /// * `pointer` - 修改后返回其摘要的节点
+    /// * `f` - 对整个文档执行的修改
+    pub fn modify<T>(
+        &self,
+        doc_id: &str,
+        pointer: &str,
+        f: impl FnOnce(&mut Value) -> Result<T, String>,
+    ) -> Result<(T, NodeSummary), String> {
+        let mut documents = self.documents.lock().unwrap();
+        let document = documents
+            .get_mut(doc_id)
+            .ok_or_else(|| format!("文档不存在或已关闭: {}", doc_id))?;
+
+        // 正在被读取的旧版本及其索引保持不变，新版本使用新的索引缓存
+        document.indexes = Arc::default();
+        let output = f(Arc::make_mut(&mut document.value))?;
+        let node = resolve(&document.value, pointer)?;
+        let summary = summarize(node, pointer.to_string(), key_of(pointer), None);
+        Ok((output, summary))
+    }
+
+    /// 关闭文档并释放内存
+    ///
+    /// # 返回
+    /// 文档存在时返回 `true`
+    pub fn close(&self, doc_id: &str) -> bool {
+        self.documents.lock().unwrap().remove(doc_id).is_some()
+    }
+
+    fn insert(&self, value: Value, size: usize, start: Instant) -> DocumentInfo {
+        let seq = self.counter.fetch_add(1, Ordering::SeqCst);
+        let doc_id = format!("doc-{}-{}", chrono::Utc::now().timestamp_millis(), seq);
+        let root = summarize(&value, String::new(), None, None);
+
+        self.documents
+            .lock()
+            .unwrap()
+            .insert(doc_id.clone(), StoredDocument::new(value));
+
+        DocumentInfo {
+            doc_id,
+            size,
+            root,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+        }
+    }
+
+    fn get(&self, doc_id: &str) -> Result<StoredDocument, String> {
+        self.documents
+            .lock()
+            .unwrap()
+            .get(doc_id)
+            .cloned()
+            .ok_or_else(|| format!("文档不存在或已关闭: {}", doc_id))
+    }
+}
+
+/// 解析文档内容
+fn parse_document(bytes: &[u8], context: &OperationContext) -> Result<Value, OperationError> {
+    let reader = ProgressReader::new(bytes, context, 0, 100);
+    let value = serde_json::from_reader(reader).map_err(|e| {
+        if e.is_io() && context.is_cancelled() {
+            OperationError::Cancelled
+        } else {
+            OperationError::Failed(format!(
+                "JSON 解析失败: {}（第 {} 行，第 {} 列）",
+                e,
+                e.line(),
+                e.column()
+            ))
+        }
+    })?;
+    context.report(100);
+    Ok(value)
+}
+
+/// 按 JSON Pointer 定位节点
+fn resolve<'a>(document: &'a Value, pointer: &str) -> Result<&'a Value, String> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(format!("无效的 JSON Pointer: {}", pointer));
+    }
+    document
+        .pointer(pointer)
+        .ok_or_else(|| format!("节点不存在: {}", display_pointer(pointer)))
+}
+
+/// 按 JSON Pointer 定位可修改的节点
+pub fn resolve_mut<'a>(document: &'a mut Value, pointer: &str) -> Result<&'a mut Value, String> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(format!("无效的 JSON Pointer: {}", pointer));
+    }
+    document
+        .pointer_mut(pointer)
+        .ok_or_else(|| format!("节点不存在: {}", display_pointer(pointer)))
+}
+
+/// 拼接子节点的 JSON Pointer (按 RFC 6901 转义 `~` 和 `/`)
+pub fn child_pointer(parent: &str, token: &str) -> String {
+    format!("{}/{}", parent, token.replace('~', "~0").replace('/', "~1"))
+}
+
+/// 取 JSON Pointer 的最后一段作为键名
+fn key_of(pointer: &str) -> Option<String> {
+    pointer
+        .rsplit_once('/')
+        .map(|(_, token)| token.replace("~1", "/").replace("~0", "~"))
+}
+
+fn display_pointer(pointer: &str) -> &str {
+    if pointer.is_empty() {
+        "(根节点)"
+    } else {
+        pointer
+    }
+}
+
+fn node_type(value: &Value) -> JsonNodeType {
+    match value {
+        Value::Object(_) => JsonNodeType::Object,
+        Value::Array(_) => JsonNodeType::Array,
+        Value::String(_) => JsonNodeType::String,
+        Value::Number(_) => JsonNodeType::Number,
+        Value::Bool(_) => JsonNodeType::Boolean,
+        Value::Null => JsonNodeType::Null,
+    }
+}
+
+fn count_types<'a>(values: impl Iterator<Item = &'a Value>) -> TypeCounts {
+    let mut counts = TypeCounts::default();
+    for value in values {
+        match node_type(value) {
+            JsonNodeType::Object => counts.object += 1,
+            JsonNodeType::Array => counts.array += 1,
+            JsonNodeType::String => counts.string += 1,
+            JsonNodeType::Number => counts.number += 1,
+            JsonNodeType::Boolean => counts.boolean += 1,
+            JsonNodeType::Null => counts.null += 1,
+        }
+    }
+    counts
+}
+
+fn summarize(
+    value: &Value,
+    pointer: String,
+    key: Option<String>,
+    index: Option<usize>,
+) -> NodeSummary {
+    let (child_count, preview, truncated) = match value {
+        Value::Object(map) => (Some(map.len()), None, false),
+        Value::Array(items) => (Some(items.len()), None, false),
+        Value::String(s) if s.chars().count() > PREVIEW_MAX_CHARS => {
+            let head: String = s.chars().take(PREVIEW_MAX_CHARS).collect();
+            (None, Some(Value::String(head).to_string()), true)
+        }
+        scalar => (None, Some(scalar.to_string()), false),
+    };
+
+    NodeSummary {
+        key,
+        index,
+        pointer,
+        node_type: node_type(value),
+        child_count,
+        preview,
+        truncated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(store: &DocumentStore, content: &str) -> DocumentInfo {
+        store
+            .open_text(content, &OperationContext::detached())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_open_text_summarizes_root() {
+        let store = DocumentStore::new();
+        let info = open(&store, r#"{"a": 1, "b": [1, 2, 3]}"#);
+
+        assert_eq!(info.root.node_type, JsonNodeType::Object);
+        assert_eq!(info.root.child_count, Some(2));
+        assert_eq!(info.root.pointer, "");
+    }
+
+    #[test]
+    fn test_open_invalid_json() {
+        let store = DocumentStore::new();
+        let result = store.open_text("{invalid}", &OperationContext::detached());
+        assert!(result.unwrap_err().to_string().contains("JSON 解析失败"));
+    }
+
+    #[test]
+    fn test_get_children_paging() {
+        let store = DocumentStore::new();
+        let items: Vec<String> = (0..50).map(|i| i.to_string()).collect();
+        let info = open(&store, &format!("{{\"items\": [{}]}}", items.join(",")));
+
+        let page = store.get_children(&info.doc_id, "/items", 10, 5).unwrap();
+        assert_eq!(page.total, 50);
+        assert_eq!(page.children.len(), 5);
+        assert_eq!(page.children[0].index, Some(10));
+        assert_eq!(page.children[0].pointer, "/items/10");
+        assert_eq!(page.children[0].preview.as_deref(), Some("10"));
+        assert_eq!(page.child_types.number, 50);
+    }
+
+    #[test]
+    fn test_get_children_escapes_pointer() {
+        let store = DocumentStore::new();
+        let info = open(&store, r#"{"a/b": {"c~d": true}}"#);
+
+        let page = store.get_children(&info.doc_id, "", 0, 10).unwrap();
+        assert_eq!(page.children[0].pointer, "/a~1b");
+        assert_eq!(page.children[0].key.as_deref(), Some("a/b"));
+
+        let nested = store.get_children(&info.doc_id, "/a~1b", 0, 10).unwrap();
+        assert_eq!(nested.children[0].pointer, "/a~1b/c~0d");
+
+        let node = store.get_node(&info.doc_id, "/a~1b/c~0d").unwrap();
+        assert_eq!(node.key.as_deref(), Some("c~d"));
+        assert_eq!(node.node_type, JsonNodeType::Boolean);
+    }
+
+    #[test]
+    fn test_get_children_of_scalar() {
+        let store = DocumentStore::new();
+        let info = open(&store, r#"{"a": 1}"#);
+        assert!(store.get_children(&info.doc_id, "/a", 0, 10).is_err());
+        assert!(store.get_children(&info.doc_id, "/missing", 0, 10).is_err());
+        assert!(store.get_children(&info.doc_id, "a", 0, 10).is_err());
+    }
+
+    #[test]
+    fn test_long_string_preview_truncated() {
+        let store = DocumentStore::new();
+        let info = open(&store, &format!("[\"{}\"]", "x".repeat(1000)));
+
+        let page = store.get_children(&info.doc_id, "", 0, 1).unwrap();
+        assert!(page.children[0].truncated);
+        assert!(page.children[0].preview.as_ref().unwrap().len() < 300);
+    }
+
+    #[test]
+    fn test_modify_document() {
+        let store = DocumentStore::new();
+        let info = open(&store, r#"{"a": {"b": 1}}"#);
+
+        let (_, summary) = store
+            .modify(&info.doc_id, "/a", |document| {
+                document["a"]["c"] = Value::Bool(true);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(summary.child_count, Some(2));
+
+        let error = store.modify(&info.doc_id, "", |_| Err::<(), _>("失败".to_string()));
+        assert_eq!(error.unwrap_err(), "失败");
+        assert!(store.modify("doc-missing", "", |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_get_children_uses_cached_index() {
+        let store = DocumentStore::new();
+        let info = open(
+            &store,
+            r#"{"obj": {"a": 1, "b": "x", "c": null, "d": [1]}}"#,
+        );
+
+        let first = store.get_children(&info.doc_id, "/obj", 0, 2).unwrap();
+        let keys: Vec<_> = first
+            .children
+            .iter()
+            .map(|c| c.key.clone().unwrap())
+            .collect();
+        assert_eq!(keys, ["a", "b"]);
+
+        let second = store.get_children(&info.doc_id, "/obj", 2, 10).unwrap();
+        let keys: Vec<_> = second
+            .children
+            .iter()
+            .map(|c| c.key.clone().unwrap())
+            .collect();
+        assert_eq!(keys, ["c", "d"]);
+        assert_eq!(second.child_types, first.child_types);
+        assert_eq!(second.child_types.array, 1);
+
+        let past_end = store.get_children(&info.doc_id, "/obj", 10, 10).unwrap();
+        assert!(past_end.children.is_empty());
+        assert_eq!(past_end.total, 4);
+
+        // 修改后缓存失效，重新统计
+        store
+            .modify(&info.doc_id, "/obj", |document| {
+                document["obj"]["e"] = Value::Bool(true);
+                Ok(())
+            })
+            .unwrap();
+        let page = store.get_children(&info.doc_id, "/obj", 4, 10).unwrap();
+        assert_eq!(page.children[0].key.as_deref(), Some("e"));
+        assert_eq!(page.child_types.boolean, 1);
+        assert_eq!(page.total, 5);
+    }
+
+    #[test]
+    fn test_close_document() {
+        let store = DocumentStore::new();
+        let info = open(&store, "[]");
+
+        assert!(store.close(&info.doc_id));
+        assert!(!store.close(&info.doc_id));
+        assert!(store.get_children(&info.doc_id, "", 0, 10).is_err());
+    }
+}
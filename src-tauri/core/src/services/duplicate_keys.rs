@@ -0,0 +1,326 @@
+//! 重复键检测服务
+//! serde_json 解析对象时静默保留最后一次出现的值，这里扫描原始文本找出重复的键及两次出现的位置，
+//! 并按 [`DuplicateKeyPolicy`] 决定报错、保留第一个值还是保留最后一个值
+
+use crate::models::validation::{DuplicateKey, DuplicateKeyPolicy};
+use crate::services::document_store::child_pointer;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 按策略处理已解析的值
+///
+/// `value` 必须是 `input` 按 serde_json 默认行为 (保留最后一个值) 解析的结果
+///
+/// # Returns
+/// 处理后的值和重复键列表；策略为 [`DuplicateKeyPolicy::Error`] 且存在重复键时返回第一个重复键
+pub fn apply_policy(
+    input: &str,
+    value: Value,
+    policy: DuplicateKeyPolicy,
+) -> Result<(Value, Vec<DuplicateKey>), DuplicateKey> {
+    let duplicates = find_duplicate_keys(input);
+    if duplicates.is_empty() {
+        return Ok((value, duplicates));
+    }
+
+    match policy {
+        DuplicateKeyPolicy::Error => Err(duplicates[0].clone()),
+        DuplicateKeyPolicy::KeepLast => Ok((value, duplicates)),
+        DuplicateKeyPolicy::KeepFirst => match parse_keep_first(input) {
+            Ok(first) => Ok((first, duplicates)),
+            // 输入已经成功解析过，这里不会失败；万一失败则退回默认行为
+            Err(_) => Ok((value, duplicates)),
+        },
+    }
+}
+
+/// 重复键的用户可读描述
+pub fn duplicate_message(duplicate: &DuplicateKey) -> String {
+    format!(
+        "对象中存在重复的键 \"{}\"（第 {} 行，第 {} 列；首次出现于第 {} 行，第 {} 列）",
+        duplicate.key,
+        duplicate.line,
+        duplicate.column,
+        duplicate.first_line,
+        duplicate.first_column
+    )
+}
+
+/// 扫描 JSON 文本，返回所有重复的对象键 (按出现顺序)
+///
+/// 输入应为合法 JSON；键按解码后的值比较，`"a"` 与 `"\u0061"` 视为重复
+pub fn find_duplicate_keys(input: &str) -> Vec<DuplicateKey> {
+    let bytes = input.as_bytes();
+    let mut duplicates = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut line = 1;
+    let mut column = 1;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match byte {
+            b'{' | b'[' => {
+                let pointer = match stack.last() {
+                    Some(frame) => frame.child_pointer(),
+                    None => String::new(),
+                };
+                stack.push(if byte == b'{' {
+                    Frame::Object {
+                        pointer,
+                        keys: HashMap::new(),
+                        current: None,
+                        expect_key: true,
+                    }
+                } else {
+                    Frame::Array { pointer, index: 0 }
+                });
+            }
+            b'}' | b']' => {
+                stack.pop();
+            }
+            b',' => match stack.last_mut() {
+                Some(Frame::Object { expect_key, .. }) => *expect_key = true,
+                Some(Frame::Array { index, .. }) => *index += 1,
+                None => {}
+            },
+            b'"' => {
+                let (start_line, start_column) = (line, column);
+                let end = string_end(bytes, index);
+                if let Some(Frame::Object {
+                    pointer,
+                    keys,
+                    current,
+                    expect_key: expect_key @ true,
+                }) = stack.last_mut()
+                {
+                    let key = unescape(&input[index..=end]);
+                    match keys.get(&key) {
+                        Some(&(first_line, first_column)) => duplicates.push(DuplicateKey {
+                            key: key.clone(),
+                            pointer: child_pointer(pointer, &key),
+                            first_line,
+                            first_column,
+                            line: start_line,
+                            column: start_column,
+                        }),
+                        None => {
+                            keys.insert(key.clone(), (start_line, start_column));
+                        }
+                    }
+                    *current = Some(key);
+                    *expect_key = false;
+                }
+                // 合法 JSON 的字符串内不含换行，只需前进列号 (按字符计)
+                column += input[index + 1..=end].chars().count();
+                index = end;
+            }
+            b'\n' => {
+                line += 1;
+                column = 0;
+            }
+            _ => {}
+        }
+        // 多字节字符只在首字节处计列
+        if byte & 0xC0 != 0x80 {
+            column += 1;
+        }
+        index += 1;
+    }
+
+    duplicates
+}
+
+/// 扫描时的容器状态
+enum Frame {
+    Object {
+        pointer: String,
+        /// 已出现的键及其位置
+        keys: HashMap<String, (usize, usize)>,
+        /// 当前成员的键
+        current: Option<String>,
+        /// 下一个字符串是否为键
+        expect_key: bool,
+    },
+    Array {
+        pointer: String,
+        index: usize,
+    },
+}
+
+impl Frame {
+    /// 当前成员的 JSON Pointer
+    fn child_pointer(&self) -> String {
+        match self {
+            Frame::Object {
+                pointer, current, ..
+            } => child_pointer(pointer, current.as_deref().unwrap_or_default()),
+            Frame::Array { pointer, index } => child_pointer(pointer, &index.to_string()),
+        }
+    }
+}
+
+/// 返回从 `start` (开引号) 开始的字符串的闭引号位置
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return index,
+            _ => index += 1,
+        }
+    }
+    bytes.len() - 1
+}
+
+/// 将带引号的 JSON 字符串字面量解码为键名
+fn unescape(literal: &str) -> String {
+    if !literal.contains('\\') {
+        return literal[1..literal.len() - 1].to_string();
+    }
+    serde_json::from_str(literal).unwrap_or_else(|_| literal[1..literal.len() - 1].to_string())
+}
+
+/// 解析 JSON，对象中的重复键保留第一次出现的值
+fn parse_keep_first(input: &str) -> serde_json::Result<Value> {
+    serde_json::from_str::<KeepFirst>(input).map(|parsed| parsed.0)
+}
+
+struct KeepFirst(Value);
+
+impl<'de> Deserialize<'de> for KeepFirst {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(KeepFirstVisitor)
+            .map(KeepFirst)
+    }
+}
+
+struct KeepFirstVisitor;
+
+impl<'de> Visitor<'de> for KeepFirstVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("任意 JSON 值")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Number(value.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::Number(value.into()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+        Ok(Number::from_f64(value).map_or(Value::Null, Value::Number))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(KeepFirst(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut object = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let KeepFirst(value) = map.next_value()?;
+            object.entry(key).or_insert(value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_find_duplicate_positions() {
+        let input = "{\n  \"a\": 1,\n  \"b\": {\"x\": [1, {\"y\": 1, \"y\": 2}]},\n  \"a\": 2\n}";
+        let duplicates = find_duplicate_keys(input);
+        assert_eq!(
+            duplicates,
+            vec![
+                DuplicateKey {
+                    key: "y".to_string(),
+                    pointer: "/b/x/1/y".to_string(),
+                    first_line: 3,
+                    first_column: 19,
+                    line: 3,
+                    column: 27,
+                },
+                DuplicateKey {
+                    key: "a".to_string(),
+                    pointer: "/a".to_string(),
+                    first_line: 2,
+                    first_column: 3,
+                    line: 4,
+                    column: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_false_positives() {
+        // 不同对象中的同名键、字符串值中的引号和大括号都不算重复
+        let input = r#"[{"a": "}\"{"}, {"a": 1}, {"b": "a", "a": "b"}]"#;
+        assert!(find_duplicate_keys(input).is_empty());
+    }
+
+    #[test]
+    fn test_escaped_keys_and_columns() {
+        let input = r#"{"名": 1, "名": 2}"#;
+        let duplicates = find_duplicate_keys(input);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].key, "名");
+        assert_eq!((duplicates[0].first_column, duplicates[0].column), (2, 10));
+
+        let duplicates = find_duplicate_keys(r#"{"a": 1, "\u0061": 2, "x": "名", "a": 3}"#);
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[1].column, 33);
+    }
+
+    #[test]
+    fn test_apply_policy() {
+        let input = r#"{"a": 1, "b": {"c": true}, "a": 2, "a": 3}"#;
+        let parsed: Value = serde_json::from_str(input).unwrap();
+
+        let (value, duplicates) =
+            apply_policy(input, parsed.clone(), DuplicateKeyPolicy::KeepLast).unwrap();
+        assert_eq!(value, json!({"a": 3, "b": {"c": true}}));
+        assert_eq!(duplicates.len(), 2);
+
+        let (value, _) =
+            apply_policy(input, parsed.clone(), DuplicateKeyPolicy::KeepFirst).unwrap();
+        assert_eq!(value, json!({"a": 1, "b": {"c": true}}));
+
+        let duplicate = apply_policy(input, parsed, DuplicateKeyPolicy::Error).unwrap_err();
+        assert_eq!(duplicate.column, 28);
+        assert!(duplicate_message(&duplicate).contains("首次出现于第 1 行，第 2 列"));
+    }
+}
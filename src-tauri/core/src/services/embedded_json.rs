@@ -0,0 +1,280 @@
+//! 嵌入 JSON 字符串服务
+//! 将值为序列化 JSON 的字符串 (日志消息、消息队列正文等) 展开为解析后的值，以及反向将子树序列化为字符串
+
+use crate::models::transform::{ExpandOptions, TransformResult};
+use crate::services::document_store::{child_pointer, resolve_mut};
+use crate::services::json_formatter::pretty_print;
+use serde_json::Value;
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 递归展开时最多解析的嵌套层数 (防止恶意构造的多重转义)
+const MAX_EXPAND_LEVELS: usize = 16;
+
+/// 展开 JSON 文本中的嵌入 JSON 字符串
+///
+/// # Arguments
+/// * `input` - JSON 文本
+/// * `pointer` - 只处理该节点下的值 (JSON Pointer，空字符串表示整个文档)
+/// * `options` - 是否递归、是否只展开对象/数组
+pub fn expand_text(input: &str, pointer: &str, options: &ExpandOptions) -> TransformResult {
+    let start = Instant::now();
+
+    let mut value = match parse_input(input) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+    match expand_strings(&mut value, pointer, options) {
+        Ok(changed) => finish(&value, options.indent, changed, start),
+        Err(message) => TransformResult::error(message),
+    }
+}
+
+/// 将 JSON 文本中的一个子树序列化为字符串
+///
+/// # Arguments
+/// * `input` - JSON 文本
+/// * `pointer` - 要序列化的节点 (JSON Pointer)
+/// * `indent` - 输出 JSON 的缩进大小
+pub fn stringify_text(input: &str, pointer: &str, indent: u8) -> TransformResult {
+    let start = Instant::now();
+
+    let mut value = match parse_input(input) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+    match stringify_node(&mut value, pointer) {
+        Ok(()) => finish(&value, indent, vec![pointer.to_string()], start),
+        Err(message) => TransformResult::error(message),
+    }
+}
+
+/// 就地展开节点下所有值为 JSON 的字符串
+///
+/// # Returns
+/// 被替换的字符串节点 (JSON Pointer)
+pub fn expand_strings(
+    value: &mut Value,
+    pointer: &str,
+    options: &ExpandOptions,
+) -> Result<Vec<String>, String> {
+    let node = resolve_mut(value, pointer)?;
+    let mut changed = Vec::new();
+    expand(node, pointer.to_string(), 0, options, &mut changed);
+    Ok(changed)
+}
+
+/// 就地将节点替换为其紧凑 JSON 文本
+pub fn stringify_node(value: &mut Value, pointer: &str) -> Result<(), String> {
+    let node = resolve_mut(value, pointer)?;
+    let text = serde_json::to_string(node).map_err(|e| format!("序列化失败: {}", e))?;
+    *node = Value::String(text);
+    Ok(())
+}
+
+fn expand(
+    value: &mut Value,
+    pointer: String,
+    level: usize,
+    options: &ExpandOptions,
+    changed: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                expand(child, child_pointer(&pointer, key), level, options, changed);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter_mut().enumerate() {
+                expand(
+                    child,
+                    child_pointer(&pointer, &index.to_string()),
+                    level,
+                    options,
+                    changed,
+                );
+            }
+        }
+        Value::String(text) => {
+            if let Some(mut parsed) = parse_embedded(text, level, options) {
+                changed.push(pointer.clone());
+                if options.recursive && level + 1 < MAX_EXPAND_LEVELS {
+                    expand(&mut parsed, pointer, level + 1, options, changed);
+                }
+                *value = parsed;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 尝试将字符串解析为 JSON，不符合条件时返回 `None`
+fn parse_embedded(text: &str, level: usize, options: &ExpandOptions) -> Option<Value> {
+    let trimmed = text.trim();
+    let looks_like_container = trimmed.starts_with('{') || trimmed.starts_with('[');
+    let looks_like_string = trimmed.starts_with('"');
+    if trimmed.is_empty()
+        || (options.only_containers
+            && !looks_like_container
+            && !(options.recursive && looks_like_string))
+    {
+        return None;
+    }
+
+    match serde_json::from_str::<Value>(trimmed).ok()? {
+        parsed @ (Value::Object(_) | Value::Array(_)) => Some(parsed),
+        // 多重转义的字符串：递归模式下继续解析其内容
+        Value::String(inner) if options.recursive && level + 1 < MAX_EXPAND_LEVELS => {
+            match parse_embedded(&inner, level + 1, options) {
+                Some(parsed) => Some(parsed),
+                None if options.only_containers => None,
+                None => Some(Value::String(inner)),
+            }
+        }
+        _ if options.only_containers => None,
+        scalar => Some(scalar),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Value, TransformResult> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(TransformResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        )));
+    }
+    if input.trim().is_empty() {
+        return Err(TransformResult::error("输入为空，请提供有效的内容"));
+    }
+
+    serde_json::from_str::<Value>(input).map_err(|e| TransformResult::Error {
+        message: format!("JSON 解析失败: {}", e),
+        line: Some(e.line()),
+        column: Some(e.column()),
+    })
+}
+
+fn finish(value: &Value, indent: u8, changed: Vec<String>, start: Instant) -> TransformResult {
+    match pretty_print(value, indent) {
+        Ok(output) => TransformResult::Success {
+            size: output.len(),
+            output,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+            changed,
+        },
+        Err(message) => TransformResult::error(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn expanded(value: Value, pointer: &str, options: &ExpandOptions) -> (Value, Vec<String>) {
+        let mut value = value;
+        let changed = expand_strings(&mut value, pointer, options).unwrap();
+        (value, changed)
+    }
+
+    #[test]
+    fn test_expand_one_level() {
+        let input = json!({
+            "body": "{\"user\": {\"id\": 1}, \"raw\": \"[1, 2]\"}",
+            "count": "42",
+            "note": "{not json"
+        });
+        let (value, changed) = expanded(input, "", &ExpandOptions::default());
+        assert_eq!(
+            value,
+            json!({
+                "body": {"user": {"id": 1}, "raw": "[1, 2]"},
+                "count": "42",
+                "note": "{not json"
+            })
+        );
+        assert_eq!(changed, vec!["/body"]);
+    }
+
+    #[test]
+    fn test_expand_recursive_and_double_encoded() {
+        let inner = json!({"level": 2}).to_string();
+        let middle = json!({"payload": inner}).to_string();
+        let double = serde_json::to_string(&middle).unwrap();
+        let input = json!({"a": middle, "b": double});
+
+        let options = ExpandOptions {
+            recursive: true,
+            ..Default::default()
+        };
+        let (value, changed) = expanded(input.clone(), "", &options);
+        let expected = json!({"payload": {"level": 2}});
+        assert_eq!(value, json!({"a": expected, "b": expected}));
+        assert_eq!(changed, vec!["/a", "/a/payload", "/b", "/b/payload"]);
+
+        // 非递归时多重转义的字符串保持不变
+        let (value, _) = expanded(input, "/b", &ExpandOptions::default());
+        assert_eq!(value["b"], Value::String(double));
+    }
+
+    #[test]
+    fn test_expand_scalars() {
+        let options = ExpandOptions {
+            only_containers: false,
+            ..Default::default()
+        };
+        let (value, _) = expanded(
+            json!({"n": "42", "b": " true ", "s": "plain", "q": "\"x\""}),
+            "",
+            &options,
+        );
+        assert_eq!(value, json!({"n": 42, "b": true, "s": "plain", "q": "x"}));
+    }
+
+    #[test]
+    fn test_pointer_scope() {
+        let text = "[1]".to_string();
+        let (value, changed) = expanded(
+            json!({"keep": text, "items": [{"x": text}]}),
+            "/items",
+            &ExpandOptions::default(),
+        );
+        assert_eq!(value, json!({"keep": "[1]", "items": [{"x": [1]}]}));
+        assert_eq!(changed, vec!["/items/0/x"]);
+
+        let mut value = json!({});
+        assert!(expand_strings(&mut value, "/missing", &ExpandOptions::default()).is_err());
+        assert!(expand_strings(&mut value, "missing", &ExpandOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_stringify_round_trip() {
+        let original = json!({"event": {"type": "click", "at": [1, 2]}, "id": 7});
+
+        let result = stringify_text(&original.to_string(), "/event", 2);
+        let output = match result {
+            TransformResult::Success {
+                output, changed, ..
+            } => {
+                assert_eq!(changed, vec!["/event"]);
+                output
+            }
+            TransformResult::Error { message, .. } => panic!("{}", message),
+        };
+        let stringified: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            stringified["event"],
+            json!(r#"{"at":[1,2],"type":"click"}"#)
+        );
+
+        match expand_text(&output, "", &ExpandOptions::default()) {
+            TransformResult::Success { output, .. } => {
+                assert_eq!(serde_json::from_str::<Value>(&output).unwrap(), original)
+            }
+            TransformResult::Error { message, .. } => panic!("{}", message),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! 文本编码服务
+//! 导入时按 BOM 和零字节分布识别 UTF-8/UTF-16/UTF-32 并转码为 UTF-8，导出时按选项编码
+
+use crate::models::file::{EncodingOptions, TextEncoding};
+
+/// 解码后的文本
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedText {
+    /// 文本内容 (已去除 BOM)
+    pub content: String,
+    /// 识别出的编码
+    pub encoding: TextEncoding,
+    /// 原文件是否带有 BOM
+    pub bom: bool,
+}
+
+/// 识别字节内容的编码
+///
+/// 先按 BOM 判断；没有 BOM 时利用 JSON 文本的前两个字符必然是 ASCII 的特点 (RFC 4627 第 3 节)，
+/// 根据开头 4 个字节中零字节的位置判断
+///
+/// # Returns
+/// 编码和 BOM 长度 (没有 BOM 时为 0)
+pub fn detect_encoding(bytes: &[u8]) -> (TextEncoding, usize) {
+    // UTF-32LE 的 BOM 以 UTF-16LE 的 BOM 开头，需要先判断
+    const BOM_ORDER: [TextEncoding; 5] = [
+        TextEncoding::Utf32Le,
+        TextEncoding::Utf32Be,
+        TextEncoding::Utf8,
+        TextEncoding::Utf16Le,
+        TextEncoding::Utf16Be,
+    ];
+    for encoding in BOM_ORDER {
+        if bytes.starts_with(encoding.bom()) {
+            return (encoding, encoding.bom().len());
+        }
+    }
+
+    let encoding = match bytes {
+        [0, 0, 0, b, ..] if *b != 0 => TextEncoding::Utf32Be,
+        [a, 0, 0, 0, ..] if *a != 0 => TextEncoding::Utf32Le,
+        [0, b, ..] if *b != 0 => TextEncoding::Utf16Be,
+        [a, 0, ..] if *a != 0 => TextEncoding::Utf16Le,
+        _ => TextEncoding::Utf8,
+    };
+    (encoding, 0)
+}
+
+/// 识别编码并转码为 UTF-8 字符串
+pub fn decode(bytes: &[u8]) -> Result<DecodedText, String> {
+    let (encoding, bom_len) = detect_encoding(bytes);
+    let body = &bytes[bom_len..];
+
+    let content = match encoding {
+        TextEncoding::Utf8 => std::str::from_utf8(body).map(str::to_string).map_err(|e| {
+            format!(
+                "文件不是有效的 UTF-8 文本 (第 {} 个字节处存在非法序列)",
+                bom_len + e.valid_up_to() + 1
+            )
+        })?,
+        TextEncoding::Utf16Le | TextEncoding::Utf16Be => decode_utf16(body, encoding)?,
+        TextEncoding::Utf32Le | TextEncoding::Utf32Be => decode_utf32(body, encoding)?,
+    };
+
+    Ok(DecodedText {
+        content,
+        encoding,
+        bom: bom_len > 0,
+    })
+}
+
+/// 按选项将文本编码为字节
+pub fn encode(text: &str, options: &EncodingOptions) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len() + 4);
+    if options.bom {
+        bytes.extend_from_slice(options.encoding.bom());
+    }
+
+    match options.encoding {
+        TextEncoding::Utf8 => bytes.extend_from_slice(text.as_bytes()),
+        TextEncoding::Utf16Le => text
+            .encode_utf16()
+            .for_each(|unit| bytes.extend_from_slice(&unit.to_le_bytes())),
+        TextEncoding::Utf16Be => text
+            .encode_utf16()
+            .for_each(|unit| bytes.extend_from_slice(&unit.to_be_bytes())),
+        TextEncoding::Utf32Le => text
+            .chars()
+            .for_each(|ch| bytes.extend_from_slice(&(ch as u32).to_le_bytes())),
+        TextEncoding::Utf32Be => text
+            .chars()
+            .for_each(|ch| bytes.extend_from_slice(&(ch as u32).to_be_bytes())),
+    }
+    bytes
+}
+
+fn decode_utf16(body: &[u8], encoding: TextEncoding) -> Result<String, String> {
+    if body.len() % 2 != 0 {
+        return Err(format!(
+            "{} 文件的长度不是 2 的倍数，内容可能已截断",
+            encoding.label()
+        ));
+    }
+
+    let units = body.chunks_exact(2).map(|pair| {
+        let pair = [pair[0], pair[1]];
+        match encoding {
+            TextEncoding::Utf16Le => u16::from_le_bytes(pair),
+            _ => u16::from_be_bytes(pair),
+        }
+    });
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|e| {
+            format!(
+                "{} 文件包含孤立的代理项 0x{:04X}",
+                encoding.label(),
+                e.unpaired_surrogate()
+            )
+        })
+}
+
+fn decode_utf32(body: &[u8], encoding: TextEncoding) -> Result<String, String> {
+    if body.len() % 4 != 0 {
+        return Err(format!(
+            "{} 文件的长度不是 4 的倍数，内容可能已截断",
+            encoding.label()
+        ));
+    }
+
+    body.chunks_exact(4)
+        .map(|quad| {
+            let quad = [quad[0], quad[1], quad[2], quad[3]];
+            let code = match encoding {
+                TextEncoding::Utf32Le => u32::from_le_bytes(quad),
+                _ => u32::from_be_bytes(quad),
+            };
+            char::from_u32(code)
+                .ok_or_else(|| format!("{} 文件包含无效的码位 0x{:X}", encoding.label(), code))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [TextEncoding; 5] = [
+        TextEncoding::Utf8,
+        TextEncoding::Utf16Le,
+        TextEncoding::Utf16Be,
+        TextEncoding::Utf32Le,
+        TextEncoding::Utf32Be,
+    ];
+
+    #[test]
+    fn test_round_trip_with_and_without_bom() {
+        let text = "{\"名称\": \"😀\"}";
+        for encoding in ALL {
+            for bom in [false, true] {
+                let bytes = encode(text, &EncodingOptions { encoding, bom });
+                let decoded = decode(&bytes).unwrap();
+                assert_eq!(decoded.content, text, "{:?} bom={}", encoding, bom);
+                assert_eq!((decoded.encoding, decoded.bom), (encoding, bom));
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_without_bom() {
+        assert_eq!(detect_encoding(b"[1]"), (TextEncoding::Utf8, 0));
+        assert_eq!(detect_encoding(b"{\x00}\x00"), (TextEncoding::Utf16Le, 0));
+        assert_eq!(detect_encoding(b"\x00{\x00}"), (TextEncoding::Utf16Be, 0));
+        assert_eq!(
+            detect_encoding(b"1\x00\x00\x00"),
+            (TextEncoding::Utf32Le, 0)
+        );
+        assert_eq!(
+            detect_encoding(b"\x00\x00\x001"),
+            (TextEncoding::Utf32Be, 0)
+        );
+        assert_eq!(detect_encoding(b""), (TextEncoding::Utf8, 0));
+    }
+
+    #[test]
+    fn test_utf8_bom_is_stripped() {
+        let decoded = decode(b"\xEF\xBB\xBF{\"a\": 1}").unwrap();
+        assert_eq!(decoded.content, "{\"a\": 1}");
+        assert!(decoded.bom);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(decode(b"[\"\xFF\"]").unwrap_err().contains("UTF-8"));
+        assert!(decode(b"[\x00\"").unwrap_err().contains("截断"));
+        // 孤立的高代理项 D800
+        assert!(decode(b"\xFF\xFE\x00\xD8").unwrap_err().contains("代理项"));
+    }
+}
@@ -0,0 +1,958 @@
+//! 文件输入输出服务
+//! 处理 JSON 文件的导入和导出；导出先写入临时文件再重命名，避免中途失败损坏已有文件
+
+use crate::models::file::{
+    EncodingOptions, FileSnapshot, FileTypeOptions, FileWriteResult, TextEncoding, WriteOptions,
+};
+use crate::services::encoding;
+use serde::de::IgnoredAny;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::UNIX_EPOCH;
+
+/// 默认允许导入导出的文件扩展名 (JSON、JSON Lines 及常见的 JSON 衍生格式)
+pub const JSON_EXTENSIONS: &[&str] = &[
+    "json",
+    "jsonl",
+    "ndjson",
+    "geojson",
+    "json5",
+    "jsonc",
+    "har",
+    "ipynb",
+    "webmanifest",
+];
+
+/// 表格导出允许的文件扩展名
+const TABLE_EXTENSIONS: &[&str] = &["csv", "tsv"];
+
+/// 二进制格式 (MessagePack/CBOR/BSON/Protobuf 及描述符集) 导入导出允许的文件扩展名
+const BINARY_EXTENSIONS: &[&str] = &[
+    "msgpack", "mpk", "cbor", "bson", "bin", "pb", "binpb", "desc", "protoset",
+];
+
+/// 二进制文件最大允许大小：10 MB
+const MAX_BINARY_SIZE: u64 = 10 * 1024 * 1024;
+
+/// 检查扩展名是否在允许列表中 (未配置时使用 [`JSON_EXTENSIONS`])
+fn is_allowed_extension(ext: &OsStr, options: &FileTypeOptions) -> bool {
+    if options.extensions.is_empty() {
+        return has_extension(ext, JSON_EXTENSIONS);
+    }
+    options
+        .extensions
+        .iter()
+        .any(|allowed| ext.eq_ignore_ascii_case(allowed.trim_start_matches('.')))
+}
+
+/// 允许的扩展名的显示文本 (如 `.json/.jsonl`)
+fn allowed_extension_list(options: &FileTypeOptions) -> String {
+    let extensions: Vec<&str> = if options.extensions.is_empty() {
+        JSON_EXTENSIONS.to_vec()
+    } else {
+        options
+            .extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.'))
+            .collect()
+    };
+    extensions
+        .iter()
+        .map(|ext| format!(".{}", ext))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 内容是否为 JSON (单个文档，或多个连续的文档如 JSON Lines)
+fn looks_like_json(content: &str) -> bool {
+    !content.trim().is_empty()
+        && serde_json::Deserializer::from_str(content)
+            .into_iter::<IgnoredAny>()
+            .all(|document| document.is_ok())
+}
+
+/// 文件读取结果
+#[derive(Debug)]
+pub struct FileReadResult {
+    pub content: String,
+    pub file_name: String,
+    /// 识别出的文件编码
+    pub encoding: TextEncoding,
+    /// 原文件是否带有 BOM
+    pub bom: bool,
+    /// 导入警告 (如扩展名不在允许列表中、根据内容识别为 JSON)
+    pub warnings: Vec<String>,
+    /// 导入时的文件状态，导出到同一文件时用于检测冲突
+    pub snapshot: FileSnapshot,
+}
+
+/// 从文件路径读取 JSON 内容
+///
+/// 自动识别 UTF-8/UTF-16/UTF-32 编码 (有无 BOM 均可)，转码为 UTF-8 并去除 BOM。
+/// 扩展名不在允许列表中 (或没有扩展名) 时检查内容，内容是 JSON 则照常导入并返回警告
+///
+/// # 参数
+/// * `file_path` - 文件的完整路径
+/// * `options` - 允许的扩展名
+///
+/// # 返回
+/// * `Ok(FileReadResult)` - 成功读取,包含文件内容和文件名
+/// * `Err(String)` - 读取失败,包含错误信息
+pub fn read_json_file(
+    file_path: &str,
+    options: &FileTypeOptions,
+) -> Result<FileReadResult, String> {
+    // 验证文件路径
+    let path = Path::new(file_path);
+
+    if !path.exists() {
+        return Err(format!("文件不存在: {}", file_path));
+    }
+
+    if !path.is_file() {
+        return Err(format!("路径不是文件: {}", file_path));
+    }
+
+    // 检查文件大小 (限制为 10 MB)；元数据在读取内容之前获取，
+    // 这样读取期间文件被修改时，导出的冲突检查会按内容哈希比较
+    let metadata = fs::metadata(path).map_err(|e| format!("无法获取文件元数据: {}", e))?;
+    let size_mb = metadata.len() as f64 / (1024.0 * 1024.0);
+    if size_mb > 10.0 {
+        return Err(format!("文件太大 ({:.2} MB),最大支持 10 MB", size_mb));
+    }
+
+    // 读取文件内容并转码
+    let bytes = fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let decoded = encoding::decode(&bytes)?;
+
+    // 检查文件扩展名，不在允许列表中时根据内容判断
+    let mut warnings = Vec::new();
+    let mismatch = match path.extension() {
+        Some(ext) if is_allowed_extension(ext, options) => None,
+        Some(ext) => Some(format!("扩展名 .{}", ext.to_string_lossy())),
+        None => Some("文件缺少扩展名".to_string()),
+    };
+    if let Some(mismatch) = mismatch {
+        if !looks_like_json(&decoded.content) {
+            return Err(format!(
+                "文件必须是 .json 格式或内容为 JSON (允许的扩展名: {}),{},且内容无法识别为 JSON",
+                allowed_extension_list(options),
+                mismatch
+            ));
+        }
+        warnings.push(format!(
+            "{}不在允许的扩展名列表中,已根据内容识别为 JSON",
+            mismatch
+        ));
+    }
+
+    let file_name = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    Ok(FileReadResult {
+        content: decoded.content,
+        file_name,
+        encoding: decoded.encoding,
+        bom: decoded.bom,
+        warnings,
+        snapshot: snapshot(path, &metadata, &bytes),
+    })
+}
+
+/// 读取文件的当前状态
+pub fn read_snapshot(path: &Path) -> Result<FileSnapshot, String> {
+    let metadata = fs::metadata(path).map_err(|e| format!("无法获取文件元数据: {}", e))?;
+    let content = fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    Ok(snapshot(path, &metadata, &content))
+}
+
+/// 将 JSON 内容写入文件
+///
+/// 原子写入并保留已有文件的权限；可选先备份已有文件，或在文件自导入后被修改时拒绝覆盖。
+/// 路径按原样使用 (与导入一致)：扩展名不在允许列表中 (或没有扩展名) 时，内容是 JSON 则照常写入并返回警告
+///
+/// # 参数
+/// * `file_path` - 文件的完整路径
+/// * `content` - 要写入的 JSON 内容
+/// * `file_types` - 允许的扩展名
+/// * `options` - 输出编码及是否写入 BOM
+/// * `write` - 备份及冲突检查选项
+///
+/// # 返回
+/// * `Ok(FileWriteResult)` - 成功写入,包含文件路径、备份路径、写入后的文件状态和警告
+/// * `Err(String)` - 写入失败,包含错误信息
+pub fn write_json_file(
+    file_path: &str,
+    content: &str,
+    file_types: &FileTypeOptions,
+    options: &EncodingOptions,
+    write: &WriteOptions,
+) -> Result<FileWriteResult, String> {
+    // 验证文件路径
+    let path = Path::new(file_path);
+
+    // 确保父目录存在
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            match fs::create_dir_all(parent) {
+                Ok(_) => {}
+                Err(e) => {
+                    return Err(format!("无法创建目录: {}", e));
+                }
+            }
+        }
+    }
+
+    // 检查文件扩展名，不在允许列表中时根据内容判断
+    let mismatch = match path.extension() {
+        Some(ext) if is_allowed_extension(ext, file_types) => None,
+        Some(ext) => Some(format!("扩展名 .{}", ext.to_string_lossy())),
+        None => Some("文件缺少扩展名".to_string()),
+    };
+    let mut warnings = Vec::new();
+    if let Some(mismatch) = mismatch {
+        if !looks_like_json(content) {
+            return Err(format!(
+                "文件必须是 .json 格式或内容为 JSON (允许的扩展名: {}),{},且内容无法识别为 JSON",
+                allowed_extension_list(file_types),
+                mismatch
+            ));
+        }
+        warnings.push(format!("{}不在允许的扩展名列表中", mismatch));
+    }
+
+    // 写入文件
+    let mut result = write_atomic(path, &encoding::encode(content, options), write)?;
+    result.warnings = warnings;
+    Ok(result)
+}
+
+/// 将 CSV/TSV 内容写入文件
+///
+/// # 参数
+/// * `file_path` - 文件的完整路径，没有扩展名时自动添加 .csv
+/// * `content` - 要写入的 CSV/TSV 内容
+///
+/// # 返回
+/// * `Ok(String)` - 成功写入,返回文件路径
+/// * `Err(String)` - 写入失败,包含错误信息
+pub fn write_csv_file(file_path: &str, content: &str) -> Result<String, String> {
+    let path = prepare_export_path(file_path, TABLE_EXTENSIONS, "csv")?;
+    write_atomic(&path, content.as_bytes(), &WriteOptions::default()).map(|result| result.path)
+}
+
+/// 读取二进制文件 (MessagePack/CBOR/BSON)
+///
+/// # 参数
+/// * `file_path` - 文件的完整路径
+///
+/// # 返回
+/// * `Ok(Vec<u8>)` - 文件内容
+/// * `Err(String)` - 读取失败,包含错误信息
+pub fn read_binary_file(file_path: &str) -> Result<Vec<u8>, String> {
+    let path = Path::new(file_path);
+
+    if !path.is_file() {
+        return Err(format!("文件不存在: {}", file_path));
+    }
+
+    match path.extension() {
+        Some(ext) if has_extension(ext, BINARY_EXTENSIONS) => {}
+        _ => {
+            return Err(format!(
+                "文件必须是 {} 格式",
+                extension_list(BINARY_EXTENSIONS)
+            ));
+        }
+    }
+
+    let metadata = fs::metadata(path).map_err(|e| format!("无法获取文件元数据: {}", e))?;
+    if metadata.len() > MAX_BINARY_SIZE {
+        return Err(format!(
+            "文件太大 ({:.2} MB),最大支持 10 MB",
+            metadata.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    fs::read(path).map_err(|e| format!("读取文件失败: {}", e))
+}
+
+/// 将二进制内容写入文件
+///
+/// # 参数
+/// * `file_path` - 文件的完整路径，没有扩展名时自动添加 .bin
+/// * `content` - 要写入的字节
+///
+/// # 返回
+/// * `Ok(String)` - 成功写入,返回文件路径
+/// * `Err(String)` - 写入失败,包含错误信息
+pub fn write_binary_file(file_path: &str, content: &[u8]) -> Result<String, String> {
+    let path = prepare_export_path(file_path, BINARY_EXTENSIONS, "bin")?;
+    write_atomic(&path, content, &WriteOptions::default()).map(|result| result.path)
+}
+
+/// 创建父目录并检查扩展名，没有扩展名时添加默认扩展名
+fn prepare_export_path(
+    file_path: &str,
+    allowed: &[&str],
+    default_extension: &str,
+) -> Result<PathBuf, String> {
+    let path = Path::new(file_path);
+
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+        }
+    }
+
+    match path.extension() {
+        Some(ext) if has_extension(ext, allowed) => Ok(path.to_path_buf()),
+        Some(ext) => Err(format!(
+            "文件必须是 {} 格式,当前: .{}",
+            extension_list(allowed),
+            ext.to_string_lossy()
+        )),
+        None => Ok(path.with_extension(default_extension)),
+    }
+}
+
+/// 原子写入文件：先写入同一目录下的临时文件并同步到磁盘，再重命名覆盖目标文件
+///
+/// 写入中途失败或程序崩溃时目标文件保持不变；覆盖已有文件时保留其权限。
+/// 目标是符号链接时写入链接指向的文件，链接本身保持不变
+pub fn write_atomic(
+    path: &Path,
+    content: &[u8],
+    options: &WriteOptions,
+) -> Result<FileWriteResult, String> {
+    if let Some(expected) = &options.expected {
+        check_unchanged(path, expected)?;
+    }
+
+    let target = resolve_target(path);
+    let existing = fs::metadata(&target)
+        .ok()
+        .filter(|metadata| metadata.is_file());
+    let backup_path = match existing {
+        Some(_) if options.backup => {
+            let backup = sibling_path(&target, ".bak");
+            fs::copy(&target, &backup).map_err(|e| format!("创建备份文件失败: {}", e))?;
+            Some(backup.to_string_lossy().to_string())
+        }
+        _ => None,
+    };
+
+    let temp = temp_path(&target);
+    let written =
+        write_temp(&temp, content, existing.as_ref()).and_then(|_| fs::rename(&temp, &target));
+    if let Err(e) = written {
+        fs::remove_file(&temp).ok();
+        return Err(format!("写入文件失败: {}", e));
+    }
+
+    let metadata = fs::metadata(path).map_err(|e| format!("无法获取文件元数据: {}", e))?;
+    Ok(FileWriteResult {
+        path: path.to_string_lossy().to_string(),
+        backup_path,
+        snapshot: snapshot(path, &metadata, content),
+        warnings: Vec::new(),
+    })
+}
+
+/// 流式原子写入：由 `fill` 向同一目录下的临时文件写入内容，成功后重命名覆盖目标文件
+///
+/// 用于无法一次放入内存的输出；`fill` 或写入失败时删除临时文件，目标文件保持不变。
+/// 目标文件同时也是输入时，读取的始终是旧内容，因此可以安全地就地转换
+pub fn write_atomic_stream<T, E: From<String>>(
+    path: &Path,
+    fill: impl FnOnce(&mut BufWriter<fs::File>) -> Result<T, E>,
+) -> Result<T, E> {
+    let target = resolve_target(path);
+    let existing = fs::metadata(&target)
+        .ok()
+        .filter(|metadata| metadata.is_file());
+    let temp = temp_path(&target);
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp)
+        .map_err(|e| format!("无法创建文件: {}", e))?;
+
+    let mut writer = BufWriter::new(file);
+    let result = fill(&mut writer).and_then(|output| {
+        finish_temp(writer, &temp, existing.as_ref())
+            .and_then(|_| fs::rename(&temp, &target))
+            .map_err(|e| format!("写入文件失败: {}", e))?;
+        Ok(output)
+    });
+    if result.is_err() {
+        fs::remove_file(&temp).ok();
+    }
+    result
+}
+
+/// 刷新并同步临时文件，覆盖已有文件时沿用其权限
+fn finish_temp(
+    writer: BufWriter<fs::File>,
+    temp: &Path,
+    existing: Option<&fs::Metadata>,
+) -> std::io::Result<()> {
+    let file = writer.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()?;
+    if let Some(metadata) = existing {
+        fs::set_permissions(temp, metadata.permissions())?;
+    }
+    Ok(())
+}
+
+fn write_temp(temp: &Path, content: &[u8], existing: Option<&fs::Metadata>) -> std::io::Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(temp)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    if let Some(metadata) = existing {
+        fs::set_permissions(temp, metadata.permissions())?;
+    }
+    Ok(())
+}
+
+/// 实际写入的文件路径：沿符号链接找到最终的文件，使重命名替换的是文件而不是链接
+///
+/// 文件尚不存在时返回原路径；链接指向的文件不存在时返回链接的目标
+fn resolve_target(path: &Path) -> PathBuf {
+    if let Ok(real) = fs::canonicalize(path) {
+        return real;
+    }
+    match fs::read_link(path) {
+        Ok(link) => match path.parent() {
+            Some(dir) => dir.join(link),
+            None => link,
+        },
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+/// 同一目录下的临时文件路径 (如 `.data.json.1234-0.tmp`)
+fn temp_path(path: &Path) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// 在文件名后追加后缀 (如 `data.json` -> `data.json.bak`)
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}{}", name, suffix))
+}
+
+/// 检查文件自记录状态以来是否被修改
+///
+/// 只检查同一路径；修改时间的精度有限 (同一毫秒内的两次写入无法区分)，因此大小相同时总是比较内容哈希
+fn check_unchanged(path: &Path, expected: &FileSnapshot) -> Result<(), String> {
+    if canonical_path(path) != expected.path {
+        return Ok(());
+    }
+    // 文件已被删除时直接写入
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(());
+    };
+
+    let unchanged = metadata.len() == expected.size && {
+        let current = fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+        content_hash(&current) == expected.hash
+    };
+    if unchanged {
+        return Ok(());
+    }
+    Err(format!(
+        "文件 {} 在导入后已被其他程序修改,为避免覆盖这些修改已取消写入",
+        path.display()
+    ))
+}
+
+/// 根据元数据和内容生成文件状态
+fn snapshot(path: &Path, metadata: &fs::Metadata, content: &[u8]) -> FileSnapshot {
+    FileSnapshot {
+        path: canonical_path(path),
+        modified_ms: modified_ms(metadata),
+        size: content.len() as u64,
+        hash: content_hash(content),
+    }
+}
+
+fn canonical_path(path: &Path) -> String {
+    fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+fn modified_ms(metadata: &fs::Metadata) -> Option<u64> {
+    let modified = metadata.modified().ok()?;
+    let elapsed = modified.duration_since(UNIX_EPOCH).ok()?;
+    Some(elapsed.as_millis() as u64)
+}
+
+/// 内容哈希 (64 位 FNV-1a)
+///
+/// 使用固定算法，结果不随 Rust 版本或进程变化，可以保存下来在之后的会话中比较
+fn content_hash(content: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = content.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+fn has_extension(ext: &OsStr, allowed: &[&str]) -> bool {
+    allowed
+        .iter()
+        .any(|allowed| ext.eq_ignore_ascii_case(allowed))
+}
+
+/// 扩展名列表的显示文本 (如 `.csv 或 .tsv`)
+fn extension_list(extensions: &[&str]) -> String {
+    extensions
+        .iter()
+        .map(|ext| format!(".{}", ext))
+        .collect::<Vec<_>>()
+        .join(" 或 ")
+}
+
+/// 验证文件是否可写
+pub fn can_write_file(file_path: &str) -> bool {
+    let path = Path::new(file_path);
+
+    // 如果文件已存在,检查是否可写
+    if path.exists() {
+        return match fs::metadata(path) {
+            Ok(metadata) => !metadata.permissions().readonly(),
+            Err(_) => false,
+        };
+    }
+
+    // 如果文件不存在,检查父目录是否可写
+    if let Some(parent) = path.parent() {
+        if parent.exists() {
+            return match fs::metadata(parent) {
+                Ok(metadata) => !metadata.permissions().readonly(),
+                Err(_) => false,
+            };
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn get_temp_dir() -> PathBuf {
+        std::env::temp_dir().join("tauri_json_tool_tests")
+    }
+
+    fn setup_test_env() -> PathBuf {
+        let temp_dir = get_temp_dir();
+        fs::create_dir_all(&temp_dir).unwrap();
+        temp_dir
+    }
+
+    fn cleanup_test_env() {
+        let temp_dir = get_temp_dir();
+        if temp_dir.exists() {
+            fs::remove_dir_all(&temp_dir).ok();
+        }
+    }
+
+    #[test]
+    fn test_read_valid_json_file() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("test.json");
+        let test_content = r#"{"name": "test", "value": 123}"#;
+
+        fs::write(&file_path, test_content).unwrap();
+
+        let result = read_json_file(&file_path.to_string_lossy(), &FileTypeOptions::default());
+        assert!(result.is_ok());
+
+        let read_result = result.unwrap();
+        assert_eq!(read_result.content, test_content);
+        assert_eq!(read_result.file_name, "test.json");
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_read_nonexistent_file() {
+        let result = read_json_file("/nonexistent/path/test.json", &FileTypeOptions::default());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("文件不存在"));
+    }
+
+    #[test]
+    fn test_read_non_json_file() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("test.txt");
+
+        fs::write(&file_path, "test content").unwrap();
+
+        let result = read_json_file(&file_path.to_string_lossy(), &FileTypeOptions::default());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("必须是 .json 格式"));
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_read_jsonl_file() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("records.jsonl");
+        let test_content = "{\"a\":1}\n{\"a\":2}\n";
+
+        fs::write(&file_path, test_content).unwrap();
+
+        let result = read_json_file(&file_path.to_string_lossy(), &FileTypeOptions::default());
+        assert_eq!(result.unwrap().content, test_content);
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_read_by_content_sniffing() {
+        let temp_dir = setup_test_env();
+        let options = FileTypeOptions::default();
+
+        let geojson = temp_dir.join("map.GeoJSON");
+        fs::write(&geojson, r#"{"type": "FeatureCollection"}"#).unwrap();
+        let result = read_json_file(&geojson.to_string_lossy(), &options).unwrap();
+        assert!(result.warnings.is_empty());
+
+        // 扩展名未知或缺失，但内容是 JSON (或 JSON Lines)：导入并给出警告
+        let dump = temp_dir.join("api-dump");
+        fs::write(&dump, "{\"a\":1}\n{\"a\":2}\n").unwrap();
+        let result = read_json_file(&dump.to_string_lossy(), &options).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("缺少扩展名"));
+
+        let log = temp_dir.join("response.txt");
+        fs::write(&log, "[1, 2]").unwrap();
+        let result = read_json_file(&log.to_string_lossy(), &options).unwrap();
+        assert!(result.warnings[0].contains(".txt"));
+
+        // 自定义扩展名列表
+        let custom = FileTypeOptions {
+            extensions: vec![".txt".to_string()],
+        };
+        let result = read_json_file(&log.to_string_lossy(), &custom).unwrap();
+        assert!(result.warnings.is_empty());
+        let result = write_json_file(
+            &temp_dir.join("out.json").to_string_lossy(),
+            "[]",
+            &custom,
+            &EncodingOptions::default(),
+            &WriteOptions::default(),
+        );
+        assert!(result.unwrap().warnings[0].contains(".json"));
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_read_utf16_file_with_bom() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("utf16.json");
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "{\"名\": 1}".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        fs::write(&file_path, bytes).unwrap();
+
+        let result =
+            read_json_file(&file_path.to_string_lossy(), &FileTypeOptions::default()).unwrap();
+        assert_eq!(result.content, "{\"名\": 1}");
+        assert_eq!(result.encoding, TextEncoding::Utf16Le);
+        assert!(result.bom);
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_write_json_file_with_encoding() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("utf8-bom.json");
+        let options = EncodingOptions {
+            encoding: TextEncoding::Utf8,
+            bom: true,
+        };
+
+        let file_types = FileTypeOptions::default();
+        let write = WriteOptions::default();
+        write_json_file(
+            &file_path.to_string_lossy(),
+            "[1]",
+            &file_types,
+            &options,
+            &write,
+        )
+        .unwrap();
+        assert_eq!(fs::read(&file_path).unwrap(), b"\xEF\xBB\xBF[1]");
+
+        let result =
+            read_json_file(&file_path.to_string_lossy(), &FileTypeOptions::default()).unwrap();
+        assert_eq!(result.content, "[1]");
+        assert!(result.bom);
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_write_json_file() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("output.json");
+        let test_content = r#"{"result": "success"}"#;
+
+        let result = write_json_file(
+            &file_path.to_string_lossy(),
+            test_content,
+            &FileTypeOptions::default(),
+            &EncodingOptions::default(),
+            &WriteOptions::default(),
+        );
+        assert!(result.is_ok());
+
+        let written_content = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(written_content, test_content);
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_write_keeps_imported_path() {
+        let temp_dir = setup_test_env();
+        let file_types = FileTypeOptions::default();
+        let encoding = EncodingOptions::default();
+
+        // 根据内容导入的 .txt 文件和无扩展名文件按原路径写回，并给出警告
+        for name in ["response.txt", "response"] {
+            let file_path = temp_dir.join(name);
+            fs::write(&file_path, "[1]").unwrap();
+            let file_path = file_path.to_string_lossy();
+            let imported = read_json_file(&file_path, &file_types).unwrap();
+            let write = WriteOptions {
+                backup: false,
+                expected: Some(imported.snapshot),
+            };
+
+            let written =
+                write_json_file(&file_path, "[\n  1\n]\n", &file_types, &encoding, &write).unwrap();
+            assert_eq!(written.path, file_path);
+            assert_eq!(written.warnings.len(), 1, "{}", name);
+            assert_eq!(
+                fs::read_to_string(file_path.as_ref()).unwrap(),
+                "[\n  1\n]\n"
+            );
+        }
+        assert!(!temp_dir.join("response.json").exists());
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_write_invalid_extension() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("output.txt");
+
+        let result = write_json_file(
+            &file_path.to_string_lossy(),
+            "not json",
+            &FileTypeOptions::default(),
+            &EncodingOptions::default(),
+            &WriteOptions::default(),
+        );
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("必须是 .json 格式"));
+        assert!(!file_path.exists());
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_atomic_write_with_backup_and_conflict_check() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("atomic.json");
+        let file_path = file_path.to_string_lossy();
+        let file_types = FileTypeOptions::default();
+        let encoding = EncodingOptions::default();
+        fs::write(file_path.as_ref(), "[1]").unwrap();
+
+        let imported = read_json_file(&file_path, &file_types).unwrap();
+        let options = WriteOptions {
+            backup: true,
+            expected: Some(imported.snapshot),
+        };
+        let written = write_json_file(&file_path, "[2]", &file_types, &encoding, &options).unwrap();
+        assert_eq!(fs::read_to_string(file_path.as_ref()).unwrap(), "[2]");
+        let backup_path = written.backup_path.unwrap();
+        assert!(backup_path.ends_with("atomic.json.bak"));
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), "[1]");
+
+        // 写入后返回的新状态可用于下一次写入
+        let options = WriteOptions {
+            backup: false,
+            expected: Some(written.snapshot.clone()),
+        };
+        assert!(write_json_file(&file_path, "[3]", &file_types, &encoding, &options).is_ok());
+
+        // 文件被其他程序修改后，使用旧状态写入会被拒绝且文件保持不变
+        let stale = WriteOptions {
+            backup: false,
+            expected: Some(written.snapshot),
+        };
+        let result = write_json_file(&file_path, "[4]", &file_types, &encoding, &stale);
+        assert!(result.unwrap_err().contains("已被其他程序修改"));
+        assert_eq!(fs::read_to_string(file_path.as_ref()).unwrap(), "[3]");
+
+        // 没有残留的临时文件
+        let leftovers = fs::read_dir(&temp_dir)
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+
+        cleanup_test_env();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("private.json");
+        fs::write(&file_path, "{}").unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        write_json_file(
+            &file_path.to_string_lossy(),
+            "[]",
+            &FileTypeOptions::default(),
+            &EncodingOptions::default(),
+            &WriteOptions::default(),
+        )
+        .unwrap();
+        let mode = fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        cleanup_test_env();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_through_symlink() {
+        let temp_dir = setup_test_env();
+        let real_dir = temp_dir.join("real");
+        fs::create_dir_all(&real_dir).unwrap();
+        let real_path = real_dir.join("data.json");
+        let link_path = temp_dir.join("link.json");
+        fs::write(&real_path, "[1]").unwrap();
+        std::os::unix::fs::symlink(&real_path, &link_path).unwrap();
+
+        let options = WriteOptions {
+            backup: true,
+            expected: None,
+        };
+        let written = write_json_file(
+            &link_path.to_string_lossy(),
+            "[2]",
+            &FileTypeOptions::default(),
+            &EncodingOptions::default(),
+            &options,
+        )
+        .unwrap();
+
+        assert!(fs::symlink_metadata(&link_path)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&real_path).unwrap(), "[2]");
+        assert!(written.backup_path.unwrap().ends_with("real/data.json.bak"));
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        // 64 位 FNV-1a 的标准测试向量
+        assert_eq!(content_hash(b""), "cbf29ce484222325");
+        assert_eq!(content_hash(b"a"), "af63dc4c8601ec8c");
+        assert_eq!(content_hash(b"foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn test_write_csv_file() {
+        let temp_dir = setup_test_env();
+        let test_content = "a,b\n1,2\n";
+
+        let result = write_csv_file(&temp_dir.join("table").to_string_lossy(), test_content);
+        assert!(result.unwrap().ends_with("table.csv"));
+        assert_eq!(fs::read_to_string(temp_dir.join("table.csv")).unwrap(), test_content);
+
+        assert!(write_csv_file(&temp_dir.join("table.tsv").to_string_lossy(), test_content).is_ok());
+
+        let result = write_csv_file(&temp_dir.join("table.json").to_string_lossy(), test_content);
+        assert!(result.unwrap_err().contains(".csv 或 .tsv"));
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_binary_file_round_trip() {
+        let temp_dir = setup_test_env();
+        let bytes = [0x81, 0xa1, 0x61, 0x01];
+
+        let saved = write_binary_file(&temp_dir.join("payload").to_string_lossy(), &bytes).unwrap();
+        assert!(saved.ends_with("payload.bin"));
+        assert_eq!(read_binary_file(&saved).unwrap(), bytes);
+
+        let result = read_binary_file(&temp_dir.join("payload.json").to_string_lossy());
+        assert!(result.is_err());
+
+        cleanup_test_env();
+    }
+
+    #[test]
+    fn test_can_write_file() {
+        let temp_dir = setup_test_env();
+        let file_path = temp_dir.join("test.json");
+
+        // 目录存在时应该可写
+        assert!(can_write_file(&file_path.to_string_lossy()));
+
+        cleanup_test_env();
+    }
+}
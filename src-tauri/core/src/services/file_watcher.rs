@@ -0,0 +1,339 @@
+//! 文件监视服务
+//! 导入文件后监视其变化 (被编辑器保存、被其他程序覆盖或删除)，可选自动重新验证
+
+use crate::models::file::{FileChangeEvent, FileChangeKind, FileSnapshot, WatchOptions};
+use crate::models::validation::ValidationResult;
+use crate::services::file_io;
+use crate::services::json_parser::validate_json_with_context;
+use crate::services::operation::OperationContext;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 合并连续变化的等待时间 (编辑器保存时通常会产生多个文件系统事件)
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// 文件持续变化时的最长等待时间，超过后即使仍有事件也检查一次
+const MAX_WAIT: Duration = Duration::from_secs(1);
+
+/// 文件变化回调
+pub type ChangeCallback = Arc<dyn Fn(FileChangeEvent) + Send + Sync>;
+
+/// 文件监视注册表 (线程安全)
+///
+/// 每个监视对应一个后台线程；移除监视时释放底层监视器，线程随之退出
+#[derive(Default)]
+pub struct FileWatcherRegistry {
+    watchers: Mutex<HashMap<String, RecommendedWatcher>>,
+    counter: AtomicU64,
+}
+
+impl FileWatcherRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 开始监视文件
+    ///
+    /// 监视文件所在目录而不是文件本身，这样通过“写入临时文件再重命名”保存的文件也能被检测到。
+    /// 内容未变化的事件 (如只修改了访问时间) 会被忽略
+    ///
+    /// # Returns
+    /// 监视 ID，用于 [`FileWatcherRegistry::unwatch`]
+    pub fn watch(
+        &self,
+        file_path: &str,
+        options: WatchOptions,
+        on_change: ChangeCallback,
+    ) -> Result<String, String> {
+        let path = Path::new(file_path)
+            .canonicalize()
+            .map_err(|e| format!("无法监视文件 {}: {}", file_path, e))?;
+        if !path.is_file() {
+            return Err(format!("路径不是文件: {}", file_path));
+        }
+        let directory = path
+            .parent()
+            .ok_or_else(|| format!("无法监视文件 {}: 没有所在目录", file_path))?
+            .to_path_buf();
+        let initial = file_io::read_snapshot(&path)?;
+
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            // 接收端已退出时忽略
+            let _ = sender.send(event);
+        })
+        .map_err(|e| format!("创建文件监视器失败: {}", e))?;
+        watcher
+            .watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("无法监视目录 {}: {}", directory.display(), e))?;
+
+        let seq = self.counter.fetch_add(1, Ordering::SeqCst);
+        let watch_id = format!("watch-{}-{}", chrono::Utc::now().timestamp_millis(), seq);
+        let monitor = Monitor {
+            watch_id: watch_id.clone(),
+            path,
+            options,
+            last: Some(initial),
+            on_change,
+        };
+        thread::spawn(move || monitor.run(receiver));
+
+        self.watchers
+            .lock()
+            .unwrap()
+            .insert(watch_id.clone(), watcher);
+        Ok(watch_id)
+    }
+
+    /// 停止监视
+    ///
+    /// # Returns
+    /// 监视存在并已移除时返回 `true`
+    pub fn unwatch(&self, watch_id: &str) -> bool {
+        self.watchers.lock().unwrap().remove(watch_id).is_some()
+    }
+
+    /// 当前的监视数量
+    pub fn active_count(&self) -> usize {
+        self.watchers.lock().unwrap().len()
+    }
+}
+
+/// 单个文件的监视线程状态
+struct Monitor {
+    watch_id: String,
+    path: PathBuf,
+    options: WatchOptions,
+    /// 上一次通知时的文件状态 (文件不存在时为空)
+    last: Option<FileSnapshot>,
+    on_change: ChangeCallback,
+}
+
+impl Monitor {
+    /// 处理文件系统事件，直到监视器被释放
+    fn run(mut self, receiver: Receiver<notify::Result<Event>>) {
+        while let Ok(event) = receiver.recv() {
+            if !self.is_relevant(&event) {
+                continue;
+            }
+            // 等待该文件的事件平息后再检查；同一目录中其他文件的事件不会推迟检查
+            let deadline = Instant::now() + MAX_WAIT;
+            let mut quiet_at = Instant::now() + DEBOUNCE;
+            loop {
+                let wait = quiet_at
+                    .min(deadline)
+                    .saturating_duration_since(Instant::now());
+                if wait.is_zero() {
+                    break;
+                }
+                match receiver.recv_timeout(wait) {
+                    Ok(event) if self.is_relevant(&event) => quiet_at = Instant::now() + DEBOUNCE,
+                    Ok(_) => {}
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            self.check();
+        }
+    }
+
+    fn is_relevant(&self, event: &notify::Result<Event>) -> bool {
+        match event {
+            Ok(event) => event
+                .paths
+                .iter()
+                .any(|path| path.file_name() == self.path.file_name()),
+            // 监视出错 (如事件队列溢出) 时重新检查一次
+            Err(_) => true,
+        }
+    }
+
+    /// 比较文件的当前状态，内容变化或文件被删除时通知
+    fn check(&mut self) {
+        let current = file_io::read_snapshot(&self.path).ok();
+        let kind = match (&self.last, &current) {
+            (None, None) => return,
+            (Some(_), None) => FileChangeKind::Removed,
+            (Some(last), Some(current))
+                if last.size == current.size && last.hash == current.hash =>
+            {
+                return
+            }
+            _ => FileChangeKind::Modified,
+        };
+
+        let validation = match kind {
+            FileChangeKind::Modified if self.options.revalidate => Some(self.revalidate()),
+            _ => None,
+        };
+        (self.on_change)(FileChangeEvent {
+            watch_id: self.watch_id.clone(),
+            path: self.path.to_string_lossy().to_string(),
+            kind,
+            snapshot: current.clone(),
+            validation,
+        });
+        self.last = current;
+    }
+
+    fn revalidate(&self) -> ValidationResult {
+        let path = self.path.to_string_lossy();
+        match file_io::read_json_file(&path, &self.options.file_types) {
+            Ok(file) => validate_json_with_context(
+                &file.content,
+                &self.options.validation,
+                &OperationContext::detached(),
+            ),
+            Err(message) => ValidationResult::Error {
+                message,
+                line: None,
+                column: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn collector() -> (ChangeCallback, Receiver<FileChangeEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let callback: ChangeCallback = Arc::new(move |event| {
+            let _ = sender.send(event);
+        });
+        (callback, receiver)
+    }
+
+    #[test]
+    fn test_watch_modify_and_remove() {
+        let dir = std::env::temp_dir().join(format!("file-watcher-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("watched.json");
+        fs::write(&file, "[1]").unwrap();
+
+        let registry = FileWatcherRegistry::new();
+        let (callback, events) = collector();
+        let options = WatchOptions {
+            revalidate: true,
+            ..Default::default()
+        };
+        let watch_id = registry
+            .watch(&file.to_string_lossy(), options, callback)
+            .unwrap();
+        assert_eq!(registry.active_count(), 1);
+
+        let timeout = Duration::from_secs(5);
+        fs::write(&file, "[1,]").unwrap();
+        let event = events.recv_timeout(timeout).unwrap();
+        assert_eq!(event.watch_id, watch_id);
+        assert_eq!(event.kind, FileChangeKind::Modified);
+        assert_eq!(event.snapshot.unwrap().size, 4);
+        assert!(matches!(
+            event.validation,
+            Some(ValidationResult::Error { .. })
+        ));
+
+        fs::remove_file(&file).unwrap();
+        let event = events.recv_timeout(timeout).unwrap();
+        assert_eq!(event.kind, FileChangeKind::Removed);
+        assert!(event.snapshot.is_none() && event.validation.is_none());
+
+        assert!(registry.unwatch(&watch_id));
+        assert!(!registry.unwatch(&watch_id));
+        fs::write(&file, "[2]").unwrap();
+        assert!(events.recv_timeout(DEBOUNCE * 3).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// 直接向监视线程发送事件，返回事件发送端和变化通知的接收端
+    fn spawn_monitor(
+        file: &Path,
+    ) -> (
+        mpsc::Sender<notify::Result<Event>>,
+        Receiver<FileChangeEvent>,
+    ) {
+        let (callback, changes) = collector();
+        let monitor = Monitor {
+            watch_id: "watch-test".to_string(),
+            path: file.to_path_buf(),
+            options: WatchOptions::default(),
+            last: Some(file_io::read_snapshot(file).unwrap()),
+            on_change: callback,
+        };
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || monitor.run(receiver));
+        (sender, changes)
+    }
+
+    fn event_for(path: &Path) -> notify::Result<Event> {
+        Ok(Event::new(notify::EventKind::Any).add_path(path.to_path_buf()))
+    }
+
+    /// 在后台按固定间隔发送某个路径的事件，持续指定时间
+    fn spam(
+        sender: &mpsc::Sender<notify::Result<Event>>,
+        path: &Path,
+        duration: Duration,
+    ) -> thread::JoinHandle<()> {
+        let sender = sender.clone();
+        let path = path.to_path_buf();
+        thread::spawn(move || {
+            let end = Instant::now() + duration;
+            while Instant::now() < end {
+                let _ = sender.send(event_for(&path));
+                thread::sleep(Duration::from_millis(20));
+            }
+        })
+    }
+
+    #[test]
+    fn test_debounce_ignores_sibling_events_and_caps_wait() {
+        let dir =
+            std::env::temp_dir().join(format!("file-watcher-debounce-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("watched.json");
+        let sibling = dir.join("other.log");
+        fs::write(&file, "[1]").unwrap();
+
+        // 其他文件持续变化不会推迟通知 (在最长等待时间之前就已通知)
+        let (sender, changes) = spawn_monitor(&file);
+        fs::write(&file, "[2]").unwrap();
+        sender.send(event_for(&file)).unwrap();
+        let spammer = spam(&sender, &sibling, MAX_WAIT * 2);
+        let event = changes.recv_timeout(DEBOUNCE * 3).unwrap();
+        assert_eq!(event.kind, FileChangeKind::Modified);
+        spammer.join().unwrap();
+
+        // 文件本身持续变化时，最迟在最长等待时间后通知
+        fs::write(&file, "[3]").unwrap();
+        let spammer = spam(&sender, &file, MAX_WAIT * 2);
+        let event = changes.recv_timeout(MAX_WAIT + DEBOUNCE * 2).unwrap();
+        assert_eq!(event.snapshot.unwrap().size, 3);
+        spammer.join().unwrap();
+
+        drop(sender);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watch_missing_file() {
+        let registry = FileWatcherRegistry::new();
+        let (callback, _events) = collector();
+        let result = registry.watch(
+            "/nonexistent/path/watched.json",
+            WatchOptions::default(),
+            callback,
+        );
+        assert!(result.unwrap_err().contains("无法监视文件"));
+        assert_eq!(registry.active_count(), 0);
+    }
+}
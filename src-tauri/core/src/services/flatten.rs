@@ -0,0 +1,576 @@
+//! 扁平化服务
+//! 将嵌套 JSON 展开为单层的路径键映射 (`a.b[0].c`)，以及从路径键映射还原嵌套结构
+
+use crate::models::conversion::ConversionResult;
+use crate::models::transform::{ArrayNotation, FlattenOptions};
+use crate::services::json_formatter::pretty_print;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 还原时允许的最大数组下标，防止 `a[99999999]` 这样的键分配巨大的数组
+const MAX_ARRAY_INDEX: usize = 100_000;
+
+/// 将嵌套 JSON 扁平化为单层对象
+///
+/// 空对象和空数组作为值保留 (`"a.b": {}`)，以便还原
+///
+/// # Arguments
+/// * `input` - 根节点为对象或数组的 JSON
+/// * `options` - 分隔符与数组下标表示方式
+pub fn flatten_json(input: &str, options: &FlattenOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    let value = match parse_input(input, options) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+    if !value.is_object() && !value.is_array() {
+        return ConversionResult::error("扁平化需要对象或数组作为根节点");
+    }
+
+    let mut flat = Map::new();
+    let mut warnings = Vec::new();
+    flatten_children(&value, "", options, &mut flat, &mut warnings);
+
+    finish(&Value::Object(flat), options, warnings, start)
+}
+
+/// 将路径键映射还原为嵌套 JSON
+///
+/// # Arguments
+/// * `input` - 键为路径的单层 JSON 对象
+/// * `options` - 分隔符与数组下标表示方式，应与扁平化时一致
+///
+/// # Returns
+/// 转换结果；路径冲突 (如同时存在 `a` 和 `a.b`) 时返回错误
+pub fn unflatten_json(input: &str, options: &FlattenOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    let value = match parse_input(input, options) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+    let flat = match value {
+        Value::Object(map) => map,
+        _ => return ConversionResult::error("还原需要以路径为键的对象作为根节点"),
+    };
+
+    let mut root = Node::Empty;
+    for (key, value) in flat {
+        let segments = match parse_path(&key, options) {
+            Ok(segments) => segments,
+            Err(message) => return ConversionResult::error(message),
+        };
+        if let Err(message) = insert(&mut root, &segments, value, &key) {
+            return ConversionResult::error(message);
+        }
+    }
+
+    let mut warnings = Vec::new();
+    let value = match root {
+        Node::Empty => Value::Object(Map::new()),
+        node => into_value(node, "", options, &mut warnings),
+    };
+
+    finish(&value, options, warnings, start)
+}
+
+/// 路径中的一段
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// 还原过程中的中间节点
+enum Node {
+    /// 尚未赋值 (稀疏数组中的空位)
+    Empty,
+    /// 叶子值及其来源键
+    Leaf(Value, String),
+    Object(BTreeMap<String, Node>),
+    Array(Vec<Node>),
+}
+
+fn parse_input(input: &str, options: &FlattenOptions) -> Result<Value, ConversionResult> {
+    check_input(input).map_err(ConversionResult::error)?;
+    if options.separator.is_empty() {
+        return Err(ConversionResult::error("分隔符不能为空"));
+    }
+
+    serde_json::from_str::<Value>(input).map_err(|e| ConversionResult::Error {
+        message: format!("JSON 解析失败: {}", e),
+        line: Some(e.line()),
+        column: Some(e.column()),
+    })
+}
+
+fn finish(
+    value: &Value,
+    options: &FlattenOptions,
+    warnings: Vec<String>,
+    start: Instant,
+) -> ConversionResult {
+    match pretty_print(value, options.indent) {
+        Ok(output) => ConversionResult::Success {
+            size: output.len(),
+            output,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+            warnings,
+        },
+        Err(message) => ConversionResult::error(message),
+    }
+}
+
+/// 展开容器的所有子节点
+fn flatten_children(
+    value: &Value,
+    path: &str,
+    options: &FlattenOptions,
+    flat: &mut Map<String, Value>,
+    warnings: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                check_key(key, options, warnings);
+                flatten_value(
+                    child,
+                    &join_key(path, key, options),
+                    options,
+                    flat,
+                    warnings,
+                );
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                flatten_value(
+                    child,
+                    &join_index(path, index, options),
+                    options,
+                    flat,
+                    warnings,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn flatten_value(
+    value: &Value,
+    path: &str,
+    options: &FlattenOptions,
+    flat: &mut Map<String, Value>,
+    warnings: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            flatten_children(value, path, options, flat, warnings)
+        }
+        Value::Array(items) if !items.is_empty() => {
+            flatten_children(value, path, options, flat, warnings)
+        }
+        leaf => {
+            if flat.insert(path.to_string(), leaf.clone()).is_some() {
+                push_warning(
+                    warnings,
+                    format!("扁平化后的键 \"{}\" 重复，后出现的值覆盖了先前的值", path),
+                );
+            }
+        }
+    }
+}
+
+/// 检查会导致还原结果不同的键
+fn check_key(key: &str, options: &FlattenOptions, warnings: &mut Vec<String>) {
+    if key.contains(options.separator.as_str()) {
+        push_warning(
+            warnings,
+            format!(
+                "键 \"{}\" 含有分隔符 \"{}\"，还原时会被拆分为嵌套路径",
+                key, options.separator
+            ),
+        );
+    }
+
+    let ambiguous = match options.array_notation {
+        ArrayNotation::Brackets => key.contains('['),
+        ArrayNotation::Separator => is_canonical_index(key),
+    };
+    if ambiguous {
+        push_warning(warnings, format!("键 \"{}\" 还原时可能被视为数组下标", key));
+    }
+}
+
+fn push_warning(warnings: &mut Vec<String>, warning: String) {
+    if !warnings.contains(&warning) {
+        warnings.push(warning);
+    }
+}
+
+fn join_key(path: &str, key: &str, options: &FlattenOptions) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}{}{}", path, options.separator, key)
+    }
+}
+
+fn join_index(path: &str, index: usize, options: &FlattenOptions) -> String {
+    match options.array_notation {
+        ArrayNotation::Brackets => format!("{}[{}]", path, index),
+        ArrayNotation::Separator => join_key(path, &index.to_string(), options),
+    }
+}
+
+/// 不带前导零的十进制数字
+fn is_canonical_index(text: &str) -> bool {
+    !text.is_empty()
+        && text.bytes().all(|b| b.is_ascii_digit())
+        && (text == "0" || !text.starts_with('0'))
+}
+
+/// 将路径键拆分为段
+fn parse_path(key: &str, options: &FlattenOptions) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+
+    for part in key.split(options.separator.as_str()) {
+        if options.array_notation == ArrayNotation::Separator {
+            segments.push(Segment::Key(part.to_string()));
+            continue;
+        }
+
+        // 不是合法的 `name[0][1]` 形式时，整段作为普通键
+        match parse_brackets(part) {
+            Some((name, indices)) => {
+                if !name.is_empty() || indices.is_empty() {
+                    segments.push(Segment::Key(name.to_string()));
+                }
+                for index in indices {
+                    if index > MAX_ARRAY_INDEX {
+                        return Err(format!(
+                            "键 \"{}\" 中的数组下标 {} 超过上限 {}",
+                            key, index, MAX_ARRAY_INDEX
+                        ));
+                    }
+                    segments.push(Segment::Index(index));
+                }
+            }
+            None => segments.push(Segment::Key(part.to_string())),
+        }
+    }
+
+    Ok(segments)
+}
+
+/// 解析 `name[0][1]` 形式的路径段
+fn parse_brackets(part: &str) -> Option<(&str, Vec<usize>)> {
+    let open = match part.find('[') {
+        Some(open) => open,
+        None => return Some((part, Vec::new())),
+    };
+
+    let mut indices = Vec::new();
+    let mut rest = &part[open..];
+    while let Some(inner) = rest.strip_prefix('[') {
+        let close = inner.find(']')?;
+        let digits = &inner[..close];
+        if !is_canonical_index(digits) {
+            return None;
+        }
+        indices.push(digits.parse().ok()?);
+        rest = &inner[close + 1..];
+    }
+
+    if rest.is_empty() {
+        Some((&part[..open], indices))
+    } else {
+        None
+    }
+}
+
+fn insert(node: &mut Node, segments: &[Segment], value: Value, key: &str) -> Result<(), String> {
+    if let Node::Leaf(_, existing) = node {
+        return Err(format!(
+            "键 \"{}\" 与 \"{}\" 冲突：\"{}\" 已是一个值，不能再包含子路径",
+            key, existing, existing
+        ));
+    }
+
+    let (first, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => {
+            if !matches!(node, Node::Empty) {
+                return Err(format!("键 \"{}\" 冲突：该路径下已存在子键", key));
+            }
+            *node = Node::Leaf(value, key.to_string());
+            return Ok(());
+        }
+    };
+
+    match first {
+        Segment::Key(name) => {
+            if let Node::Empty = node {
+                *node = Node::Object(BTreeMap::new());
+            }
+            match node {
+                Node::Object(map) => insert(
+                    map.entry(name.clone()).or_insert(Node::Empty),
+                    rest,
+                    value,
+                    key,
+                ),
+                _ => Err(format!(
+                    "键 \"{}\" 冲突：同一位置既有数组下标又有对象键",
+                    key
+                )),
+            }
+        }
+        Segment::Index(index) => {
+            if let Node::Empty = node {
+                *node = Node::Array(Vec::new());
+            }
+            match node {
+                Node::Array(items) => {
+                    if items.len() <= *index {
+                        items.resize_with(index + 1, || Node::Empty);
+                    }
+                    insert(&mut items[*index], rest, value, key)
+                }
+                _ => Err(format!(
+                    "键 \"{}\" 冲突：同一位置既有数组下标又有对象键",
+                    key
+                )),
+            }
+        }
+    }
+}
+
+fn into_value(
+    node: Node,
+    path: &str,
+    options: &FlattenOptions,
+    warnings: &mut Vec<String>,
+) -> Value {
+    match node {
+        Node::Empty => Value::Null,
+        Node::Leaf(value, _) => value,
+        Node::Array(items) => {
+            let missing: Vec<String> = items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| matches!(item, Node::Empty))
+                .map(|(index, _)| index.to_string())
+                .collect();
+            if !missing.is_empty() {
+                warnings.push(format!(
+                    "数组 \"{}\" 缺少下标 {}，已填充 null",
+                    path,
+                    missing.join(", ")
+                ));
+            }
+
+            Value::Array(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        into_value(item, &join_index(path, index, options), options, warnings)
+                    })
+                    .collect(),
+            )
+        }
+        Node::Object(map) => {
+            // 分隔符表示法下，键恰好为 0..n 的对象还原为数组
+            if options.array_notation == ArrayNotation::Separator && is_sequence(&map) {
+                let mut items: Vec<(usize, Node)> = map
+                    .into_iter()
+                    .map(|(key, item)| (key.parse().unwrap_or(0), item))
+                    .collect();
+                items.sort_by_key(|(index, _)| *index);
+                return Value::Array(
+                    items
+                        .into_iter()
+                        .map(|(index, item)| {
+                            into_value(item, &join_index(path, index, options), options, warnings)
+                        })
+                        .collect(),
+                );
+            }
+
+            Value::Object(
+                map.into_iter()
+                    .map(|(key, item)| {
+                        let child =
+                            into_value(item, &join_key(path, &key, options), options, warnings);
+                        (key, child)
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// 键是否恰好为 `0..n`
+fn is_sequence(map: &BTreeMap<String, Node>) -> bool {
+    !map.is_empty()
+        && map.keys().all(|key| is_canonical_index(key))
+        && map
+            .keys()
+            .all(|key| key.parse::<usize>().is_ok_and(|index| index < map.len()))
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn run(
+        f: fn(&str, &FlattenOptions) -> ConversionResult,
+        input: Value,
+        options: &FlattenOptions,
+    ) -> (Value, Vec<String>) {
+        match f(&input.to_string(), options) {
+            ConversionResult::Success {
+                output, warnings, ..
+            } => (serde_json::from_str(&output).unwrap(), warnings),
+            ConversionResult::Error { message, .. } => panic!("{}", message),
+        }
+    }
+
+    fn error(f: fn(&str, &FlattenOptions) -> ConversionResult, input: Value) -> String {
+        match f(&input.to_string(), &FlattenOptions::default()) {
+            ConversionResult::Error { message, .. } => message,
+            other => panic!("意外的结果: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_flatten_brackets() {
+        let input = json!({
+            "a": {"b": [{"c": 1}, 2]},
+            "empty": {},
+            "list": [],
+            "n": null
+        });
+        let (flat, warnings) = run(flatten_json, input.clone(), &FlattenOptions::default());
+        assert_eq!(
+            flat,
+            json!({
+                "a.b[0].c": 1,
+                "a.b[1]": 2,
+                "empty": {},
+                "list": [],
+                "n": null
+            })
+        );
+        assert!(warnings.is_empty());
+
+        let (nested, _) = run(unflatten_json, flat, &FlattenOptions::default());
+        assert_eq!(nested, input);
+    }
+
+    #[test]
+    fn test_separator_notation_round_trip() {
+        let options = FlattenOptions {
+            separator: "__".to_string(),
+            array_notation: ArrayNotation::Separator,
+            ..Default::default()
+        };
+        let input = json!({"db": {"hosts": ["a", "b"], "port": 5432}});
+
+        let (flat, _) = run(flatten_json, input.clone(), &options);
+        assert_eq!(
+            flat,
+            json!({"db__hosts__0": "a", "db__hosts__1": "b", "db__port": 5432})
+        );
+
+        let (nested, _) = run(unflatten_json, flat, &options);
+        assert_eq!(nested, input);
+
+        // 不连续的数字键保留为对象
+        let (nested, _) = run(unflatten_json, json!({"x__0": 1, "x__2": 2}), &options);
+        assert_eq!(nested, json!({"x": {"0": 1, "2": 2}}));
+    }
+
+    #[test]
+    fn test_root_array_and_nested_indices() {
+        let input = json!([[1, 2], {"k": true}]);
+        let (flat, _) = run(flatten_json, input.clone(), &FlattenOptions::default());
+        assert_eq!(flat, json!({"[0][0]": 1, "[0][1]": 2, "[1].k": true}));
+
+        let (nested, _) = run(unflatten_json, flat, &FlattenOptions::default());
+        assert_eq!(nested, input);
+    }
+
+    #[test]
+    fn test_flatten_warnings() {
+        let (flat, warnings) = run(
+            flatten_json,
+            json!({"a.b": 1, "a": {"b": 2}, "x[1]": 3}),
+            &FlattenOptions::default(),
+        );
+        assert_eq!(flat["x[1]"], json!(3));
+        assert!(warnings.iter().any(|w| w.contains("\"a.b\" 含有分隔符")));
+        assert!(warnings.iter().any(|w| w.contains("\"a.b\" 重复")));
+        assert!(warnings.iter().any(|w| w.contains("\"x[1]\"")));
+    }
+
+    #[test]
+    fn test_unflatten_sparse_and_literal_keys() {
+        let (nested, warnings) = run(
+            unflatten_json,
+            json!({"a[2]": "z", "b[x]": 1, "c[01]": 2}),
+            &FlattenOptions::default(),
+        );
+        assert_eq!(
+            nested,
+            json!({"a": [null, null, "z"], "b[x]": 1, "c[01]": 2})
+        );
+        assert_eq!(warnings, vec!["数组 \"a\" 缺少下标 0, 1，已填充 null"]);
+    }
+
+    #[test]
+    fn test_unflatten_conflicts() {
+        let message = error(unflatten_json, json!({"a": 1, "a.b": 2}));
+        assert!(message.contains("\"a.b\""));
+        assert!(message.contains("\"a\""));
+
+        let message = error(unflatten_json, json!({"a[0]": 1, "a.b": 2}));
+        assert!(message.contains("数组下标"));
+
+        assert!(error(unflatten_json, json!({"a[100001]": 1})).contains("上限"));
+        assert!(error(unflatten_json, json!([1])).contains("对象"));
+        assert!(error(flatten_json, json!("text")).contains("根节点"));
+
+        let options = FlattenOptions {
+            separator: String::new(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            flatten_json("{}", &options),
+            ConversionResult::Error { .. }
+        ));
+    }
+}
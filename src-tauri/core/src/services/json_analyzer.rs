@@ -0,0 +1,434 @@
+//! JSON 结构分析服务
+//! 统计深度、节点类型、最大数组、最长字符串、键分布与重复路径，用于定位负载膨胀的原因
+
+use crate::models::analysis::{
+    AnalysisOptions, AnalysisResult, ArrayStat, KeyPathStat, KeyStat, StringStat,
+};
+use crate::models::document::TypeCounts;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::mem::size_of;
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 字符串预览的最大字符数
+const PREVIEW_MAX_CHARS: usize = 80;
+
+/// 对象每个条目在 BTreeMap 中的额外开销估算 (节点指针、长度等的摊销)
+const MAP_ENTRY_OVERHEAD: usize = 16;
+
+/// 分析 JSON 文本的结构
+///
+/// # Arguments
+/// * `input` - JSON 文本
+/// * `options` - 排行榜保留的条目数
+pub fn analyze_json(input: &str, options: &AnalysisOptions) -> AnalysisResult {
+    let start = Instant::now();
+
+    if input.len() > MAX_INPUT_SIZE {
+        return AnalysisResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+    if input.trim().is_empty() {
+        return AnalysisResult::error("输入为空，请提供有效的内容");
+    }
+
+    let value: Value = match serde_json::from_str(input) {
+        Ok(value) => value,
+        Err(e) => {
+            return AnalysisResult::Error {
+                message: format!("JSON 解析失败: {}", e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            }
+        }
+    };
+
+    let mut analyzer = Analyzer::new(options.top_n);
+    analyzer.visit(&value, 1);
+    analyzer.finish(input.len(), start)
+}
+
+/// 按分数降序保留前 N 项，分数相同时先出现的在前
+struct TopList<T> {
+    limit: usize,
+    items: Vec<(usize, T)>,
+}
+
+impl<T> TopList<T> {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            items: Vec::new(),
+        }
+    }
+
+    /// 分数是否能进入榜单 (避免为不入榜的节点构造详情)
+    fn accepts(&self, score: usize) -> bool {
+        self.limit > 0
+            && (self.items.len() < self.limit
+                || self.items.last().is_some_and(|(last, _)| score > *last))
+    }
+
+    fn push(&mut self, score: usize, item: T) {
+        let position = self
+            .items
+            .iter()
+            .position(|(existing, _)| score > *existing)
+            .unwrap_or(self.items.len());
+        self.items.insert(position, (score, item));
+        self.items.truncate(self.limit);
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items.into_iter().map(|(_, item)| item).collect()
+    }
+}
+
+struct Analyzer<'a> {
+    top_n: usize,
+    max_depth: usize,
+    node_count: usize,
+    type_counts: TypeCounts,
+    total_keys: usize,
+    key_bytes: usize,
+    memory: usize,
+    key_counts: HashMap<&'a str, usize>,
+    /// 归一化路径 -> (出现次数, 键名字节数)
+    path_counts: HashMap<String, (usize, usize)>,
+    largest_arrays: TopList<ArrayStat>,
+    longest_strings: TopList<StringStat>,
+    /// 当前节点的 JSON Pointer (根节点为空字符串)
+    pointer: String,
+    /// 当前节点的归一化路径 (数组下标替换为 `*`)
+    pattern: String,
+}
+
+impl<'a> Analyzer<'a> {
+    fn new(top_n: usize) -> Self {
+        Self {
+            top_n,
+            max_depth: 0,
+            node_count: 0,
+            type_counts: TypeCounts::default(),
+            total_keys: 0,
+            key_bytes: 0,
+            memory: 0,
+            key_counts: HashMap::new(),
+            path_counts: HashMap::new(),
+            largest_arrays: TopList::new(top_n),
+            longest_strings: TopList::new(top_n),
+            pointer: String::new(),
+            pattern: String::new(),
+        }
+    }
+
+    /// 访问节点；`depth` 为该节点若是容器时所在的层级
+    fn visit(&mut self, value: &'a Value, depth: usize) {
+        self.node_count += 1;
+        self.memory += size_of::<Value>();
+
+        match value {
+            Value::Object(map) => {
+                self.type_counts.object += 1;
+                self.max_depth = self.max_depth.max(depth);
+                for (key, child) in map {
+                    // 序列化后的键名及两侧引号 (不计转义)
+                    let bytes = key.len() + 2;
+                    self.total_keys += 1;
+                    self.key_bytes += bytes;
+                    self.memory += size_of::<String>() + key.len() + MAP_ENTRY_OVERHEAD;
+                    *self.key_counts.entry(key.as_str()).or_insert(0) += 1;
+
+                    let (pointer_len, pattern_len) = self.enter(key, key);
+                    let entry = self
+                        .path_counts
+                        .entry(self.pattern.clone())
+                        .or_insert((0, 0));
+                    entry.0 += 1;
+                    entry.1 += bytes;
+                    self.visit(child, depth + 1);
+                    self.leave(pointer_len, pattern_len);
+                }
+            }
+            Value::Array(items) => {
+                self.type_counts.array += 1;
+                self.max_depth = self.max_depth.max(depth);
+                if self.largest_arrays.accepts(items.len()) {
+                    let stat = ArrayStat {
+                        pointer: self.pointer.clone(),
+                        length: items.len(),
+                    };
+                    self.largest_arrays.push(items.len(), stat);
+                }
+                for (index, child) in items.iter().enumerate() {
+                    let (pointer_len, pattern_len) = self.enter(&index.to_string(), "*");
+                    self.visit(child, depth + 1);
+                    self.leave(pointer_len, pattern_len);
+                }
+            }
+            Value::String(text) => {
+                self.type_counts.string += 1;
+                self.memory += text.len();
+                // 字符数不超过字节数，先用字节数排除不可能入榜的字符串
+                if self.longest_strings.accepts(text.len()) {
+                    let length = text.chars().count();
+                    if self.longest_strings.accepts(length) {
+                        let stat = StringStat {
+                            pointer: self.pointer.clone(),
+                            length,
+                            bytes: text.len(),
+                            preview: text.chars().take(PREVIEW_MAX_CHARS).collect(),
+                        };
+                        self.longest_strings.push(length, stat);
+                    }
+                }
+            }
+            Value::Number(_) => self.type_counts.number += 1,
+            Value::Bool(_) => self.type_counts.boolean += 1,
+            Value::Null => self.type_counts.null += 1,
+        }
+    }
+
+    /// 进入子节点，返回恢复路径所需的长度
+    fn enter(&mut self, token: &str, pattern_token: &str) -> (usize, usize) {
+        let lengths = (self.pointer.len(), self.pattern.len());
+        push_token(&mut self.pointer, token);
+        push_token(&mut self.pattern, pattern_token);
+        lengths
+    }
+
+    fn leave(&mut self, pointer_len: usize, pattern_len: usize) {
+        self.pointer.truncate(pointer_len);
+        self.pattern.truncate(pattern_len);
+    }
+
+    fn finish(self, size: usize, start: Instant) -> AnalysisResult {
+        let top_n = self.top_n;
+
+        let distinct_keys = self.key_counts.len();
+        let mut top_keys: Vec<KeyStat> = self
+            .key_counts
+            .into_iter()
+            .map(|(key, count)| KeyStat {
+                key: key.to_string(),
+                count,
+            })
+            .collect();
+        top_keys.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        top_keys.truncate(top_n);
+
+        let mut repeated_paths: Vec<KeyPathStat> = self
+            .path_counts
+            .into_iter()
+            .filter(|(_, (count, _))| *count > 1)
+            .map(|(path, (count, key_bytes))| KeyPathStat {
+                path,
+                count,
+                key_bytes,
+            })
+            .collect();
+        repeated_paths.sort_by(|a, b| {
+            b.key_bytes
+                .cmp(&a.key_bytes)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        repeated_paths.truncate(top_n);
+
+        AnalysisResult::Success {
+            size,
+            max_depth: self.max_depth,
+            node_count: self.node_count,
+            type_counts: self.type_counts,
+            total_keys: self.total_keys,
+            distinct_keys,
+            top_keys,
+            largest_arrays: self.largest_arrays.into_items(),
+            longest_strings: self.longest_strings.into_items(),
+            repeated_paths,
+            key_bytes: self.key_bytes,
+            estimated_memory_bytes: self.memory,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// 追加一段 JSON Pointer 引用 (RFC 6901 转义 `~` 与 `/`)
+fn push_token(path: &mut String, token: &str) {
+    path.push('/');
+    for ch in token.chars() {
+        match ch {
+            '~' => path.push_str("~0"),
+            '/' => path.push_str("~1"),
+            _ => path.push(ch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(input: &str, top_n: usize) -> AnalysisResult {
+        analyze_json(input, &AnalysisOptions { top_n })
+    }
+
+    #[test]
+    fn test_analyze_structure() {
+        let input = r#"{
+            "users": [
+                {"id": 1, "name": "Alice", "tags": ["a", "b", "c"]},
+                {"id": 2, "name": "Bob", "tags": [], "meta": null}
+            ],
+            "total": 2,
+            "ok": true
+        }"#;
+
+        match analyze(input, 10) {
+            AnalysisResult::Success {
+                size,
+                max_depth,
+                node_count,
+                type_counts,
+                total_keys,
+                distinct_keys,
+                top_keys,
+                largest_arrays,
+                longest_strings,
+                repeated_paths,
+                key_bytes,
+                estimated_memory_bytes,
+                ..
+            } => {
+                assert_eq!(size, input.len());
+                assert_eq!(max_depth, 4);
+                assert_eq!(node_count, 16);
+                assert_eq!(
+                    type_counts,
+                    TypeCounts {
+                        object: 3,
+                        array: 3,
+                        string: 5,
+                        number: 3,
+                        boolean: 1,
+                        null: 1,
+                    }
+                );
+                assert_eq!(total_keys, 10);
+                assert_eq!(distinct_keys, 7);
+                assert_eq!(
+                    top_keys[0],
+                    KeyStat {
+                        key: "id".to_string(),
+                        count: 2
+                    }
+                );
+                assert_eq!(
+                    largest_arrays,
+                    vec![
+                        ArrayStat {
+                            pointer: "/users/0/tags".to_string(),
+                            length: 3
+                        },
+                        ArrayStat {
+                            pointer: "/users".to_string(),
+                            length: 2
+                        },
+                        ArrayStat {
+                            pointer: "/users/1/tags".to_string(),
+                            length: 0
+                        },
+                    ]
+                );
+                assert_eq!(longest_strings[0].pointer, "/users/0/name");
+                assert_eq!(longest_strings[0].length, 5);
+                assert_eq!(
+                    repeated_paths,
+                    vec![
+                        KeyPathStat {
+                            path: "/users/*/name".to_string(),
+                            count: 2,
+                            key_bytes: 12
+                        },
+                        KeyPathStat {
+                            path: "/users/*/tags".to_string(),
+                            count: 2,
+                            key_bytes: 12
+                        },
+                        KeyPathStat {
+                            path: "/users/*/id".to_string(),
+                            count: 2,
+                            key_bytes: 8
+                        },
+                    ]
+                );
+                assert_eq!(key_bytes, 56);
+                assert!(estimated_memory_bytes > node_count * size_of::<Value>());
+            }
+            AnalysisResult::Error { message, .. } => panic!("{}", message),
+        }
+    }
+
+    #[test]
+    fn test_top_n_limits_and_unicode_length() {
+        let input = r#"["短", "ééééé", "abcd", "xy", {"a/b": ["x"]}]"#;
+
+        match analyze(input, 2) {
+            AnalysisResult::Success {
+                longest_strings,
+                largest_arrays,
+                ..
+            } => {
+                let lengths: Vec<(usize, usize)> = longest_strings
+                    .iter()
+                    .map(|s| (s.length, s.bytes))
+                    .collect();
+                assert_eq!(lengths, vec![(5, 10), (4, 4)]);
+                assert_eq!(largest_arrays[0].pointer, "");
+                assert_eq!(largest_arrays[1].pointer, "/4/a~1b");
+            }
+            AnalysisResult::Error { message, .. } => panic!("{}", message),
+        }
+
+        match analyze(input, 0) {
+            AnalysisResult::Success {
+                longest_strings,
+                largest_arrays,
+                top_keys,
+                ..
+            } => {
+                assert!(longest_strings.is_empty());
+                assert!(largest_arrays.is_empty());
+                assert!(top_keys.is_empty());
+            }
+            AnalysisResult::Error { message, .. } => panic!("{}", message),
+        }
+    }
+
+    #[test]
+    fn test_scalar_root_and_errors() {
+        match analyze("\"text\"", 10) {
+            AnalysisResult::Success {
+                max_depth,
+                node_count,
+                repeated_paths,
+                ..
+            } => {
+                assert_eq!(max_depth, 0);
+                assert_eq!(node_count, 1);
+                assert!(repeated_paths.is_empty());
+            }
+            AnalysisResult::Error { message, .. } => panic!("{}", message),
+        }
+
+        match analyze("{\n  \"a\": }", 10) {
+            AnalysisResult::Error { line, .. } => assert_eq!(line, Some(2)),
+            _ => panic!("Expected Error result"),
+        }
+        assert!(matches!(analyze("  ", 10), AnalysisResult::Error { .. }));
+    }
+}
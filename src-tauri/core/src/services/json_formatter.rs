@@ -0,0 +1,847 @@
+use crate::models::formatting::{EscapeOptions, FormattingOptions, FormattingResult};
+use crate::models::validation::DuplicateKeyPolicy;
+use crate::services::duplicate_keys::{apply_policy, duplicate_message};
+use crate::services::operation::{
+    OperationContext, OperationError, ProgressReader, ProgressWriter,
+};
+use serde_json::ser::{CharEscape, CompactFormatter, Formatter, PrettyFormatter};
+use std::io;
+use std::time::Instant;
+
+/// JSON 最大允许大小：5 MB
+const MAX_JSON_SIZE: usize = 5 * 1024 * 1024;
+
+/// 解析阶段占总进度的百分比，剩余部分属于序列化阶段
+const PARSE_PROGRESS: u8 = 60;
+
+/// 格式化 JSON（美化输出）
+///
+/// # Arguments
+/// * `input` - 待格式化的 JSON 字符串
+/// * `options` - 格式化选项（缩进、尾部换行等）
+///
+/// # Returns
+/// 格式化结果，包含格式化后的字符串或错误信息
+pub fn format_json(input: &str, options: &FormattingOptions) -> FormattingResult {
+    format_json_with_context(input, options, &OperationContext::detached())
+}
+
+/// 格式化 JSON (可取消，并上报进度)
+///
+/// # Arguments
+/// * `input` - 待格式化的 JSON 字符串
+/// * `options` - 格式化选项（缩进、尾部换行等）
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// 格式化结果，取消时返回 `FormattingResult::Cancelled`
+pub fn format_json_with_context(
+    input: &str,
+    options: &FormattingOptions,
+    context: &OperationContext,
+) -> FormattingResult {
+    let start = Instant::now();
+    // 检查输入大小
+    if input.len() > MAX_JSON_SIZE {
+        return FormattingResult::Error {
+            message: format!(
+                "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+                input.len() as f64 / (1024.0 * 1024.0)
+            ),
+        };
+    }
+
+    // 检查空输入
+    if input.trim().is_empty() {
+        return FormattingResult::Error {
+            message: "输入为空，请提供有效的 JSON".to_string(),
+        };
+    }
+
+    // 验证缩进值
+    if options.indent != 2 && options.indent != 4 {
+        return FormattingResult::Error {
+            message: format!(
+                "不支持的缩进值 {}，仅支持 2 或 4 个空格",
+                options.indent
+            ),
+        };
+    }
+
+    // 解析 JSON
+    let value = match parse_value(input, context) {
+        Ok(v) => v,
+        Err(error) => return error.into(),
+    };
+
+    // 按策略处理重复键
+    let value = match apply_policy(input, value, options.duplicate_keys) {
+        Ok((value, _)) => value,
+        Err(duplicate) => {
+            return FormattingResult::Error {
+                message: duplicate_message(&duplicate),
+            };
+        }
+    };
+
+    // 格式化 JSON
+    let formatted = match format_value(
+        &value,
+        options.indent,
+        &options.escape,
+        input.len(),
+        context,
+    ) {
+        Ok(s) => s,
+        Err(_) if context.is_cancelled() => return FormattingResult::Cancelled,
+        Err(e) => {
+            return FormattingResult::Error {
+                message: format!("JSON 格式化失败: {}", e),
+            };
+        }
+    };
+
+    // 根据配置添加或不添加尾部换行符
+    let output = if options.trailing_newline {
+        format!("{}\n", formatted)
+    } else {
+        formatted
+    };
+
+    context.report(100);
+    let duration = start.elapsed();
+
+    FormattingResult::Success {
+        size: output.len(),
+        formatted: output,
+        processing_time_ms: duration.as_millis() as u64,
+    }
+}
+
+/// 压缩 JSON（移除多余空白）
+///
+/// # Arguments
+/// * `input` - 待压缩的 JSON 字符串
+/// * `escape` - 字符串转义选项
+/// * `duplicate_keys` - 重复键的处理策略
+///
+/// # Returns
+/// 格式化结果，包含压缩后的字符串或错误信息
+pub fn minify_json(
+    input: &str,
+    escape: &EscapeOptions,
+    duplicate_keys: DuplicateKeyPolicy,
+) -> FormattingResult {
+    minify_json_with_context(input, escape, duplicate_keys, &OperationContext::detached())
+}
+
+/// 压缩 JSON (可取消，并上报进度)
+///
+/// # Arguments
+/// * `input` - 待压缩的 JSON 字符串
+/// * `escape` - 字符串转义选项
+/// * `duplicate_keys` - 重复键的处理策略
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// 格式化结果，取消时返回 `FormattingResult::Cancelled`
+pub fn minify_json_with_context(
+    input: &str,
+    escape: &EscapeOptions,
+    duplicate_keys: DuplicateKeyPolicy,
+    context: &OperationContext,
+) -> FormattingResult {
+    let start = Instant::now();
+    // 检查输入大小
+    if input.len() > MAX_JSON_SIZE {
+        return FormattingResult::Error {
+            message: format!(
+                "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+                input.len() as f64 / (1024.0 * 1024.0)
+            ),
+        };
+    }
+
+    // 检查空输入
+    if input.trim().is_empty() {
+        return FormattingResult::Error {
+            message: "输入为空，请提供有效的 JSON".to_string(),
+        };
+    }
+
+    // 解析 JSON
+    let value = match parse_value(input, context) {
+        Ok(v) => v,
+        Err(error) => return error.into(),
+    };
+
+    // 按策略处理重复键
+    let value = match apply_policy(input, value, duplicate_keys) {
+        Ok((value, _)) => value,
+        Err(duplicate) => {
+            return FormattingResult::Error {
+                message: duplicate_message(&duplicate),
+            };
+        }
+    };
+
+    // 压缩 JSON（使用 serde_json 的紧凑格式化器，它会移除所有多余空白）
+    let mut buf = Vec::new();
+    let writer = ProgressWriter::new(&mut buf, input.len(), context, PARSE_PROGRESS, 100);
+    let minified = match serialize(&value, writer, CompactFormatter, escape) {
+        Ok(()) => match String::from_utf8(buf) {
+            Ok(s) => s,
+            Err(e) => {
+                return FormattingResult::Error {
+                    message: format!("UTF-8 转换失败: {}", e),
+                };
+            }
+        },
+        Err(e) if e.is_io() && context.is_cancelled() => return FormattingResult::Cancelled,
+        Err(e) => {
+            return FormattingResult::Error {
+                message: format!("JSON 压缩失败: {}", e),
+            };
+        }
+    };
+
+    context.report(100);
+    let duration = start.elapsed();
+
+    FormattingResult::Success {
+        size: minified.len(),
+        formatted: minified,
+        processing_time_ms: duration.as_millis() as u64,
+    }
+}
+
+/// 使用指定缩进美化 JSON 值 (不可取消，供其他服务复用)
+pub fn pretty_print(value: &serde_json::Value, indent: u8) -> Result<String, String> {
+    format_value(
+        value,
+        indent,
+        &EscapeOptions::default(),
+        0,
+        &OperationContext::detached(),
+    )
+}
+
+/// 按转义选项美化 JSON 值；`indent` 为 0 时输出紧凑格式
+pub fn pretty_print_escaped(
+    value: &serde_json::Value,
+    indent: u8,
+    escape: &EscapeOptions,
+) -> Result<String, String> {
+    if indent > 0 {
+        return format_value(value, indent, escape, 0, &OperationContext::detached());
+    }
+
+    let mut buf = Vec::new();
+    serialize(value, &mut buf, CompactFormatter, escape)
+        .map_err(|e| format!("序列化失败: {}", e))?;
+    String::from_utf8(buf).map_err(|e| format!("UTF-8 转换失败: {}", e))
+}
+
+/// 通过可中断的读取器解析 JSON，错误转换为用户可读消息
+fn parse_value(
+    input: &str,
+    context: &OperationContext,
+) -> Result<serde_json::Value, OperationError> {
+    let reader = ProgressReader::new(input.as_bytes(), context, 0, PARSE_PROGRESS);
+    serde_json::from_reader(reader).map_err(|e| {
+        if e.is_io() && context.is_cancelled() {
+            OperationError::Cancelled
+        } else {
+            OperationError::Failed(format!(
+                "JSON 解析失败: {}（第 {} 行，第 {} 列）",
+                e,
+                e.line(),
+                e.column()
+            ))
+        }
+    })
+}
+
+impl From<OperationError> for FormattingResult {
+    fn from(error: OperationError) -> Self {
+        match error {
+            OperationError::Cancelled => FormattingResult::Cancelled,
+            OperationError::Failed(message) => FormattingResult::Error { message },
+        }
+    }
+}
+
+/// 使用指定缩进格式化 JSON 值
+///
+/// `expected_size` 用于估算序列化进度
+fn format_value(
+    value: &serde_json::Value,
+    indent: u8,
+    escape: &EscapeOptions,
+    expected_size: usize,
+    context: &OperationContext,
+) -> Result<String, String> {
+    let indent_str = " ".repeat(indent as usize);
+
+    // 使用 serde_json 的 Serializer 和自定义格式化器
+    let formatter = PrettyFormatter::with_indent(indent_str.as_bytes());
+    let mut buf = Vec::new();
+    let writer = ProgressWriter::new(&mut buf, expected_size, context, PARSE_PROGRESS, 100);
+    serialize(value, writer, formatter, escape).map_err(|e| format!("序列化失败: {}", e))?;
+
+    String::from_utf8(buf).map_err(|e| format!("UTF-8 转换失败: {}", e))
+}
+
+/// 序列化 JSON 值，需要额外转义时包装为 [`EscapeFormatter`]
+fn serialize<W: io::Write, F: Formatter>(
+    value: &serde_json::Value,
+    writer: W,
+    formatter: F,
+    escape: &EscapeOptions,
+) -> serde_json::Result<()> {
+    if escape.is_plain() {
+        let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+        serde::Serialize::serialize(value, &mut serializer)
+    } else {
+        let formatter = EscapeFormatter::new(formatter, *escape);
+        let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+        serde::Serialize::serialize(value, &mut serializer)
+    }
+}
+
+/// 按转义选项输出字符串内容，其余输出交给内部格式化器
+struct EscapeFormatter<F> {
+    inner: F,
+    options: EscapeOptions,
+}
+
+impl<F> EscapeFormatter<F> {
+    fn new(inner: F, options: EscapeOptions) -> Self {
+        Self { inner, options }
+    }
+
+    fn needs_escape(&self, ch: char) -> bool {
+        match ch {
+            '/' => self.options.escape_slash,
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' if self.options.escape_html => true,
+            _ => self.options.ascii_only && !ch.is_ascii(),
+        }
+    }
+
+    fn write_unit<W: ?Sized + io::Write>(&self, writer: &mut W, unit: u16) -> io::Result<()> {
+        if self.options.uppercase_hex {
+            write!(writer, "\\u{:04X}", unit)
+        } else {
+            write!(writer, "\\u{:04x}", unit)
+        }
+    }
+}
+
+impl<F: Formatter> Formatter for EscapeFormatter<F> {
+    fn write_string_fragment<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        let mut start = 0;
+        for (index, ch) in fragment.char_indices() {
+            if !self.needs_escape(ch) {
+                continue;
+            }
+            writer.write_all(&fragment.as_bytes()[start..index])?;
+            if ch == '/' {
+                writer.write_all(b"\\/")?;
+            } else {
+                let mut units = [0u16; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    self.write_unit(writer, *unit)?;
+                }
+            }
+            start = index + ch.len_utf8();
+        }
+        writer.write_all(&fragment.as_bytes()[start..])
+    }
+
+    fn write_char_escape<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        char_escape: CharEscape,
+    ) -> io::Result<()> {
+        match char_escape {
+            CharEscape::AsciiControl(byte) => self.write_unit(writer, byte as u16),
+            other => self.inner.write_char_escape(writer, other),
+        }
+    }
+
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.inner.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.inner.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_object_value(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_json_with_2_spaces() {
+        let input = r#"{"name":"test","value":42,"nested":{"key":"value"}}"#;
+        let options = FormattingOptions {
+            indent: 2,
+            trailing_newline: false,
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(formatted.contains("  \"name\":"));
+            assert!(formatted.contains("    \"key\":"));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_with_4_spaces() {
+        let input = r#"{"name":"test","value":42}"#;
+        let options = FormattingOptions {
+            indent: 4,
+            trailing_newline: false,
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(formatted.contains("    \"name\":"));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_with_trailing_newline() {
+        let input = r#"{"name":"test"}"#;
+        let options = FormattingOptions {
+            indent: 2,
+            trailing_newline: true,
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(formatted.ends_with('\n'));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_without_trailing_newline() {
+        let input = r#"{"name":"test"}"#;
+        let options = FormattingOptions {
+            indent: 2,
+            trailing_newline: false,
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(!formatted.ends_with('\n'));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_ascii_only() {
+        let input = r#"{"名": "café 😀", "plain": "a\"b"}"#;
+        let options = FormattingOptions {
+            indent: 2,
+            trailing_newline: false,
+            escape: EscapeOptions {
+                ascii_only: true,
+                ..Default::default()
+            },
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(formatted.is_ascii());
+            assert!(formatted.contains(r#""\u540d": "caf\u00e9 \ud83d\ude00""#));
+            assert!(formatted.contains(r#""plain": "a\"b""#));
+            let original: serde_json::Value = serde_json::from_str(input).unwrap();
+            let reparsed: serde_json::Value = serde_json::from_str(&formatted).unwrap();
+            assert_eq!(original, reparsed);
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_escape_options() {
+        let input = "{\"html\":\"</script><b>&\",\"url\":\"a/b\",\"text\":\"é\\u001f\\u2028\"}";
+        let escape = EscapeOptions {
+            escape_slash: true,
+            escape_html: true,
+            uppercase_hex: true,
+            ..Default::default()
+        };
+
+        let result = minify_json(input, &escape, DuplicateKeyPolicy::default());
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(formatted.contains(r#""html":"\u003C\/script\u003E\u003Cb\u003E\u0026""#));
+            assert!(formatted.contains(r#""url":"a\/b""#));
+            // 未开启 ascii_only 时保留非 ASCII 字符，控制字符使用大写十六进制
+            assert!(formatted.contains(r#""text":"é\u001F\u2028""#));
+            let original: serde_json::Value = serde_json::from_str(input).unwrap();
+            let reparsed: serde_json::Value = serde_json::from_str(&formatted).unwrap();
+            assert_eq!(original, reparsed);
+        } else {
+            panic!("Expected Success result");
+        }
+
+        // 默认输出与 serde_json 一致
+        if let FormattingResult::Success { formatted, .. } = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        ) {
+            assert!(formatted.contains(r#""url":"a/b""#));
+            assert!(formatted.contains("\\u001f\u{2028}"));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_escape_options_deserialize_flat() {
+        let options: FormattingOptions =
+            serde_json::from_str(r#"{"indent": 4, "ascii_only": true, "escape_html": true}"#)
+                .unwrap();
+        assert_eq!(options.indent, 4);
+        assert!(options.trailing_newline);
+        assert!(options.escape.ascii_only);
+        assert!(options.escape.escape_html);
+        assert!(!options.escape.escape_slash);
+
+        let result = format_json(r#"{"a": "<ü>"}"#, &options);
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(formatted, "{\n    \"a\": \"\\u003c\\u00fc\\u003e\"\n}\n");
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_duplicate_key_policy() {
+        let input = r#"{"a": 1, "b": 2, "a": 3}"#;
+        let format = |duplicate_keys| {
+            let options = FormattingOptions {
+                trailing_newline: false,
+                duplicate_keys,
+                ..Default::default()
+            };
+            format_json(input, &options)
+        };
+
+        match format(DuplicateKeyPolicy::KeepFirst) {
+            FormattingResult::Success { formatted, .. } => {
+                assert_eq!(formatted, "{\n  \"a\": 1,\n  \"b\": 2\n}")
+            }
+            other => panic!("{:?}", other),
+        }
+        match format(DuplicateKeyPolicy::KeepLast) {
+            FormattingResult::Success { formatted, .. } => assert!(formatted.contains("\"a\": 3")),
+            other => panic!("{:?}", other),
+        }
+        match format(DuplicateKeyPolicy::Error) {
+            FormattingResult::Error { message } => assert!(message.contains("第 1 行，第 18 列")),
+            _ => panic!("Expected Error result"),
+        }
+
+        // 压缩使用相同的策略
+        let minify = |duplicate_keys| minify_json(input, &EscapeOptions::default(), duplicate_keys);
+        match minify(DuplicateKeyPolicy::KeepFirst) {
+            FormattingResult::Success { formatted, .. } => {
+                assert_eq!(formatted, r#"{"a":1,"b":2}"#)
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(
+            minify(DuplicateKeyPolicy::Error),
+            FormattingResult::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn test_format_json_invalid_indent() {
+        let input = r#"{"name":"test"}"#;
+        let options = FormattingOptions {
+            indent: 3,
+            trailing_newline: false,
+            escape: EscapeOptions::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        };
+
+        let result = format_json(input, &options);
+
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_format_json_invalid_json() {
+        let input = r#"{"name": invalid}"#;
+        let options = FormattingOptions::default();
+
+        let result = format_json(input, &options);
+
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_format_json_empty_input() {
+        let input = "";
+        let options = FormattingOptions::default();
+
+        let result = format_json(input, &options);
+
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_minify_json_basic() {
+        let input = r#"{
+  "name": "test",
+  "value": 42
+}"#;
+
+        let result = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(formatted, r#"{"name":"test","value":42}"#);
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_minify_json_nested() {
+        let input = r#"{
+  "user": {
+    "name": "Alice",
+    "age": 30,
+    "hobbies": [
+      "reading",
+      "coding"
+    ]
+  }
+}"#;
+
+        let result = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert!(!formatted.contains('\n'));
+            assert!(!formatted.contains("  "));
+            assert!(formatted.contains("\"user\""));
+            assert!(formatted.contains("\"hobbies\""));
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_minify_json_invalid() {
+        let input = r#"{"name": invalid}"#;
+
+        let result = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_minify_json_empty() {
+        let input = "";
+
+        let result = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_format_json_too_large() {
+        let input = "a".repeat(6 * 1024 * 1024); // 6 MB
+        let options = FormattingOptions::default();
+
+        let result = format_json(&input, &options);
+
+        if let FormattingResult::Error { message } = result {
+            assert!(message.contains("超过最大限制"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_minify_json_too_large() {
+        let input = "a".repeat(6 * 1024 * 1024); // 6 MB
+
+        let result = minify_json(
+            &input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        if let FormattingResult::Error { message } = result {
+            assert!(message.contains("超过最大限制"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_format_preserves_json_semantics() {
+        let input = r#"{"a":1,"b":null,"c":true,"d":"string","e":[],"f":{}}"#;
+        let options = FormattingOptions::default();
+
+        let result = format_json(input, &options);
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            // 重新解析格式化后的 JSON，确保语义一致
+            let original: serde_json::Value = serde_json::from_str(input).unwrap();
+            let reformatted: serde_json::Value = serde_json::from_str(&formatted).unwrap();
+            assert_eq!(original, reformatted);
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_minify_preserves_json_semantics() {
+        let input = r#"{
+  "a": 1,
+  "b": null,
+  "c": true,
+  "d": "string",
+  "e": [],
+  "f": {}
+}"#;
+
+        let result = minify_json(
+            input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            // 重新解析压缩后的 JSON，确保语义一致
+            let original: serde_json::Value = serde_json::from_str(input).unwrap();
+            let minified: serde_json::Value = serde_json::from_str(&formatted).unwrap();
+            assert_eq!(original, minified);
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_cancelled() {
+        use crate::services::operation::CancellationToken;
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = OperationContext::new(token, None);
+
+        let result = format_json_with_context(r#"{"a":1}"#, &FormattingOptions::default(), &context);
+
+        assert!(matches!(result, FormattingResult::Cancelled));
+    }
+
+    #[test]
+    fn test_minify_json_reports_progress() {
+        use crate::services::operation::CancellationToken;
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let context = OperationContext::new(
+            CancellationToken::new(),
+            Some(Arc::new(move |p| sink.lock().unwrap().push(p))),
+        );
+
+        let input = format!("[{}1]", "1,   ".repeat(100_000));
+        let result = minify_json_with_context(
+            &input,
+            &EscapeOptions::default(),
+            DuplicateKeyPolicy::default(),
+            &context,
+        );
+
+        assert!(matches!(result, FormattingResult::Success { .. }));
+        assert_eq!(seen.lock().unwrap().last(), Some(&100));
+    }
+}
@@ -0,0 +1,532 @@
+use crate::models::validation::{
+    DuplicateKeyPolicy, StrictMode, ValidationOptions, ValidationResult,
+};
+use crate::services::conformance::check_conformance;
+use crate::services::duplicate_keys::{apply_policy, duplicate_message};
+use crate::services::linter::lint_value;
+use crate::services::operation::{OperationContext, ProgressReader};
+use std::time::Instant;
+
+/// JSON 最大允许大小：5 MB
+const MAX_JSON_SIZE: usize = 5 * 1024 * 1024;
+
+/// 验证 JSON 字符串
+///
+/// # Arguments
+/// * `input` - 待验证的 JSON 字符串
+///
+/// # Returns
+/// 验证结果，包含成功的数据或错误信息
+pub fn validate_json(input: &str) -> ValidationResult {
+    validate_json_with_context(
+        input,
+        &ValidationOptions::default(),
+        &OperationContext::detached(),
+    )
+}
+
+/// 验证 JSON 字符串 (可取消，并上报进度)
+///
+/// # Arguments
+/// * `input` - 待验证的 JSON 字符串
+/// * `options` - 验证选项（重复键策略、风格检查、严格模式）
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// 验证结果，取消时返回 `ValidationResult::Cancelled`
+pub fn validate_json_with_context(
+    input: &str,
+    options: &ValidationOptions,
+    context: &OperationContext,
+) -> ValidationResult {
+    let start = Instant::now();
+    // 检查输入大小
+    if input.len() > MAX_JSON_SIZE {
+        return ValidationResult::Error {
+            message: format!(
+                "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+                input.len() as f64 / (1024.0 * 1024.0)
+            ),
+            line: None,
+            column: None,
+        };
+    }
+
+    // 检查空输入
+    if input.trim().is_empty() {
+        return ValidationResult::Error {
+            message: "输入为空，请提供有效的 JSON".to_string(),
+            line: None,
+            column: None,
+        };
+    }
+
+    // 严格模式：先检查 serde_json 容忍或无法准确报告的问题
+    if let Some(violation) = check_conformance(input, options.strict) {
+        return ValidationResult::Error {
+            message: violation.message,
+            line: Some(violation.line),
+            column: Some(violation.column),
+        };
+    }
+
+    // I-JSON 不允许重复键
+    let duplicate_keys = match options.strict {
+        StrictMode::IJson => DuplicateKeyPolicy::Error,
+        _ => options.duplicate_keys,
+    };
+
+    // 尝试解析 JSON（通过可中断的读取器，按已读取字节上报进度）
+    let reader = ProgressReader::new(input.as_bytes(), context, 0, 100);
+    match serde_json::from_reader::<_, serde_json::Value>(reader) {
+        Ok(value) => {
+            // serde_json 静默保留最后一个值，按策略检查重复键
+            let (value, duplicate_keys) = match apply_policy(input, value, duplicate_keys) {
+                Ok(resolved) => resolved,
+                Err(duplicate) => {
+                    return ValidationResult::Error {
+                        message: duplicate_message(&duplicate),
+                        line: Some(duplicate.line),
+                        column: Some(duplicate.column),
+                    };
+                }
+            };
+            let warnings = options
+                .lint
+                .as_ref()
+                .map(|config| lint_value(&value, config))
+                .unwrap_or_default();
+            context.report(100);
+            let duration = start.elapsed();
+            ValidationResult::Success {
+                data: value,
+                size: input.len(),
+                processing_time_ms: duration.as_millis() as u64,
+                duplicate_keys,
+                warnings,
+            }
+        },
+        Err(error) if error.is_io() && context.is_cancelled() => ValidationResult::Cancelled,
+        Err(error) => {
+            // 提取错误位置
+            let line = Some(error.line());
+            let column = Some(error.column());
+
+            // 格式化错误消息
+            let message = format_error_message(&error);
+
+            ValidationResult::Error {
+                message,
+                line,
+                column,
+            }
+        }
+    }
+}
+
+/// 将 serde_json 错误转换为用户友好的消息
+pub fn format_error_message(error: &serde_json::Error) -> String {
+    let raw = error.to_string();
+
+    // 转换为中文用户友好的消息
+    if raw.contains("trailing comma") {
+        format!("JSON 中存在多余的逗号（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("expected `,` or `}`") || raw.contains("expected comma") {
+        format!("缺少逗号分隔符（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("expected value") {
+        format!("缺少值或引号不完整（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("EOF while parsing") {
+        format!("JSON 结构不完整，可能缺少括号（第 {} 行）", error.line())
+    } else if raw.contains("key must be a string") {
+        format!("对象的键必须是字符串（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("invalid escape") {
+        format!("包含非法的转义序列（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("trailing characters") {
+        format!("JSON 之后存在多余内容，可能是多个连续的 JSON 文档（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else if raw.contains("control character") {
+        format!("包含非法的控制字符（第 {} 行，第 {} 列）", error.line(), error.column())
+    } else {
+        // 保留原始错误消息，添加位置信息
+        format!("JSON 解析错误：{}（第 {} 行，第 {} 列）", raw, error.line(), error.column())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::lint::{KeyNamingConvention, LintConfig};
+
+    #[test]
+    fn test_valid_json_object() {
+        let input = r#"{"name": "test", "value": 42}"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_valid_json_array() {
+        let input = r#"[1, 2, 3, "test"]"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_valid_json_nested() {
+        let input = r#"{"user": {"name": "Alice", "age": 30, "hobbies": ["reading", "coding"]}}"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_empty_object() {
+        let input = r#"{}"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let input = r#"[]"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_invalid_json_missing_quote() {
+        let input = r#"{"name: "test"}"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_invalid_json_trailing_comma() {
+        let input = r#"{"name": "test",}"#;
+        let result = validate_json(input);
+        if let ValidationResult::Error { line, column, .. } = result {
+            assert_eq!(line, Some(1));
+            assert!(column.is_some());
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_invalid_json_missing_comma() {
+        let input = r#"{"name": "test" "value": 42}"#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_invalid_json_unmatched_bracket() {
+        let input = r#"{"name": "test""#;
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let input = "";
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_plain_text() {
+        let input = "hello world";
+        let result = validate_json(input);
+        assert!(matches!(result, ValidationResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_json_too_large() {
+        let input = "a".repeat(6 * 1024 * 1024); // 6 MB
+        let result = validate_json(&input);
+        if let ValidationResult::Error { message, .. } = result {
+            assert!(message.contains("超过最大限制"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_error_location_multiline() {
+        let input = "{\n  \"name\": \"test\",\n  \"value\": invalid\n}";
+        let result = validate_json(input);
+        if let ValidationResult::Error { line, column, .. } = result {
+            assert_eq!(line, Some(3));
+            assert!(column.is_some());
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_trailing_document_hint() {
+        let input = r#"{"a": 1}{"b": 2}"#;
+        let result = validate_json(input);
+        if let ValidationResult::Error { message, .. } = result {
+            assert!(message.contains("多个连续的 JSON 文档"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_success_returns_size() {
+        let input = r#"{"test": 123}"#;
+        let result = validate_json(input);
+        if let ValidationResult::Success { size, .. } = result {
+            assert_eq!(size, input.len());
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_cancelled_validation() {
+        use crate::services::operation::CancellationToken;
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = OperationContext::new(token, None);
+
+        let result =
+            validate_json_with_context(r#"{"test": 123}"#, &ValidationOptions::default(), &context);
+        assert!(matches!(result, ValidationResult::Cancelled));
+    }
+
+    #[test]
+    fn test_validation_reports_progress() {
+        use crate::services::operation::CancellationToken;
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let context = OperationContext::new(
+            CancellationToken::new(),
+            Some(Arc::new(move |p| sink.lock().unwrap().push(p))),
+        );
+
+        let input = format!("[{}1]", "1, ".repeat(100_000));
+        let result = validate_json_with_context(&input, &ValidationOptions::default(), &context);
+        assert!(matches!(result, ValidationResult::Success { .. }));
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.last(), Some(&100));
+        assert!(seen.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let input = "{\n  \"a\": 1,\n  \"a\": 2\n}";
+        let validate = |duplicate_keys| {
+            let options = ValidationOptions {
+                duplicate_keys,
+                ..Default::default()
+            };
+            validate_json_with_context(input, &options, &OperationContext::detached())
+        };
+
+        match validate(DuplicateKeyPolicy::KeepLast) {
+            ValidationResult::Success {
+                data,
+                duplicate_keys,
+                ..
+            } => {
+                assert_eq!(data["a"], 2);
+                assert_eq!(duplicate_keys.len(), 1);
+                assert_eq!(
+                    (duplicate_keys[0].first_line, duplicate_keys[0].line),
+                    (2, 3)
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+
+        match validate(DuplicateKeyPolicy::KeepFirst) {
+            ValidationResult::Success { data, .. } => assert_eq!(data["a"], 1),
+            other => panic!("{:?}", other),
+        }
+
+        match validate(DuplicateKeyPolicy::Error) {
+            ValidationResult::Error {
+                message,
+                line,
+                column,
+            } => {
+                assert!(message.contains("重复的键 \"a\""));
+                assert_eq!((line, column), (Some(3), Some(3)));
+            }
+            _ => panic!("Expected Error result"),
+        }
+    }
+
+    #[test]
+    fn test_lint_warnings_on_success() {
+        let options = ValidationOptions {
+            lint: Some(LintConfig {
+                key_naming: Some(KeyNamingConvention::CamelCase),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = validate_json_with_context(
+            r#"{"user_id": 1, "userName": "a"}"#,
+            &options,
+            &OperationContext::detached(),
+        );
+
+        match result {
+            ValidationResult::Success { warnings, .. } => {
+                assert_eq!(warnings.len(), 1);
+                assert_eq!(warnings[0].pointer, "/user_id");
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    /// 按严格模式验证，成功返回 `Ok(())`，失败返回错误消息
+    fn validate_strict(input: &str, strict: StrictMode) -> Result<(), String> {
+        let options = ValidationOptions {
+            strict,
+            ..Default::default()
+        };
+        match validate_json_with_context(input, &options, &OperationContext::detached()) {
+            ValidationResult::Success { .. } => Ok(()),
+            ValidationResult::Error { message, .. } => Err(message),
+            ValidationResult::Cancelled => unreachable!(),
+        }
+    }
+
+    /// JSONTestSuite 风格的一致性用例：y_ 必须接受，n_ 必须拒绝，i_ 由实现决定
+    #[test]
+    fn test_conformance_suite() {
+        const MODES: [StrictMode; 3] = [StrictMode::Off, StrictMode::Rfc8259, StrictMode::IJson];
+
+        let accepted = [
+            ("y_object_empty", "{}"),
+            ("y_array_nested", "[[[]], {\"a\": [null]}]"),
+            ("y_number_negative_zero", "[-0]"),
+            ("y_number_real_exponent", "[1.5e+10, -2E-3]"),
+            ("y_number_1e308", "[1e308]"),
+            (
+                "y_number_max_safe_integer",
+                "[9007199254740991, -9007199254740991]",
+            ),
+            ("y_string_surrogate_pair", r#"["\ud83d\ude00"]"#),
+            ("y_string_escapes", r#"["\"\\\/\b\f\n\r\t\u0000"]"#),
+            ("y_string_utf8", "[\"中文 😀\"]"),
+            ("y_structure_whitespace", " \t\r\n[] \n"),
+            ("y_structure_scalar", "\"text\""),
+        ];
+        for (name, input) in accepted {
+            for mode in MODES {
+                assert_eq!(validate_strict(input, mode), Ok(()), "{} {:?}", name, mode);
+            }
+        }
+
+        let rejected = [
+            ("n_array_trailing_comma", "[1,]"),
+            ("n_object_trailing_comma", "{\"a\": 1,}"),
+            ("n_object_single_quote", "{'a': 1}"),
+            ("n_number_leading_zero", "[01]"),
+            ("n_number_plus", "[+1]"),
+            ("n_number_nan", "[NaN]"),
+            ("n_number_trailing_dot", "[1.]"),
+            ("n_string_escape_x", r#"["\x41"]"#),
+            ("n_string_ctrl_char", "[\"a\tb\"]"),
+            ("n_structure_trailing", "1 2"),
+            ("n_structure_comment", "[1] // 注释"),
+        ];
+        for (name, input) in rejected {
+            for mode in MODES {
+                assert!(validate_strict(input, mode).is_err(), "{} {:?}", name, mode);
+            }
+        }
+
+        // 实现自定义的用例：宽松模式与严格模式的结果不同
+        let implementation_defined = [
+            ("i_number_underflow", "[1e-400]", [true, false, false]),
+            ("i_number_huge_exponent", "[1e400]", [false, false, false]),
+            (
+                "i_number_huge_int",
+                "[123456789012345678901234567890]",
+                [true, true, false],
+            ),
+            (
+                "i_number_unsafe_int",
+                "[9007199254740993]",
+                [true, true, false],
+            ),
+            (
+                "i_string_noncharacter",
+                r#"["\uffff"]"#,
+                [true, true, false],
+            ),
+            (
+                "i_string_raw_noncharacter",
+                "[\"\u{fdd0}\"]",
+                [true, true, false],
+            ),
+            (
+                "i_object_duplicate_key",
+                "{\"a\": 1, \"a\": 2}",
+                [true, true, false],
+            ),
+            (
+                "i_string_lone_surrogate",
+                r#"["\ud800"]"#,
+                [false, false, false],
+            ),
+            (
+                "i_string_lone_low_surrogate",
+                r#"["\udc00x"]"#,
+                [false, false, false],
+            ),
+            ("i_structure_bom", "\u{feff}{}", [false, false, false]),
+        ];
+        for (name, input, expected) in implementation_defined {
+            for (mode, accepted) in MODES.into_iter().zip(expected) {
+                let result = validate_strict(input, mode);
+                assert_eq!(
+                    result.is_ok(),
+                    accepted,
+                    "{} {:?}: {:?}",
+                    name,
+                    mode,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_strict_messages_and_location() {
+        let message = validate_strict("\u{feff}{}", StrictMode::Rfc8259).unwrap_err();
+        assert!(message.contains("BOM"));
+
+        let message = validate_strict(r#"["\ud800"]"#, StrictMode::Rfc8259).unwrap_err();
+        assert!(message.contains("代理项 \\ud800"));
+
+        let message = validate_strict("[1e-400]", StrictMode::Rfc8259).unwrap_err();
+        assert!(message.contains("绝对值过小"));
+
+        let input = "{\n  \"名称\": \"值\",\n  \"id\": 9007199254740993\n}";
+        let options = ValidationOptions {
+            strict: StrictMode::IJson,
+            ..Default::default()
+        };
+        match validate_json_with_context(input, &options, &OperationContext::detached()) {
+            ValidationResult::Error {
+                message,
+                line,
+                column,
+            } => {
+                assert!(message.contains("I-JSON"));
+                assert_eq!((line, column), (Some(3), Some(9)));
+            }
+            _ => panic!("Expected Error result"),
+        }
+    }
+}
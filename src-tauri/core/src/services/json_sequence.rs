@@ -0,0 +1,482 @@
+//! 多文档 JSON 服务
+//! 支持首尾相接的多个 JSON 值以及 RFC 7464 JSON 文本序列，逐个文档验证与格式化
+
+use crate::models::formatting::{FormattingOptions, FormattingResult};
+use crate::models::sequence::{SequenceDocument, SequenceFormat, SequenceValidationResult};
+use crate::models::validation::ValidationResult;
+use crate::services::json_formatter::pretty_print;
+use crate::services::json_parser::format_error_message;
+use crate::services::operation::{OperationContext, OperationError};
+use serde_json::Value;
+use std::time::Instant;
+
+/// JSON 最大允许大小：5 MB
+const MAX_JSON_SIZE: usize = 5 * 1024 * 1024;
+
+/// RFC 7464 记录分隔符
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// 根据内容判断多文档格式：包含记录分隔符时视为 RFC 7464 序列
+pub fn detect_sequence_format(input: &str) -> SequenceFormat {
+    if input.contains(RECORD_SEPARATOR) {
+        SequenceFormat::JsonSeq
+    } else {
+        SequenceFormat::Concatenated
+    }
+}
+
+/// 验证多文档 JSON
+///
+/// # Arguments
+/// * `input` - 待验证的文本
+/// * `format` - 输入格式，`None` 时自动检测
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// * `Ok(SequenceValidationResult)` - 各文档的验证结果
+/// * `Err(OperationError)` - 输入为空、过大或操作被取消
+///
+/// 首尾相接格式在遇到第一个无效文档后无法继续定位后续文档，因此在该处停止；
+/// RFC 7464 序列的每条记录相互独立，全部记录都会被验证
+pub fn validate_sequence(
+    input: &str,
+    format: Option<SequenceFormat>,
+    context: &OperationContext,
+) -> Result<SequenceValidationResult, OperationError> {
+    let start = Instant::now();
+    check_input(input)?;
+
+    let format = format.unwrap_or_else(|| detect_sequence_format(input));
+    let documents = match format {
+        SequenceFormat::Concatenated => split_concatenated(input, context)?,
+        SequenceFormat::JsonSeq => split_json_seq(input, context)?,
+    };
+
+    let valid_documents = documents
+        .iter()
+        .filter(|d| matches!(d.result, ValidationResult::Success { .. }))
+        .count();
+
+    Ok(SequenceValidationResult {
+        format,
+        total_documents: documents.len(),
+        valid_documents,
+        documents,
+        size: input.len(),
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// 格式化多文档 JSON，输出保持原有格式
+///
+/// # Arguments
+/// * `input` - 待格式化的文本
+/// * `options` - 格式化选项（缩进、尾部换行）
+/// * `format` - 输入格式，`None` 时自动检测
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// 格式化结果；任一文档无效时返回包含文档序号和位置的错误
+pub fn format_sequence(
+    input: &str,
+    options: &FormattingOptions,
+    format: Option<SequenceFormat>,
+    context: &OperationContext,
+) -> FormattingResult {
+    let start = Instant::now();
+
+    if options.indent != 2 && options.indent != 4 {
+        return FormattingResult::Error {
+            message: format!("不支持的缩进值 {}，仅支持 2 或 4 个空格", options.indent),
+        };
+    }
+
+    let validation = match validate_sequence(input, format, context) {
+        Ok(v) => v,
+        Err(error) => return error.into(),
+    };
+
+    let mut output = String::new();
+
+    for document in &validation.documents {
+        let value = match &document.result {
+            ValidationResult::Success { data, .. } => data,
+            ValidationResult::Error { message, .. } => {
+                return FormattingResult::Error {
+                    message: format!("第 {} 个文档无效: {}", document.index + 1, message),
+                };
+            }
+            ValidationResult::Cancelled => return FormattingResult::Cancelled,
+        };
+
+        let formatted = match pretty_print(value, options.indent) {
+            Ok(s) => s,
+            Err(message) => return FormattingResult::Error { message },
+        };
+
+        if validation.format == SequenceFormat::JsonSeq {
+            output.push(RECORD_SEPARATOR);
+        }
+        output.push_str(&formatted);
+        output.push('\n');
+    }
+
+    // RFC 7464 要求每条记录以换行结束，尾部换行选项仅对首尾相接格式生效
+    if validation.format == SequenceFormat::Concatenated && !options.trailing_newline {
+        output.pop();
+    }
+
+    FormattingResult::Success {
+        size: output.len(),
+        formatted: output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// 依次解析首尾相接的 JSON 值
+fn split_concatenated(
+    input: &str,
+    context: &OperationContext,
+) -> Result<Vec<SequenceDocument>, OperationError> {
+    let mut stream = serde_json::Deserializer::from_str(input).into_iter::<Value>();
+    let mut documents = Vec::new();
+    let mut offset = 0;
+    let mut positions = LineTracker::new(input);
+
+    loop {
+        context.check()?;
+
+        // 跳过文档之间的空白，定位下一个文档的起点
+        let rest = &input[offset..];
+        let doc_start = offset + (rest.len() - rest.trim_start().len());
+        if doc_start >= input.len() {
+            break;
+        }
+
+        let (line, column) = positions.position(doc_start);
+        let parse_start = Instant::now();
+
+        match stream.next() {
+            None => break,
+            Some(Ok(value)) => {
+                offset = stream.byte_offset();
+                documents.push(SequenceDocument {
+                    index: documents.len(),
+                    line,
+                    column,
+                    result: ValidationResult::Success {
+                        data: value,
+                        size: offset - doc_start,
+                        processing_time_ms: parse_start.elapsed().as_millis() as u64,
+                        duplicate_keys: Vec::new(),
+                        warnings: Vec::new(),
+                    },
+                });
+                context.report_range(offset, input.len(), 0, 100);
+            }
+            Some(Err(error)) => {
+                documents.push(SequenceDocument {
+                    index: documents.len(),
+                    line,
+                    column,
+                    result: error_result(&error),
+                });
+                break;
+            }
+        }
+    }
+
+    context.report(100);
+    Ok(documents)
+}
+
+/// 按 RFC 7464 记录分隔符拆分并逐条解析
+fn split_json_seq(
+    input: &str,
+    context: &OperationContext,
+) -> Result<Vec<SequenceDocument>, OperationError> {
+    let mut documents = Vec::new();
+    let mut chunks = input.split(RECORD_SEPARATOR);
+
+    // 第一个分隔符之前只允许出现空白
+    let leading = chunks.next().unwrap_or_default();
+    if !leading.trim().is_empty() {
+        documents.push(SequenceDocument {
+            index: 0,
+            line: 1,
+            column: 1,
+            result: ValidationResult::Error {
+                message: "JSON 文本序列的记录必须以记录分隔符 (0x1E) 开头".to_string(),
+                line: Some(1),
+                column: Some(1),
+            },
+        });
+    }
+
+    let mut offset = leading.len();
+    let mut positions = LineTracker::new(input);
+    for chunk in chunks {
+        context.check()?;
+
+        // 记录内容从分隔符之后开始
+        let record_start = offset + RECORD_SEPARATOR.len_utf8();
+        offset = record_start + chunk.len();
+
+        // 连续的分隔符 (空记录) 按 RFC 7464 忽略
+        if chunk.trim().is_empty() {
+            continue;
+        }
+
+        let (line, column) = positions.position(record_start);
+        let parse_start = Instant::now();
+
+        let result = match serde_json::from_str::<Value>(chunk) {
+            Ok(value) => ValidationResult::Success {
+                data: value,
+                size: chunk.len(),
+                processing_time_ms: parse_start.elapsed().as_millis() as u64,
+                duplicate_keys: Vec::new(),
+                warnings: Vec::new(),
+            },
+            Err(error) => {
+                // 将记录内的位置换算为相对于整个输入的位置
+                let (error_line, error_column) = if error.line() <= 1 {
+                    (line, column - 1 + error.column())
+                } else {
+                    (line - 1 + error.line(), error.column())
+                };
+                ValidationResult::Error {
+                    message: format_error_message(&error),
+                    line: Some(error_line),
+                    column: Some(error_column),
+                }
+            }
+        };
+
+        documents.push(SequenceDocument {
+            index: documents.len(),
+            line,
+            column,
+            result,
+        });
+        context.report_range(offset, input.len(), 0, 100);
+    }
+
+    context.report(100);
+    Ok(documents)
+}
+
+fn error_result(error: &serde_json::Error) -> ValidationResult {
+    ValidationResult::Error {
+        message: format_error_message(error),
+        line: Some(error.line()),
+        column: Some(error.column()),
+    }
+}
+
+/// 按递增的字节偏移计算行列号，每次只扫描上次位置之后的内容
+///
+/// 逐个文档调用 [`line_col`] 需要每次从头扫描，文档很多时耗时与文档数的平方成正比
+struct LineTracker<'a> {
+    input: &'a [u8],
+    offset: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> LineTracker<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            offset: 0,
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    /// 返回偏移对应的行列号，偏移必须不小于上一次调用时的偏移
+    fn position(&mut self, offset: usize) -> (usize, usize) {
+        debug_assert!(offset >= self.offset);
+        let skipped = &self.input[self.offset..offset];
+        if let Some(last) = skipped.iter().rposition(|&b| b == b'\n') {
+            self.line += skipped.iter().filter(|&&b| b == b'\n').count();
+            self.line_start = self.offset + last + 1;
+        }
+        self.offset = offset;
+        (self.line, offset - self.line_start + 1)
+    }
+}
+
+/// 计算字节偏移对应的行列号 (均从 1 开始，列按字节计，与 serde_json 一致)
+pub fn line_col(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input.as_bytes()[..offset];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map(|p| p + 1)
+        .unwrap_or(0);
+    (line, offset - line_start + 1)
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_JSON_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input
+        .trim_matches(|c: char| c.is_whitespace() || c == RECORD_SEPARATOR)
+        .is_empty()
+    {
+        return Err("输入为空，请提供有效的 JSON".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(input: &str) -> SequenceValidationResult {
+        validate_sequence(input, None, &OperationContext::detached()).unwrap()
+    }
+
+    #[test]
+    fn test_concatenated_documents() {
+        let result = validate("{\"a\":1}{\"b\":2}\n[1, 2] 3");
+
+        assert_eq!(result.format, SequenceFormat::Concatenated);
+        assert_eq!(result.total_documents, 4);
+        assert_eq!(result.valid_documents, 4);
+        assert_eq!(result.documents[1].column, 8);
+        assert_eq!(result.documents[2].line, 2);
+        assert_eq!(result.documents[3].column, 8);
+    }
+
+    #[test]
+    fn test_concatenated_stops_at_error() {
+        let result = validate("{\"a\":1}\n{\"b\":}\n{\"c\":3}");
+
+        assert_eq!(result.total_documents, 2);
+        assert_eq!(result.valid_documents, 1);
+        if let ValidationResult::Error { line, .. } = &result.documents[1].result {
+            assert_eq!(*line, Some(2));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_json_seq_records() {
+        let input = "\u{1e}{\"a\":1}\n\u{1e}{\"b\":}\n\u{1e}[1]\n";
+        let result = validate(input);
+
+        assert_eq!(result.format, SequenceFormat::JsonSeq);
+        assert_eq!(result.total_documents, 3);
+        assert_eq!(result.valid_documents, 2);
+
+        let failed = &result.documents[1];
+        assert_eq!(failed.line, 2);
+        if let ValidationResult::Error { line, column, .. } = &failed.result {
+            assert_eq!(*line, Some(2));
+            assert_eq!(*column, Some(7));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_many_small_documents() {
+        const COUNT: usize = 50_000;
+        let input = "[1] \n".repeat(COUNT);
+        let result = validate(&input);
+
+        assert_eq!(result.total_documents, COUNT);
+        assert_eq!(result.valid_documents, COUNT);
+        let last = &result.documents[COUNT - 1];
+        assert_eq!((last.line, last.column), (COUNT, 1));
+
+        let input = "\u{1e}{\"a\": 1}\n".repeat(COUNT) + "\u{1e}{\n  \"b\": }\n";
+        let result = validate(&input);
+
+        assert_eq!(result.total_documents, COUNT + 1);
+        let failed = &result.documents[COUNT];
+        assert_eq!((failed.line, failed.column), (COUNT + 1, 2));
+        if let ValidationResult::Error { line, column, .. } = &failed.result {
+            assert_eq!((*line, *column), (Some(COUNT + 2), Some(8)));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_json_seq_requires_leading_separator() {
+        let result = validate("{\"a\":1}\n\u{1e}{\"b\":2}\n");
+        assert_eq!(result.total_documents, 2);
+        assert_eq!(result.valid_documents, 1);
+    }
+
+    #[test]
+    fn test_empty_sequence() {
+        assert!(validate_sequence("\u{1e}\n", None, &OperationContext::detached()).is_err());
+    }
+
+    #[test]
+    fn test_format_concatenated() {
+        let options = FormattingOptions {
+            indent: 2,
+            trailing_newline: false,
+            escape: Default::default(),
+            duplicate_keys: Default::default(),
+        };
+        let result = format_sequence(
+            "{\"a\":1}{\"b\":2}",
+            &options,
+            None,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(formatted, "{\n  \"a\": 1\n}\n{\n  \"b\": 2\n}");
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_json_seq_keeps_separators() {
+        let options = FormattingOptions::default();
+        let result = format_sequence(
+            "\u{1e}[1]\n\u{1e}2\n",
+            &options,
+            None,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(formatted, "\u{1e}[\n  1\n]\n\u{1e}2\n");
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_reports_invalid_document() {
+        let result = format_sequence(
+            "{\"a\":1}{oops}",
+            &FormattingOptions::default(),
+            None,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Error { message } = result {
+            assert!(message.contains("第 2 个文档"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+}
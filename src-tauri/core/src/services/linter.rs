@@ -0,0 +1,370 @@
+//! JSON 风格检查服务
+//! 按配置的规则检查已解析的值 (键命名、嵌套深度、空容器、混合类型数组等)，结果作为验证成功时的警告返回
+
+use crate::models::lint::{KeyNamingConvention, LintConfig, LintConfigFile, LintRule, LintWarning};
+use crate::services::document_store::child_pointer;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use unicode_normalization::is_nfc;
+
+/// 项目检查配置文件名
+pub const CONFIG_FILE_NAME: &str = ".jsonlint.json";
+
+/// 最多返回的警告数量
+const MAX_WARNINGS: usize = 1000;
+
+/// 按配置检查 JSON 值
+///
+/// # Returns
+/// 警告列表 (按先序遍历排列：对象的键按字典序、数组按下标，不一定与原文顺序一致；最多 1000 条)
+pub fn lint_value(value: &Value, config: &LintConfig) -> Vec<LintWarning> {
+    let mut linter = Linter {
+        config,
+        warnings: Vec::new(),
+    };
+    linter.visit(value, String::new(), 1);
+    linter.warnings
+}
+
+/// 从文件所在目录开始向上查找项目检查配置
+///
+/// # Arguments
+/// * `file_path` - JSON 文件路径或目录
+///
+/// # Returns
+/// 找到的配置；各级目录都没有配置文件时返回 `None`
+pub fn find_config(file_path: &str) -> Result<Option<LintConfigFile>, String> {
+    let path = Path::new(file_path);
+    let start = if path.is_dir() {
+        Some(path)
+    } else {
+        path.parent()
+    };
+
+    for dir in start.into_iter().flat_map(Path::ancestors) {
+        let candidate = dir.join(CONFIG_FILE_NAME);
+        if candidate.is_file() {
+            let config = load_config(&candidate)?;
+            return Ok(Some(LintConfigFile {
+                path: candidate.to_string_lossy().to_string(),
+                config,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// 读取并解析检查配置文件
+pub fn load_config(path: &Path) -> Result<LintConfig, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("读取检查配置 {} 失败: {}", path.display(), e))?;
+    serde_json::from_str(&content).map_err(|e| format!("检查配置 {} 无效: {}", path.display(), e))
+}
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    warnings: Vec<LintWarning>,
+}
+
+impl Linter<'_> {
+    fn warn(&mut self, rule: LintRule, pointer: &str, message: String) {
+        if self.warnings.len() < MAX_WARNINGS {
+            self.warnings.push(LintWarning {
+                rule,
+                pointer: pointer.to_string(),
+                message,
+            });
+        }
+    }
+
+    /// `depth` 为该节点若是容器时所在的层级
+    fn visit(&mut self, value: &Value, pointer: String, depth: usize) {
+        if self.warnings.len() >= MAX_WARNINGS {
+            return;
+        }
+
+        match value {
+            Value::Object(map) => {
+                if self.check_container(&pointer, depth, map.is_empty(), "对象") {
+                    return;
+                }
+                for (key, child) in map {
+                    let path = child_pointer(&pointer, key);
+                    self.check_key(key, &path);
+                    self.visit(child, path, depth + 1);
+                }
+            }
+            Value::Array(items) => {
+                if self.check_container(&pointer, depth, items.is_empty(), "数组") {
+                    return;
+                }
+                if self.config.mixed_type_arrays {
+                    self.check_mixed_types(items, &pointer);
+                }
+                for (index, child) in items.iter().enumerate() {
+                    self.visit(
+                        child,
+                        child_pointer(&pointer, &index.to_string()),
+                        depth + 1,
+                    );
+                }
+            }
+            Value::String(text) => self.check_string(text, &pointer),
+            _ => {}
+        }
+    }
+
+    /// 检查容器的深度和是否为空；超过最大深度时返回 `true`，不再检查其子节点
+    fn check_container(&mut self, pointer: &str, depth: usize, empty: bool, kind: &str) -> bool {
+        if let Some(max_depth) = self.config.max_depth {
+            if depth > max_depth {
+                self.warn(
+                    LintRule::MaxDepth,
+                    pointer,
+                    format!("嵌套深度 {} 超过限制 {}", depth, max_depth),
+                );
+                return true;
+            }
+        }
+        if empty && self.config.empty_containers {
+            self.warn(LintRule::EmptyContainer, pointer, format!("空{}", kind));
+        }
+        false
+    }
+
+    fn check_key(&mut self, key: &str, pointer: &str) {
+        if let Some(convention) = self.config.key_naming {
+            if !key.starts_with(['$', '@']) && !matches_convention(key, convention) {
+                let name = match convention {
+                    KeyNamingConvention::CamelCase => "camelCase",
+                    KeyNamingConvention::SnakeCase => "snake_case",
+                };
+                self.warn(
+                    LintRule::KeyNaming,
+                    pointer,
+                    format!("键 \"{}\" 不符合 {} 命名风格", key, name),
+                );
+            }
+        }
+        if self.config.unicode_nfc && !is_nfc(key) {
+            self.warn(
+                LintRule::UnicodeNfc,
+                pointer,
+                format!("键 \"{}\" 不是 Unicode NFC 规范化形式", key),
+            );
+        }
+    }
+
+    fn check_string(&mut self, text: &str, pointer: &str) {
+        if self.config.numbers_as_strings && is_number_literal(text) {
+            self.warn(
+                LintRule::NumberAsString,
+                pointer,
+                format!("数字 {} 以字符串形式存储", text),
+            );
+        }
+        if let Some(max_length) = self.config.max_string_length {
+            let length = text.chars().count();
+            if length > max_length {
+                self.warn(
+                    LintRule::LongString,
+                    pointer,
+                    format!("字符串长度 {} 超过限制 {}", length, max_length),
+                );
+            }
+        }
+        if self.config.unicode_nfc && !is_nfc(text) {
+            self.warn(
+                LintRule::UnicodeNfc,
+                pointer,
+                "字符串不是 Unicode NFC 规范化形式".to_string(),
+            );
+        }
+    }
+
+    fn check_mixed_types(&mut self, items: &[Value], pointer: &str) {
+        let mut types: Vec<&str> = Vec::new();
+        for item in items {
+            let name = type_name(item);
+            if name != "null" && !types.contains(&name) {
+                types.push(name);
+            }
+        }
+        if types.len() > 1 {
+            self.warn(
+                LintRule::MixedTypeArray,
+                pointer,
+                format!("数组元素类型不一致: {}", types.join(", ")),
+            );
+        }
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+fn matches_convention(key: &str, convention: KeyNamingConvention) -> bool {
+    let mut chars = key.chars();
+    let starts_lower = chars.next().is_some_and(|c| c.is_ascii_lowercase());
+    match convention {
+        KeyNamingConvention::CamelCase => starts_lower && chars.all(|c| c.is_ascii_alphanumeric()),
+        KeyNamingConvention::SnakeCase => {
+            starts_lower
+                && key.split('_').all(|part| {
+                    !part.is_empty()
+                        && part
+                            .chars()
+                            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                })
+        }
+    }
+}
+
+/// 字符串内容是否恰好是一个 JSON 数字 (不允许前导零和首尾空白，因此 `"007"` 不算)
+fn is_number_literal(text: &str) -> bool {
+    text.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+        && text.ends_with(|c: char| c.is_ascii_digit())
+        && serde_json::from_str::<serde_json::Number>(text).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rules(value: &Value, config: &LintConfig) -> Vec<(LintRule, String)> {
+        lint_value(value, config)
+            .into_iter()
+            .map(|warning| (warning.rule, warning.pointer))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_config_reports_nothing() {
+        let value = json!({"Bad Key": [], "n": "42", "mixed": [1, "a"]});
+        assert!(lint_value(&value, &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_key_naming() {
+        let value = json!({
+            "userId": 1,
+            "user_name": 2,
+            "$schema": 3,
+            "items": [{"HTTPCode": 4, "v2": 5}]
+        });
+
+        let camel = LintConfig {
+            key_naming: Some(KeyNamingConvention::CamelCase),
+            ..Default::default()
+        };
+        assert_eq!(
+            rules(&value, &camel),
+            vec![
+                (LintRule::KeyNaming, "/items/0/HTTPCode".to_string()),
+                (LintRule::KeyNaming, "/user_name".to_string()),
+            ]
+        );
+
+        let snake = LintConfig {
+            key_naming: Some(KeyNamingConvention::SnakeCase),
+            ..Default::default()
+        };
+        assert_eq!(
+            rules(&value, &snake),
+            vec![
+                (LintRule::KeyNaming, "/items/0/HTTPCode".to_string()),
+                (LintRule::KeyNaming, "/userId".to_string()),
+            ]
+        );
+        assert!(!matches_convention("a__b", KeyNamingConvention::SnakeCase));
+        assert!(!matches_convention("", KeyNamingConvention::CamelCase));
+    }
+
+    #[test]
+    fn test_structure_rules() {
+        let value = json!({
+            "a": {"b": {"c": {"d": 1}}},
+            "empty": {},
+            "list": [],
+            "mixed": [1, "two", null, 3.5],
+            "nullable": [1, null, 2]
+        });
+        let config = LintConfig {
+            max_depth: Some(3),
+            empty_containers: true,
+            mixed_type_arrays: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            rules(&value, &config),
+            vec![
+                (LintRule::MaxDepth, "/a/b/c".to_string()),
+                (LintRule::EmptyContainer, "/empty".to_string()),
+                (LintRule::EmptyContainer, "/list".to_string()),
+                (LintRule::MixedTypeArray, "/mixed".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_string_rules() {
+        // "e\u{301}" 是分解形式 (NFD)
+        let value = json!({
+            "ids": ["42", "-1.5e3", "007", " 1", "1a", "abc"],
+            "long": "x".repeat(11),
+            "name": "e\u{301}",
+            "caf\u{e9}": true
+        });
+        let config = LintConfig {
+            numbers_as_strings: true,
+            max_string_length: Some(10),
+            unicode_nfc: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            rules(&value, &config),
+            vec![
+                (LintRule::NumberAsString, "/ids/0".to_string()),
+                (LintRule::NumberAsString, "/ids/1".to_string()),
+                (LintRule::LongString, "/long".to_string()),
+                (LintRule::UnicodeNfc, "/name".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_config() {
+        let root = std::env::temp_dir().join(format!("jsonlint-test-{}", std::process::id()));
+        let nested = root.join("api").join("v1");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(
+            root.join(CONFIG_FILE_NAME),
+            r#"{"key_naming": "snake_case"}"#,
+        )
+        .unwrap();
+
+        let found = find_config(&nested.join("data.json").to_string_lossy())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            found.config.key_naming,
+            Some(KeyNamingConvention::SnakeCase)
+        );
+        assert!(found.path.ends_with(CONFIG_FILE_NAME));
+
+        fs::write(root.join(CONFIG_FILE_NAME), r#"{"unknown_rule": true}"#).unwrap();
+        assert!(find_config(&nested.to_string_lossy()).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -131,6 +131,7 @@ impl Logger {
                 total_operations: 0,
                 success_count: 0,
                 error_count: 0,
+                cancelled_count: 0,
                 success_rate: 0.0,
                 validate_count: 0,
                 format_count: 0,
@@ -150,6 +151,10 @@ impl Logger {
             .iter()
             .filter(|e| e.result == OperationResult::Error)
             .count();
+        let cancelled = entries
+            .iter()
+            .filter(|e| e.result == OperationResult::Cancelled)
+            .count();
         let validate = entries
             .iter()
             .filter(|e| e.operation == OperationType::Validate)
@@ -176,8 +181,9 @@ impl Logger {
             total_operations: total,
             success_count: success,
             error_count: error,
-            success_rate: if total > 0 {
-                (success as f64 / total as f64) * 100.0
+            cancelled_count: cancelled,
+            success_rate: if success + error > 0 {
+                (success as f64 / (success + error) as f64) * 100.0
             } else {
                 0.0
             },
@@ -299,13 +305,17 @@ mod tests {
         logger
             .log_operation(OperationType::Format, OperationResult::Success, 512, 3, None)
             .unwrap();
+        logger
+            .log_operation(OperationType::Format, OperationResult::Cancelled, 4096, 7, None)
+            .unwrap();
 
         let stats = logger.get_statistics().unwrap();
-        assert_eq!(stats.total_operations, 3);
+        assert_eq!(stats.total_operations, 4);
         assert_eq!(stats.success_count, 2);
         assert_eq!(stats.error_count, 1);
+        assert_eq!(stats.cancelled_count, 1);
         assert_eq!(stats.validate_count, 2);
-        assert_eq!(stats.format_count, 1);
+        assert_eq!(stats.format_count, 2);
         assert!((stats.success_rate - 66.666).abs() < 0.1);
     }
 
@@ -0,0 +1,27 @@
+//! 核心服务，每个模块对应一类功能 (模块文档说明其行为)
+
+pub mod json_parser;
+pub mod json_formatter;
+pub mod file_io;
+pub mod logger;
+pub mod operation;
+pub mod document_store;
+pub mod ndjson;
+pub mod json_sequence;
+pub mod yaml_converter;
+pub mod toml_converter;
+pub mod csv_converter;
+pub mod xml_converter;
+pub mod bson;
+pub mod binary_codec;
+pub mod protobuf_codec;
+pub mod flatten;
+pub mod embedded_json;
+pub mod transform;
+pub mod json_analyzer;
+pub mod duplicate_keys;
+pub mod linter;
+pub mod conformance;
+pub mod encoding;
+pub mod file_watcher;
+pub mod batch;
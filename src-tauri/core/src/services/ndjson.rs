@@ -0,0 +1,786 @@
+//! JSON Lines / NDJSON 服务
+//! 逐行验证、格式化、压缩，并在 NDJSON 与 JSON 数组之间互相转换；文件模式按行流式处理
+
+use crate::models::formatting::{FormattingOptions, FormattingResult};
+use crate::models::ndjson::{LineError, NdjsonOutputMode, NdjsonValidationResult};
+use crate::services::duplicate_keys::{apply_policy, duplicate_message};
+use crate::services::file_io;
+use crate::services::json_formatter::pretty_print_escaped;
+use crate::services::operation::{OperationContext, OperationError, CANCELLED_MESSAGE};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde_json::Value;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read, Write};
+use std::path::Path;
+use std::time::Instant;
+
+/// 文本输入最大允许大小：5 MB (文件模式流式处理，不受此限制)
+const MAX_NDJSON_SIZE: usize = 5 * 1024 * 1024;
+
+/// 最多保留的行错误数
+const MAX_LINE_ERRORS: usize = 1000;
+
+/// 验证 NDJSON 文本，每行独立解析
+///
+/// # Arguments
+/// * `input` - 待验证的 NDJSON 文本
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// * `Ok(NdjsonValidationResult)` - 验证完成，包含逐行错误
+/// * `Err(OperationError)` - 输入为空、过大或操作被取消
+pub fn validate_ndjson(
+    input: &str,
+    context: &OperationContext,
+) -> Result<NdjsonValidationResult, OperationError> {
+    check_text_input(input)?;
+    validate_ndjson_reader(Cursor::new(input.as_bytes()), input.len(), context)
+}
+
+/// 流式验证 NDJSON 文件
+///
+/// # Arguments
+/// * `file_path` - 文件的完整路径
+/// * `context` - 操作上下文（取消令牌与进度回调）
+pub fn validate_ndjson_file(
+    file_path: &str,
+    context: &OperationContext,
+) -> Result<NdjsonValidationResult, OperationError> {
+    let (reader, size) = open_input(file_path)?;
+    validate_ndjson_reader(reader, size, context)
+}
+
+/// 格式化 NDJSON 文本
+///
+/// # Arguments
+/// * `input` - NDJSON 文本
+/// * `options` - 格式化选项（缩进、尾部换行、转义、重复键策略），逐条应用
+/// * `mode` - 输出模式：逐条美化、逐行压缩或转换为 JSON 数组
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// 格式化结果；任一行解析失败时返回带行号的错误
+pub fn format_ndjson(
+    input: &str,
+    options: &FormattingOptions,
+    mode: NdjsonOutputMode,
+    context: &OperationContext,
+) -> FormattingResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_text_input(input).and_then(|_| check_indent(options)) {
+        return FormattingResult::Error { message };
+    }
+
+    let mut buf = Vec::new();
+    if let Err(error) = write_records(
+        Cursor::new(input.as_bytes()),
+        &mut buf,
+        input.len(),
+        mode,
+        options,
+        context,
+    ) {
+        return error.into();
+    }
+
+    let mut output = match String::from_utf8(buf) {
+        Ok(s) => s,
+        Err(e) => {
+            return FormattingResult::Error {
+                message: format!("UTF-8 转换失败: {}", e),
+            };
+        }
+    };
+
+    // 根据配置添加或不添加尾部换行符
+    while output.ends_with('\n') {
+        output.pop();
+    }
+    if options.trailing_newline {
+        output.push('\n');
+    }
+
+    FormattingResult::Success {
+        size: output.len(),
+        formatted: output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// 将 JSON 数组转换为 NDJSON (每个元素一行)
+///
+/// # Arguments
+/// * `input` - JSON 数组文本
+///
+/// # Returns
+/// 格式化结果；输入不是数组时返回错误
+pub fn json_array_to_ndjson(input: &str) -> FormattingResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_text_input(input) {
+        return FormattingResult::Error { message };
+    }
+
+    let items = match serde_json::from_str::<Value>(input) {
+        Ok(Value::Array(items)) => items,
+        Ok(_) => {
+            return FormattingResult::Error {
+                message: "输入必须是 JSON 数组".to_string(),
+            };
+        }
+        Err(e) => {
+            return FormattingResult::Error {
+                message: format!(
+                    "JSON 解析失败: {}（第 {} 行，第 {} 列）",
+                    e,
+                    e.line(),
+                    e.column()
+                ),
+            };
+        }
+    };
+
+    let mut output = String::new();
+    for item in &items {
+        output.push_str(&item.to_string());
+        output.push('\n');
+    }
+
+    FormattingResult::Success {
+        size: output.len(),
+        formatted: output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// 流式转换 NDJSON 文件并写入目标文件
+///
+/// # Arguments
+/// * `input_path` - 源 NDJSON 文件
+/// * `output_path` - 目标文件 (可以与源文件相同，此时就地转换)
+/// * `mode` - 输出模式
+/// * `options` - 格式化选项
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// * `Ok(usize)` - 成功写入的记录数
+/// * `Err(OperationError)` - 读取、解析、写入失败或操作被取消
+pub fn convert_ndjson_file(
+    input_path: &str,
+    output_path: &str,
+    mode: NdjsonOutputMode,
+    options: &FormattingOptions,
+    context: &OperationContext,
+) -> Result<usize, OperationError> {
+    check_indent(options)?;
+
+    // 先写入临时文件再替换目标文件，目标与源文件相同 (包括经链接或不同写法指向同一文件) 时也不会损坏输入
+    let (reader, size) = open_input(input_path)?;
+    file_io::write_atomic_stream(Path::new(output_path), |writer| {
+        let count = write_records(reader, writer, size, mode, options, context)?;
+        if mode == NdjsonOutputMode::JsonArray && options.trailing_newline {
+            writer.write_all(b"\n").map_err(write_error)?;
+        }
+        Ok(count)
+    })
+}
+
+/// 流式将 JSON 数组文件转换为 NDJSON 文件 (每个元素一行)
+///
+/// 逐个元素解析并写出，内存占用只取决于单个元素的大小
+///
+/// # Arguments
+/// * `input_path` - 源 JSON 数组文件
+/// * `output_path` - 目标文件 (可以与源文件相同，此时就地转换)
+/// * `context` - 操作上下文（取消令牌与进度回调）
+///
+/// # Returns
+/// * `Ok(usize)` - 成功写入的记录数
+/// * `Err(OperationError)` - 读取、解析、写入失败，输入不是数组或操作被取消
+pub fn convert_array_file_to_ndjson(
+    input_path: &str,
+    output_path: &str,
+    context: &OperationContext,
+) -> Result<usize, OperationError> {
+    let (mut reader, size) = open_input(input_path)?;
+    // 跳过 UTF-8 BOM
+    let has_bom = reader
+        .fill_buf()
+        .map_err(|e| format!("读取文件失败: {}", e))?
+        .starts_with(b"\xEF\xBB\xBF");
+    if has_bom {
+        reader.consume(3);
+    }
+    let reader = ProgressFileReader {
+        inner: reader,
+        consumed: 0,
+        total: size,
+        context,
+    };
+
+    file_io::write_atomic_stream(Path::new(output_path), |writer| {
+        let mut write_failure = None;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let result = deserializer
+            .deserialize_seq(ArrayToNdjson {
+                writer,
+                write_failure: &mut write_failure,
+            })
+            .and_then(|count| deserializer.end().map(|_| count));
+        match (result, write_failure) {
+            (Ok(count), _) => {
+                context.report(100);
+                Ok(count)
+            }
+            (Err(_), _) if context.is_cancelled() => Err(OperationError::Cancelled),
+            (Err(_), Some(e)) => Err(write_error(e).into()),
+            (Err(e), None) if e.is_io() => Err(format!("读取文件失败: {}", e).into()),
+            (Err(e), None) if e.is_data() => Err("输入必须是 JSON 数组".to_string().into()),
+            (Err(e), None) => Err(format!(
+                "JSON 解析失败: {}（第 {} 行，第 {} 列）",
+                e,
+                e.line(),
+                e.column()
+            )
+            .into()),
+        }
+    })
+}
+
+/// 逐个读取数组元素并写为 NDJSON 行；写入失败的原因记录在 `write_failure` 中
+struct ArrayToNdjson<'a, W> {
+    writer: &'a mut W,
+    write_failure: &'a mut Option<io::Error>,
+}
+
+impl<'de, W: Write> Visitor<'de> for ArrayToNdjson<'_, W> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("JSON 数组")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+        let mut count = 0;
+        while let Some(item) = seq.next_element::<Value>()? {
+            let written = serde_json::to_writer(&mut *self.writer, &item)
+                .map_err(io::Error::from)
+                .and_then(|_| self.writer.write_all(b"\n"));
+            if let Err(e) = written {
+                let message = e.to_string();
+                *self.write_failure = Some(e);
+                return Err(de::Error::custom(message));
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// 按已读取的字节数上报进度，并在取消时中断读取
+struct ProgressFileReader<'a, R> {
+    inner: R,
+    consumed: usize,
+    total: usize,
+    context: &'a OperationContext,
+}
+
+impl<R: Read> Read for ProgressFileReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.context.is_cancelled() {
+            return Err(io::Error::other(CANCELLED_MESSAGE));
+        }
+        let read = self.inner.read(buf)?;
+        self.consumed += read;
+        self.context.report_range(self.consumed, self.total, 0, 100);
+        Ok(read)
+    }
+}
+
+/// 逐行验证
+fn validate_ndjson_reader<R: BufRead>(
+    reader: R,
+    total_size: usize,
+    context: &OperationContext,
+) -> Result<NdjsonValidationResult, OperationError> {
+    let start = Instant::now();
+    let mut total_records = 0;
+    let mut valid_records = 0;
+    let mut errors = Vec::new();
+    let mut errors_truncated = false;
+
+    for_each_record(reader, total_size, context, |line, _, record| {
+        total_records += 1;
+        match record {
+            Ok(_) => valid_records += 1,
+            Err(e) if errors.len() < MAX_LINE_ERRORS => errors.push(LineError {
+                line,
+                column: Some(e.column()),
+                message: e.to_string(),
+            }),
+            Err(_) => errors_truncated = true,
+        }
+        Ok(())
+    })?;
+
+    Ok(NdjsonValidationResult {
+        total_records,
+        valid_records,
+        errors,
+        errors_truncated,
+        size: total_size,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// 逐条解析记录，按重复键策略处理后按输出模式和转义选项写入
+fn write_records<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+    total_size: usize,
+    mode: NdjsonOutputMode,
+    options: &FormattingOptions,
+    context: &OperationContext,
+) -> Result<usize, OperationError> {
+    let indent_str = " ".repeat(options.indent as usize);
+    let mut count = 0;
+
+    if mode == NdjsonOutputMode::JsonArray {
+        writer.write_all(b"[").map_err(write_error)?;
+    }
+
+    for_each_record(reader, total_size, context, |line, source, record| {
+        let value = record.map_err(|e| format!("第 {} 行 JSON 解析失败: {}", line, e))?;
+        let (value, _) = apply_policy(source, value, options.duplicate_keys)
+            .map_err(|duplicate| format!("第 {} 行: {}", line, duplicate_message(&duplicate)))?;
+
+        let indent = match mode {
+            NdjsonOutputMode::Minify => 0,
+            NdjsonOutputMode::Pretty | NdjsonOutputMode::JsonArray => options.indent,
+        };
+        let text = pretty_print_escaped(&value, indent, &options.escape)?;
+
+        match mode {
+            NdjsonOutputMode::JsonArray => {
+                // 数组元素整体再缩进一级
+                let separator = if count == 0 { "\n" } else { ",\n" };
+                writer
+                    .write_all(separator.as_bytes())
+                    .map_err(write_error)?;
+                let nested = text
+                    .lines()
+                    .map(|l| format!("{}{}", indent_str, l))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                writer.write_all(nested.as_bytes()).map_err(write_error)?;
+            }
+            _ => {
+                writer.write_all(text.as_bytes()).map_err(write_error)?;
+                writer.write_all(b"\n").map_err(write_error)?;
+            }
+        }
+
+        count += 1;
+        Ok(())
+    })?;
+
+    if mode == NdjsonOutputMode::JsonArray {
+        let closing: &[u8] = if count == 0 { b"]" } else { b"\n]" };
+        writer.write_all(closing).map_err(write_error)?;
+    }
+
+    Ok(count)
+}
+
+/// 遍历每条非空记录，回调参数为行号（从 1 开始）、记录原文和解析结果
+fn for_each_record<R, F>(
+    mut reader: R,
+    total_size: usize,
+    context: &OperationContext,
+    mut on_record: F,
+) -> Result<(), OperationError>
+where
+    R: BufRead,
+    F: FnMut(usize, &str, Result<Value, serde_json::Error>) -> Result<(), OperationError>,
+{
+    let mut buf = String::new();
+    let mut line = 0;
+    let mut consumed = 0;
+
+    loop {
+        context.check()?;
+
+        buf.clear();
+        let read = reader
+            .read_line(&mut buf)
+            .map_err(|e| format!("读取第 {} 行失败: {}", line + 1, e))?;
+        if read == 0 {
+            break;
+        }
+
+        line += 1;
+        consumed += read;
+        context.report_range(consumed, total_size, 0, 100);
+
+        let text = buf.trim_end_matches(['\n', '\r']);
+        // 首行可能带有 UTF-8 BOM
+        let text = if line == 1 {
+            text.trim_start_matches('\u{feff}')
+        } else {
+            text
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        on_record(line, text, serde_json::from_str::<Value>(text))?;
+    }
+
+    context.report(100);
+    Ok(())
+}
+
+/// 检查文本输入的大小和是否为空
+fn check_text_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_NDJSON_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的 JSON".to_string());
+    }
+
+    Ok(())
+}
+
+fn check_indent(options: &FormattingOptions) -> Result<(), String> {
+    if options.indent != 2 && options.indent != 4 {
+        return Err(format!(
+            "不支持的缩进值 {}，仅支持 2 或 4 个空格",
+            options.indent
+        ));
+    }
+    Ok(())
+}
+
+fn open_input(file_path: &str) -> Result<(BufReader<File>, usize), String> {
+    let path = Path::new(file_path);
+    if !path.is_file() {
+        return Err(format!("文件不存在: {}", file_path));
+    }
+
+    let file = File::open(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let size = file
+        .metadata()
+        .map_err(|e| format!("无法获取文件元数据: {}", e))?
+        .len() as usize;
+
+    Ok((BufReader::new(file), size))
+}
+
+fn write_error(e: std::io::Error) -> String {
+    format!("写入文件失败: {}", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::validation::DuplicateKeyPolicy;
+    use std::fs;
+
+    fn options(trailing_newline: bool) -> FormattingOptions {
+        FormattingOptions {
+            indent: 2,
+            trailing_newline,
+            escape: Default::default(),
+            duplicate_keys: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_errors_per_line() {
+        let input = "{\"a\":1}\n\n{\"b\":}\n[1,2]\nnot json\n";
+        let result = validate_ndjson(input, &OperationContext::detached()).unwrap();
+
+        assert_eq!(result.total_records, 4);
+        assert_eq!(result.valid_records, 2);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.errors[0].line, 3);
+        assert_eq!(result.errors[1].line, 5);
+        assert!(!result.errors_truncated);
+    }
+
+    #[test]
+    fn test_validate_handles_crlf_and_bom() {
+        let input = "\u{feff}{\"a\":1}\r\n{\"b\":2}\r\n";
+        let result = validate_ndjson(input, &OperationContext::detached()).unwrap();
+
+        assert_eq!(result.total_records, 2);
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_validate_empty_input() {
+        assert!(validate_ndjson("  \n", &OperationContext::detached()).is_err());
+    }
+
+    #[test]
+    fn test_format_pretty_each_record() {
+        let input = "{\"a\":1}\n{\"b\":[1,2]}\n";
+        let result = format_ndjson(
+            input,
+            &options(false),
+            NdjsonOutputMode::Pretty,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(
+                formatted,
+                "{\n  \"a\": 1\n}\n{\n  \"b\": [\n    1,\n    2\n  ]\n}"
+            );
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_minify_normalizes_lines() {
+        let input = "{ \"a\" : 1 }\n\n[ 1, 2 ]";
+        let result = format_ndjson(
+            input,
+            &options(true),
+            NdjsonOutputMode::Minify,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Success { formatted, .. } = result {
+            assert_eq!(formatted, "{\"a\":1}\n[1,2]\n");
+        } else {
+            panic!("Expected Success result");
+        }
+    }
+
+    #[test]
+    fn test_format_reports_failing_line() {
+        let input = "{\"a\":1}\n{oops}\n";
+        let result = format_ndjson(
+            input,
+            &options(false),
+            NdjsonOutputMode::Minify,
+            &OperationContext::detached(),
+        );
+
+        if let FormattingResult::Error { message } = result {
+            assert!(message.contains("第 2 行"));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_format_applies_escape_and_duplicate_key_options() {
+        let input = "{\"a\":\"é\",\"a\":\"</b>\"}\n{\"b\":1}\n";
+        let mut options = options(false);
+        options.escape.ascii_only = true;
+        options.duplicate_keys = DuplicateKeyPolicy::KeepFirst;
+        let format = |options: &FormattingOptions| {
+            format_ndjson(
+                input,
+                options,
+                NdjsonOutputMode::Minify,
+                &OperationContext::detached(),
+            )
+        };
+
+        match format(&options) {
+            FormattingResult::Success { formatted, .. } => {
+                assert_eq!(formatted, "{\"a\":\"\\u00e9\"}\n{\"b\":1}")
+            }
+            other => panic!("{:?}", other),
+        }
+
+        options.duplicate_keys = DuplicateKeyPolicy::Error;
+        match format(&options) {
+            FormattingResult::Error { message } => {
+                assert!(message.starts_with("第 1 行: 对象中存在重复的键 \"a\""))
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ndjson_to_array_round_trip() {
+        let input = "{\"a\":1}\n{\"b\":2}\n";
+        let result = format_ndjson(
+            input,
+            &options(false),
+            NdjsonOutputMode::JsonArray,
+            &OperationContext::detached(),
+        );
+
+        let array = match result {
+            FormattingResult::Success { formatted, .. } => formatted,
+            _ => panic!("Expected Success result"),
+        };
+        let parsed: Value = serde_json::from_str(&array).unwrap();
+        assert_eq!(parsed, serde_json::json!([{"a": 1}, {"b": 2}]));
+
+        match json_array_to_ndjson(&array) {
+            FormattingResult::Success { formatted, .. } => assert_eq!(formatted, input),
+            _ => panic!("Expected Success result"),
+        }
+    }
+
+    #[test]
+    fn test_empty_records_to_array() {
+        let result = format_ndjson(
+            "\n\n ",
+            &options(false),
+            NdjsonOutputMode::JsonArray,
+            &OperationContext::detached(),
+        );
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_array_to_ndjson_rejects_object() {
+        let result = json_array_to_ndjson(r#"{"a":1}"#);
+        assert!(matches!(result, FormattingResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_convert_file_streaming() {
+        let temp_dir = std::env::temp_dir().join("tauri_json_tool_ndjson_tests");
+        fs::create_dir_all(&temp_dir).unwrap();
+        let input_path = temp_dir.join("input.jsonl");
+        let output_path = temp_dir.join("output.json");
+        fs::write(&input_path, "{\"a\":1}\n{\"a\":2}\n{\"a\":3}\n").unwrap();
+
+        let count = convert_ndjson_file(
+            &input_path.to_string_lossy(),
+            &output_path.to_string_lossy(),
+            NdjsonOutputMode::JsonArray,
+            &options(true),
+            &OperationContext::detached(),
+        )
+        .unwrap();
+        assert_eq!(count, 3);
+
+        let written: Value =
+            serde_json::from_str(&fs::read_to_string(&output_path).unwrap()).unwrap();
+        assert_eq!(written.as_array().unwrap().len(), 3);
+
+        let result =
+            validate_ndjson_file(&input_path.to_string_lossy(), &OperationContext::detached())
+                .unwrap();
+        assert_eq!(result.valid_records, 3);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_convert_array_file_to_ndjson() {
+        let temp_dir = std::env::temp_dir().join("tauri_json_tool_ndjson_from_array");
+        fs::create_dir_all(&temp_dir).unwrap();
+        let input_path = temp_dir.join("data.json");
+        let input = input_path.to_string_lossy();
+        fs::write(
+            &input_path,
+            "\u{feff}[\n  {\"a\": 1},\n  [1, 2],\n  \"x\"\n]\n",
+        )
+        .unwrap();
+
+        // 就地转换
+        let count =
+            convert_array_file_to_ndjson(&input, &input, &OperationContext::detached()).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(
+            fs::read_to_string(&input_path).unwrap(),
+            "{\"a\":1}\n[1,2]\n\"x\"\n"
+        );
+
+        // 与 NDJSON → 数组的转换互逆
+        let array_path = temp_dir.join("array.json");
+        let array = array_path.to_string_lossy();
+        convert_ndjson_file(
+            &input,
+            &array,
+            NdjsonOutputMode::JsonArray,
+            &options(true),
+            &OperationContext::detached(),
+        )
+        .unwrap();
+        let output_path = temp_dir.join("round-trip.jsonl");
+        let output = output_path.to_string_lossy();
+        assert_eq!(
+            convert_array_file_to_ndjson(&array, &output, &OperationContext::detached()).unwrap(),
+            3
+        );
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            fs::read_to_string(&input_path).unwrap()
+        );
+
+        for (content, expected) in [
+            ("{\"a\": 1}", "必须是 JSON 数组"),
+            ("[1, 2", "JSON 解析失败"),
+            ("[1] 2", "JSON 解析失败"),
+        ] {
+            fs::write(&input_path, content).unwrap();
+            let error =
+                convert_array_file_to_ndjson(&input, &output, &OperationContext::detached())
+                    .unwrap_err();
+            assert!(error.to_string().contains(expected), "{}", error);
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_convert_file_in_place() {
+        let temp_dir = std::env::temp_dir().join("tauri_json_tool_ndjson_in_place");
+        fs::create_dir_all(&temp_dir).unwrap();
+        let input_path = temp_dir.join("data.jsonl");
+        fs::write(&input_path, "{ \"a\" : 1 }\n{ \"a\" : 2 }\n").unwrap();
+
+        // 同一文件的另一种写法
+        let same_file = temp_dir.join(".").join("data.jsonl");
+        let count = convert_ndjson_file(
+            &input_path.to_string_lossy(),
+            &same_file.to_string_lossy(),
+            NdjsonOutputMode::Minify,
+            &options(true),
+            &OperationContext::detached(),
+        )
+        .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            fs::read_to_string(&input_path).unwrap(),
+            "{\"a\":1}\n{\"a\":2}\n"
+        );
+
+        // 解析失败时保留原文件，且不留下临时文件
+        fs::write(&input_path, "{\"a\":1}\n{oops}\n").unwrap();
+        let result = convert_ndjson_file(
+            &input_path.to_string_lossy(),
+            &input_path.to_string_lossy(),
+            NdjsonOutputMode::Minify,
+            &options(true),
+            &OperationContext::detached(),
+        );
+        assert!(result.unwrap_err().to_string().contains("第 2 行"));
+        assert_eq!(
+            fs::read_to_string(&input_path).unwrap(),
+            "{\"a\":1}\n{oops}\n"
+        );
+        assert_eq!(fs::read_dir(&temp_dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+}
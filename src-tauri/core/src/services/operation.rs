@@ -0,0 +1,355 @@
+//! 可取消的长耗时操作
+//! 提供取消令牌、进度回调以及全局操作注册表
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 操作被取消时返回的错误消息
+pub const CANCELLED_MESSAGE: &str = "操作已取消";
+
+/// 可取消操作的错误
+///
+/// 取消与失败分开表示，调用方无需比较错误消息即可识别取消；
+/// 转换为字符串时，取消对应 [`CANCELLED_MESSAGE`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationError {
+    /// 操作被取消
+    Cancelled,
+    /// 操作失败 (面向用户的错误消息)
+    Failed(String),
+}
+
+impl OperationError {
+    /// 是否因取消而结束
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, OperationError::Cancelled)
+    }
+}
+
+impl fmt::Display for OperationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperationError::Cancelled => f.write_str(CANCELLED_MESSAGE),
+            OperationError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for OperationError {}
+
+impl From<String> for OperationError {
+    fn from(message: String) -> Self {
+        OperationError::Failed(message)
+    }
+}
+
+impl From<OperationError> for String {
+    fn from(error: OperationError) -> Self {
+        error.to_string()
+    }
+}
+
+/// 每处理多少字节检查一次取消状态并上报进度
+const CHECK_INTERVAL: usize = 64 * 1024;
+
+/// 取消令牌 (可跨线程共享)
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 请求取消
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// 检查是否已请求取消
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// 进度回调 (参数为 0-100 的百分比)
+pub type ProgressCallback = Arc<dyn Fn(u8) + Send + Sync>;
+
+/// 操作上下文：携带取消令牌和进度回调
+#[derive(Clone)]
+pub struct OperationContext {
+    token: CancellationToken,
+    on_progress: Option<ProgressCallback>,
+    last_percent: Arc<AtomicU8>,
+}
+
+impl OperationContext {
+    /// 创建操作上下文
+    pub fn new(token: CancellationToken, on_progress: Option<ProgressCallback>) -> Self {
+        Self {
+            token,
+            on_progress,
+            last_percent: Arc::new(AtomicU8::new(0)),
+        }
+    }
+
+    /// 不可取消、不上报进度的上下文
+    pub fn detached() -> Self {
+        Self::new(CancellationToken::new(), None)
+    }
+
+    /// 检查是否已请求取消
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// 已请求取消时返回 [`OperationError::Cancelled`]，便于在循环中使用 `?`
+    pub fn check(&self) -> Result<(), OperationError> {
+        if self.is_cancelled() {
+            Err(OperationError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 上报进度 (仅在百分比增加时触发回调)
+    pub fn report(&self, percent: u8) {
+        let percent = percent.min(100);
+        let previous = self.last_percent.fetch_max(percent, Ordering::SeqCst);
+        if percent > previous {
+            if let Some(callback) = &self.on_progress {
+                callback(percent);
+            }
+        }
+    }
+
+    /// 按比例将已处理字节数映射到 [from, to] 百分比区间后上报
+    pub fn report_range(&self, done: usize, total: usize, from: u8, to: u8) {
+        let ratio = if total == 0 {
+            1.0
+        } else {
+            (done as f64 / total as f64).min(1.0)
+        };
+        let percent = from as f64 + (to.saturating_sub(from)) as f64 * ratio;
+        self.report(percent as u8);
+    }
+}
+
+/// 带取消检查和进度上报的读取器
+///
+/// 供 `serde_json::from_reader` 使用，解析过程中可被中断
+pub struct ProgressReader<'a> {
+    input: &'a [u8],
+    position: usize,
+    next_check: usize,
+    context: &'a OperationContext,
+    from: u8,
+    to: u8,
+}
+
+impl<'a> ProgressReader<'a> {
+    /// 创建读取器，进度映射到 [from, to] 区间
+    pub fn new(input: &'a [u8], context: &'a OperationContext, from: u8, to: u8) -> Self {
+        Self {
+            input,
+            position: 0,
+            next_check: 0,
+            context,
+            from,
+            to,
+        }
+    }
+}
+
+impl Read for ProgressReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.next_check {
+            if self.context.is_cancelled() {
+                return Err(io::Error::other(CANCELLED_MESSAGE));
+            }
+            self.context
+                .report_range(self.position, self.input.len(), self.from, self.to);
+            self.next_check = self.position + CHECK_INTERVAL;
+        }
+
+        let remaining = &self.input[self.position..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+/// 带取消检查和进度上报的写入器
+///
+/// 输出总量未知，按预估大小 `expected` 计算进度
+pub struct ProgressWriter<'a, W: Write> {
+    inner: W,
+    written: usize,
+    next_check: usize,
+    expected: usize,
+    context: &'a OperationContext,
+    from: u8,
+    to: u8,
+}
+
+impl<'a, W: Write> ProgressWriter<'a, W> {
+    /// 创建写入器，进度映射到 [from, to] 区间
+    pub fn new(inner: W, expected: usize, context: &'a OperationContext, from: u8, to: u8) -> Self {
+        Self {
+            inner,
+            written: 0,
+            next_check: 0,
+            expected,
+            context,
+            from,
+            to,
+        }
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written >= self.next_check {
+            if self.context.is_cancelled() {
+                return Err(io::Error::other(CANCELLED_MESSAGE));
+            }
+            self.context
+                .report_range(self.written, self.expected, self.from, self.to);
+            self.next_check = self.written + CHECK_INTERVAL;
+        }
+
+        let n = self.inner.write(buf)?;
+        self.written += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// 运行中操作的注册表 (线程安全)
+#[derive(Default)]
+pub struct OperationRegistry {
+    operations: Mutex<HashMap<String, CancellationToken>>,
+    counter: AtomicU64,
+}
+
+impl OperationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册新操作，未指定 ID 时自动生成
+    ///
+    /// # 返回
+    /// 操作 ID 和对应的取消令牌
+    pub fn register(&self, operation_id: Option<String>) -> (String, CancellationToken) {
+        let id = operation_id.unwrap_or_else(|| {
+            let seq = self.counter.fetch_add(1, Ordering::SeqCst);
+            format!("op-{}-{}", chrono::Utc::now().timestamp_millis(), seq)
+        });
+        let token = CancellationToken::new();
+        self.operations
+            .lock()
+            .unwrap()
+            .insert(id.clone(), token.clone());
+        (id, token)
+    }
+
+    /// 取消操作
+    ///
+    /// # 返回
+    /// 操作存在且已发出取消请求时返回 `true`
+    pub fn cancel(&self, operation_id: &str) -> bool {
+        match self.operations.lock().unwrap().get(operation_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 操作结束后移除
+    pub fn finish(&self, operation_id: &str) {
+        self.operations.lock().unwrap().remove(operation_id);
+    }
+
+    /// 当前运行中的操作数
+    pub fn active_count(&self) -> usize {
+        self.operations.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_cancel() {
+        let registry = OperationRegistry::new();
+        let (id, token) = registry.register(None);
+
+        assert!(!token.is_cancelled());
+        assert!(registry.cancel(&id));
+        assert!(token.is_cancelled());
+
+        registry.finish(&id);
+        assert_eq!(registry.active_count(), 0);
+        assert!(!registry.cancel(&id));
+    }
+
+    #[test]
+    fn test_registry_uses_given_id() {
+        let registry = OperationRegistry::new();
+        let (id, _) = registry.register(Some("custom-id".to_string()));
+        assert_eq!(id, "custom-id");
+        assert_eq!(registry.active_count(), 1);
+    }
+
+    #[test]
+    fn test_registry_generates_unique_ids() {
+        let registry = OperationRegistry::new();
+        let (a, _) = registry.register(None);
+        let (b, _) = registry.register(None);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_progress_reported_monotonically() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let context = OperationContext::new(
+            CancellationToken::new(),
+            Some(Arc::new(move |p| sink.lock().unwrap().push(p))),
+        );
+
+        context.report(10);
+        context.report(5);
+        context.report(10);
+        context.report(50);
+
+        assert_eq!(*seen.lock().unwrap(), vec![10, 50]);
+    }
+
+    #[test]
+    fn test_reader_stops_when_cancelled() {
+        let token = CancellationToken::new();
+        let context = OperationContext::new(token.clone(), None);
+        let input = vec![b' '; CHECK_INTERVAL * 2];
+        let mut reader = ProgressReader::new(&input, &context, 0, 100);
+
+        let mut buf = vec![0u8; CHECK_INTERVAL];
+        assert!(reader.read(&mut buf).is_ok());
+
+        token.cancel();
+        assert!(reader.read(&mut buf).is_err());
+    }
+}
@@ -0,0 +1,566 @@
+//! Protobuf 编解码服务
+//! 基于编译后的 FileDescriptorSet (`protoc --descriptor_set_out`)，在 Protobuf 二进制消息与
+//! proto3 规范 JSON 映射之间转换，并验证 JSON 是否符合指定的消息类型
+
+use crate::models::binary::{
+    BinaryDecodeResult, BinaryTextEncoding, LossyKind, LossyValue, ProtobufJsonOptions,
+};
+use crate::models::conversion::ConversionResult;
+use crate::models::validation::ValidationResult;
+use crate::services::binary_codec::text_to_bytes;
+use crate::services::file_io;
+use crate::services::json_formatter::pretty_print;
+use crate::services::json_parser::format_error_message;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use prost_reflect::prost::Message;
+use prost_reflect::{
+    DescriptorPool, DeserializeOptions, DynamicMessage, MapKey, MessageDescriptor,
+    SerializeOptions, Value as ReflectValue,
+};
+use serde_json::Value;
+use std::time::Instant;
+
+/// 文本输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 列出描述符集中定义的所有消息类型 (完整名称，按字母排序)
+pub fn list_messages(descriptor_path: &str) -> Result<Vec<String>, String> {
+    let pool = load_pool(&file_io::read_binary_file(descriptor_path)?)?;
+    let mut names: Vec<String> = pool
+        .all_messages()
+        .map(|message| message.full_name().to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// 解码 Base64/十六进制文本形式的 Protobuf 消息
+///
+/// # Arguments
+/// * `input` - Base64 或十六进制文本
+/// * `encoding` - 文本编码
+/// * `descriptor_path` - FileDescriptorSet 文件路径
+/// * `message_type` - 消息类型的完整名称 (如 `acme.v1.User`)
+/// * `options` - JSON 输出选项
+pub fn decode_text(
+    input: &str,
+    encoding: BinaryTextEncoding,
+    descriptor_path: &str,
+    message_type: &str,
+    options: &ProtobufJsonOptions,
+) -> BinaryDecodeResult {
+    if input.len() > MAX_INPUT_SIZE {
+        return BinaryDecodeResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    let result = text_to_bytes(input, encoding).and_then(|bytes| {
+        let descriptor = load_message(descriptor_path, message_type)?;
+        Ok(decode_message(&bytes, &descriptor, options))
+    });
+    result.unwrap_or_else(BinaryDecodeResult::error)
+}
+
+/// 读取并解码 Protobuf 二进制文件
+pub fn decode_file(
+    file_path: &str,
+    descriptor_path: &str,
+    message_type: &str,
+    options: &ProtobufJsonOptions,
+) -> BinaryDecodeResult {
+    let result = file_io::read_binary_file(file_path).and_then(|bytes| {
+        let descriptor = load_message(descriptor_path, message_type)?;
+        Ok(decode_message(&bytes, &descriptor, options))
+    });
+    result.unwrap_or_else(BinaryDecodeResult::error)
+}
+
+/// 将 proto3 JSON 编码为 Protobuf 二进制，以 Base64/十六进制文本输出
+///
+/// # Returns
+/// 转换结果，`size` 为二进制数据的字节数
+pub fn encode_text(
+    input: &str,
+    descriptor_path: &str,
+    message_type: &str,
+    encoding: BinaryTextEncoding,
+) -> ConversionResult {
+    let start = Instant::now();
+
+    let descriptor = match load_message(descriptor_path, message_type) {
+        Ok(descriptor) => descriptor,
+        Err(message) => return ConversionResult::error(message),
+    };
+    let bytes = match encode_message(input, &descriptor) {
+        Ok(bytes) => bytes,
+        Err(result) => return result,
+    };
+    let output = match encoding {
+        BinaryTextEncoding::Base64 => STANDARD.encode(&bytes),
+        BinaryTextEncoding::Hex => hex::encode(&bytes),
+    };
+
+    ConversionResult::Success {
+        output,
+        size: bytes.len(),
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings: Vec::new(),
+    }
+}
+
+/// 将 proto3 JSON 编码为 Protobuf 二进制并写入文件
+///
+/// # Returns
+/// 成功时返回保存的文件路径
+pub fn encode_file(
+    input: &str,
+    descriptor_path: &str,
+    message_type: &str,
+    file_path: &str,
+) -> Result<String, String> {
+    let descriptor = load_message(descriptor_path, message_type)?;
+    let bytes = encode_message(input, &descriptor).map_err(|result| match result {
+        ConversionResult::Error { message, .. } => message,
+        ConversionResult::Success { .. } => unreachable!(),
+    })?;
+    file_io::write_binary_file(file_path, &bytes)
+}
+
+/// 验证 JSON 是否符合指定消息类型的 proto3 JSON 映射
+///
+/// 未定义的字段、类型不匹配的值、无效的枚举名等都会报错，并给出位置
+pub fn validate_json(input: &str, descriptor_path: &str, message_type: &str) -> ValidationResult {
+    match load_message(descriptor_path, message_type) {
+        Ok(descriptor) => validate_message(input, &descriptor),
+        Err(message) => ValidationResult::Error {
+            message,
+            line: None,
+            column: None,
+        },
+    }
+}
+
+/// 读取描述符集文件并查找消息类型
+fn load_message(descriptor_path: &str, message_type: &str) -> Result<MessageDescriptor, String> {
+    let pool = load_pool(&file_io::read_binary_file(descriptor_path)?)?;
+    find_message(&pool, message_type)
+}
+
+/// 解析 FileDescriptorSet
+fn load_pool(bytes: &[u8]) -> Result<DescriptorPool, String> {
+    DescriptorPool::decode(bytes).map_err(|e| format!("描述符集解析失败: {}", e))
+}
+
+/// 按完整名称查找消息类型，允许带前导点 (`.acme.v1.User`)
+fn find_message(pool: &DescriptorPool, message_type: &str) -> Result<MessageDescriptor, String> {
+    let name = message_type.trim().trim_start_matches('.');
+    if name.is_empty() {
+        return Err("请指定消息类型".to_string());
+    }
+    pool.get_message_by_name(name)
+        .ok_or_else(|| format!("描述符集中找不到消息类型 `{}`", name))
+}
+
+/// 解码二进制消息为格式化的 JSON
+fn decode_message(
+    bytes: &[u8],
+    descriptor: &MessageDescriptor,
+    options: &ProtobufJsonOptions,
+) -> BinaryDecodeResult {
+    let start = Instant::now();
+
+    let message = match DynamicMessage::decode(descriptor.clone(), bytes) {
+        Ok(message) => message,
+        Err(e) => {
+            return BinaryDecodeResult::error(format!(
+                "Protobuf 解码失败 ({}): {}",
+                descriptor.full_name(),
+                e
+            ))
+        }
+    };
+
+    let serialize_options = SerializeOptions::new()
+        .use_proto_field_name(options.use_proto_field_names)
+        .use_enum_numbers(options.use_enum_numbers)
+        .skip_default_fields(!options.emit_default_fields);
+    let value =
+        match message.serialize_with_options(serde_json::value::Serializer, &serialize_options) {
+            Ok(value) => value,
+            Err(e) => return BinaryDecodeResult::error(format!("转换为 JSON 失败: {}", e)),
+        };
+
+    let mut lossy = Vec::new();
+    collect_unknown_fields(&message, "$", options, &mut lossy);
+
+    match pretty_print(&value, options.indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            BinaryDecodeResult::Success {
+                output,
+                size: bytes.len(),
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                lossy,
+            }
+        }
+        Err(message) => BinaryDecodeResult::error(message),
+    }
+}
+
+/// 解析 proto3 JSON 并编码为二进制
+fn encode_message(
+    input: &str,
+    descriptor: &MessageDescriptor,
+) -> Result<Vec<u8>, ConversionResult> {
+    check_input(input).map_err(ConversionResult::error)?;
+
+    let message = parse_message(input, descriptor).map_err(|(message, position)| {
+        ConversionResult::Error {
+            message,
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+        }
+    })?;
+    Ok(message.encode_to_vec())
+}
+
+/// 按消息类型验证 JSON
+fn validate_message(input: &str, descriptor: &MessageDescriptor) -> ValidationResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ValidationResult::Error {
+            message,
+            line: None,
+            column: None,
+        };
+    }
+
+    match parse_message(input, descriptor) {
+        Ok(_) => match serde_json::from_str::<Value>(input) {
+            Ok(data) => ValidationResult::Success {
+                data,
+                size: input.len(),
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                duplicate_keys: Vec::new(),
+                warnings: Vec::new(),
+            },
+            Err(e) => ValidationResult::Error {
+                message: format_error_message(&e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            },
+        },
+        Err((message, position)) => ValidationResult::Error {
+            message,
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+        },
+    }
+}
+
+/// 按消息类型解析 JSON，拒绝未定义的字段
+///
+/// # Returns
+/// 失败时返回错误消息和 (行, 列) 位置
+fn parse_message(
+    input: &str,
+    descriptor: &MessageDescriptor,
+) -> Result<DynamicMessage, (String, Option<(usize, usize)>)> {
+    let options = DeserializeOptions::new().deny_unknown_fields(true);
+    let mut deserializer = serde_json::Deserializer::from_str(input);
+    let result =
+        DynamicMessage::deserialize_with_options(descriptor.clone(), &mut deserializer, &options)
+            .and_then(|message| deserializer.end().map(|_| message));
+
+    result.map_err(|e| {
+        let position = Some((e.line(), e.column()));
+        if e.is_data() {
+            // 去掉 serde_json 附加的英文位置信息，改用统一的中文格式
+            let raw = e.to_string();
+            let detail = match raw.rfind(" at line ") {
+                Some(index) => raw[..index].to_string(),
+                None => raw,
+            };
+            let message = format!(
+                "不符合消息类型 {}: {}（第 {} 行，第 {} 列）",
+                descriptor.full_name(),
+                detail,
+                e.line(),
+                e.column()
+            );
+            (message, position)
+        } else {
+            (format_error_message(&e), position)
+        }
+    })
+}
+
+/// 检查文本输入的大小和是否为空
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+    Ok(())
+}
+
+/// 递归收集描述符中未定义的字段 (JSON 映射中会被丢弃)
+fn collect_unknown_fields(
+    message: &DynamicMessage,
+    path: &str,
+    options: &ProtobufJsonOptions,
+    lossy: &mut Vec<LossyValue>,
+) {
+    for field in message.unknown_fields() {
+        lossy.push(LossyValue {
+            path: path.to_string(),
+            kind: LossyKind::UnknownField,
+            description: format!("字段编号 {} 未在描述符中定义，已忽略", field.number()),
+        });
+    }
+
+    for (field, value) in message.fields() {
+        let name = if options.use_proto_field_names {
+            field.name()
+        } else {
+            field.json_name()
+        };
+        collect_in_value(value, &format!("{}.{}", path, name), options, lossy);
+    }
+}
+
+fn collect_in_value(
+    value: &ReflectValue,
+    path: &str,
+    options: &ProtobufJsonOptions,
+    lossy: &mut Vec<LossyValue>,
+) {
+    match value {
+        ReflectValue::Message(message) => collect_unknown_fields(message, path, options, lossy),
+        ReflectValue::List(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_in_value(item, &format!("{}[{}]", path, index), options, lossy);
+            }
+        }
+        ReflectValue::Map(entries) => {
+            for (key, item) in entries {
+                let key = match key {
+                    MapKey::Bool(b) => b.to_string(),
+                    MapKey::I32(n) => n.to_string(),
+                    MapKey::I64(n) => n.to_string(),
+                    MapKey::U32(n) => n.to_string(),
+                    MapKey::U64(n) => n.to_string(),
+                    MapKey::String(s) => s.clone(),
+                };
+                collect_in_value(item, &format!("{}.{}", path, key), options, lossy);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+    use prost_reflect::prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+    use serde_json::json;
+
+    fn field(name: &str, json_name: &str, number: i32, kind: Type) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            json_name: Some(json_name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(kind as i32),
+            ..Default::default()
+        }
+    }
+
+    /// package demo;
+    /// enum Status { STATUS_UNKNOWN = 0; STATUS_ACTIVE = 1; }
+    /// message Address { string city = 1; }
+    /// message Person {
+    ///   string first_name = 1; int64 id = 2; Status status = 3;
+    ///   repeated string tags = 4; Address address = 5; bytes avatar = 6;
+    /// }
+    fn person() -> MessageDescriptor {
+        let mut status = field("status", "status", 3, Type::Enum);
+        status.type_name = Some(".demo.Status".to_string());
+        let mut tags = field("tags", "tags", 4, Type::String);
+        tags.label = Some(Label::Repeated as i32);
+        let mut address = field("address", "address", 5, Type::Message);
+        address.type_name = Some(".demo.Address".to_string());
+
+        let file = FileDescriptorProto {
+            name: Some("demo.proto".to_string()),
+            package: Some("demo".to_string()),
+            syntax: Some("proto3".to_string()),
+            message_type: vec![
+                DescriptorProto {
+                    name: Some("Address".to_string()),
+                    field: vec![field("city", "city", 1, Type::String)],
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Person".to_string()),
+                    field: vec![
+                        field("first_name", "firstName", 1, Type::String),
+                        field("id", "id", 2, Type::Int64),
+                        status,
+                        tags,
+                        address,
+                        field("avatar", "avatar", 6, Type::Bytes),
+                    ],
+                    ..Default::default()
+                },
+            ],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Status".to_string()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("STATUS_UNKNOWN".to_string()),
+                        number: Some(0),
+                        ..Default::default()
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("STATUS_ACTIVE".to_string()),
+                        number: Some(1),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let set = FileDescriptorSet { file: vec![file] };
+        let pool = load_pool(&set.encode_to_vec()).unwrap();
+        find_message(&pool, ".demo.Person").unwrap()
+    }
+
+    fn decode(bytes: &[u8], options: &ProtobufJsonOptions) -> (Value, Vec<LossyValue>) {
+        match decode_message(bytes, &person(), options) {
+            BinaryDecodeResult::Success { output, lossy, .. } => {
+                (serde_json::from_str(&output).unwrap(), lossy)
+            }
+            BinaryDecodeResult::Error { message } => panic!("{}", message),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_canonical_json() {
+        let input = json!({
+            "firstName": "Ada",
+            "id": "42",
+            "status": "STATUS_ACTIVE",
+            "tags": ["a", "b"],
+            "address": {"city": "London"},
+            "avatar": "AQI="
+        });
+        let bytes = encode_message(&input.to_string(), &person()).unwrap();
+        let (value, lossy) = decode(&bytes, &ProtobufJsonOptions::default());
+        assert_eq!(value, input);
+        assert!(lossy.is_empty());
+    }
+
+    #[test]
+    fn test_serialize_options() {
+        let bytes = encode_message(r#"{"firstName": "Ada", "status": 1}"#, &person()).unwrap();
+
+        let options = ProtobufJsonOptions {
+            use_proto_field_names: true,
+            use_enum_numbers: true,
+            ..Default::default()
+        };
+        let (value, _) = decode(&bytes, &options);
+        assert_eq!(value, json!({"first_name": "Ada", "status": 1}));
+
+        let options = ProtobufJsonOptions {
+            emit_default_fields: true,
+            ..Default::default()
+        };
+        let (value, _) = decode(&bytes, &options);
+        assert_eq!(value["id"], json!("0"));
+        assert_eq!(value["tags"], json!([]));
+    }
+
+    #[test]
+    fn test_unknown_fields_reported() {
+        let mut bytes = encode_message(r#"{"address": {"city": "Paris"}}"#, &person()).unwrap();
+        // 字段 99，varint 类型，值为 1
+        bytes.extend([0x98, 0x06, 0x01]);
+
+        let (value, lossy) = decode(&bytes, &ProtobufJsonOptions::default());
+        assert_eq!(value, json!({"address": {"city": "Paris"}}));
+        assert_eq!(lossy.len(), 1);
+        assert_eq!(lossy[0].path, "$");
+        assert_eq!(lossy[0].kind, LossyKind::UnknownField);
+        assert!(lossy[0].description.contains("99"));
+    }
+
+    #[test]
+    fn test_validate_against_message_type() {
+        let descriptor = person();
+
+        let valid = "{\n  \"firstName\": \"Ada\",\n  \"id\": 7\n}";
+        assert!(matches!(
+            validate_message(valid, &descriptor),
+            ValidationResult::Success { .. }
+        ));
+
+        match validate_message("{\n  \"nickname\": \"x\"\n}", &descriptor) {
+            ValidationResult::Error { message, line, .. } => {
+                assert!(message.contains("demo.Person"));
+                assert!(message.contains("nickname"));
+                assert_eq!(line, Some(2));
+            }
+            other => panic!("意外的结果: {:?}", other),
+        }
+
+        match validate_message(r#"{"status": "STATUS_GONE"}"#, &descriptor) {
+            ValidationResult::Error { message, .. } => assert!(message.contains("STATUS_GONE")),
+            other => panic!("意外的结果: {:?}", other),
+        }
+
+        match validate_message(r#"{"tags": ["a",]}"#, &descriptor) {
+            ValidationResult::Error { message, .. } => assert!(message.contains("逗号")),
+            other => panic!("意外的结果: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let pool = person().parent_pool().clone();
+        assert!(find_message(&pool, "demo.Missing")
+            .unwrap_err()
+            .contains("demo.Missing"));
+        assert!(find_message(&pool, "demo.Address").is_ok());
+        assert!(load_pool(&[0xff, 0xff]).is_err());
+
+        match decode_message(
+            &[0x0a, 0x05, 0x41],
+            &person(),
+            &ProtobufJsonOptions::default(),
+        ) {
+            BinaryDecodeResult::Error { message } => assert!(message.contains("Protobuf 解码失败")),
+            other => panic!("意外的结果: {:?}", other),
+        }
+
+        assert!(matches!(
+            encode_message("  ", &person()),
+            Err(ConversionResult::Error { .. })
+        ));
+    }
+}
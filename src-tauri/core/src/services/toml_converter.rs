@@ -0,0 +1,600 @@
+//! TOML 转换服务
+//! JSON 与 TOML 双向转换，支持标准表/内联表输出、表数组，并对 TOML 无法表示的 null 与混合类型数组给出明确错误
+
+use crate::models::conversion::{
+    ConversionResult, JsonToTomlOptions, NullHandling, TomlTableStyle, TomlToJsonOptions,
+};
+use crate::services::json_formatter::pretty_print;
+use crate::services::json_sequence::line_col;
+use serde_json::{Map, Number, Value};
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 将 JSON 转换为 TOML
+///
+/// # Arguments
+/// * `input` - JSON 字符串，根节点必须是对象
+/// * `options` - 表输出方式、null 与混合类型数组的处理方式
+///
+/// # Returns
+/// 转换结果；JSON 解析失败时错误包含行列位置，无法表示的值在错误中给出路径
+pub fn json_to_toml(input: &str, options: &JsonToTomlOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let value = match serde_json::from_str::<Value>(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return ConversionResult::Error {
+                message: format!("JSON 解析失败: {}", e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            };
+        }
+    };
+
+    let root = match &value {
+        Value::Object(map) => map,
+        other => {
+            return ConversionResult::error(format!(
+                "TOML 文档的根节点必须是对象，当前为 {}",
+                type_name(other)
+            ));
+        }
+    };
+
+    let mut emitter = TomlEmitter {
+        options,
+        output: String::new(),
+        warnings: Vec::new(),
+    };
+    if let Err(message) = emitter.table(root, &[], "$") {
+        return ConversionResult::error(message);
+    }
+
+    ConversionResult::Success {
+        size: emitter.output.len(),
+        output: emitter.output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings: emitter.warnings,
+    }
+}
+
+/// 将 TOML 转换为 JSON
+///
+/// # Arguments
+/// * `input` - TOML 字符串
+/// * `options` - 输出缩进
+///
+/// # Returns
+/// 转换结果；TOML 解析失败时错误包含行列位置，日期时间转换为字符串并给出警告
+pub fn toml_to_json(input: &str, options: &TomlToJsonOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let table = match input.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(e) => {
+            let position = e.span().map(|span| line_col(input, span.start));
+            return ConversionResult::Error {
+                message: format!("TOML 解析失败: {}", e.message()),
+                line: position.map(|(line, _)| line),
+                column: position.map(|(_, column)| column),
+            };
+        }
+    };
+
+    let mut warnings = Vec::new();
+    let value = match toml_value_to_json(toml::Value::Table(table), "$", &mut warnings) {
+        Ok(value) => value,
+        Err(message) => return ConversionResult::error(message),
+    };
+
+    match pretty_print(&value, options.indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            ConversionResult::Success {
+                size: output.len(),
+                output,
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                warnings,
+            }
+        }
+        Err(message) => ConversionResult::error(message),
+    }
+}
+
+/// TOML 输出器
+struct TomlEmitter<'a> {
+    options: &'a JsonToTomlOptions,
+    output: String,
+    warnings: Vec<String>,
+}
+
+impl TomlEmitter<'_> {
+    /// 输出一个表：先输出键值对，再输出子表和表数组
+    ///
+    /// `keys` 为表头中的键路径，`path` 用于在错误和警告中标明位置
+    fn table(
+        &mut self,
+        map: &Map<String, Value>,
+        keys: &[String],
+        path: &str,
+    ) -> Result<(), String> {
+        let standard = self.options.table_style == TomlTableStyle::Standard;
+        let mut tables = Vec::new();
+        let mut array_tables = Vec::new();
+
+        for (key, value) in map {
+            let child_path = format!("{}.{}", path, key);
+            match value {
+                Value::Null => self.null(&child_path)?,
+                Value::Object(child) if standard => tables.push((key, child, child_path)),
+                Value::Array(items) if standard && is_array_of_tables(items) => {
+                    array_tables.push((key, items, child_path))
+                }
+                _ => {
+                    let inline = self.inline(value, &child_path)?;
+                    self.output
+                        .push_str(&format!("{} = {}\n", format_key(key), inline));
+                }
+            }
+        }
+
+        for (key, child, child_path) in tables {
+            let child_keys = [keys, std::slice::from_ref(key)].concat();
+            self.header(&format!("[{}]", dotted_key(&child_keys)));
+            self.table(child, &child_keys, &child_path)?;
+        }
+
+        for (key, items, child_path) in array_tables {
+            let child_keys = [keys, std::slice::from_ref(key)].concat();
+            for (index, item) in items.iter().enumerate() {
+                if let Value::Object(child) = item {
+                    self.header(&format!("[[{}]]", dotted_key(&child_keys)));
+                    self.table(child, &child_keys, &format!("{}[{}]", child_path, index))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn header(&mut self, header: &str) {
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.output.push_str(header);
+        self.output.push('\n');
+    }
+
+    /// 输出内联值 (标量、数组、内联表)
+    fn inline(&mut self, value: &Value, path: &str) -> Result<String, String> {
+        Ok(match value {
+            Value::Null => unreachable!("null 由调用方处理"),
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => format_number(n, path)?,
+            Value::String(s) => quote(s),
+            Value::Array(items) => {
+                self.check_array_types(items, path)?;
+                let mut parts = Vec::with_capacity(items.len());
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, index);
+                    if item.is_null() {
+                        self.null(&item_path)?;
+                        continue;
+                    }
+                    parts.push(self.inline(item, &item_path)?);
+                }
+                format!("[{}]", parts.join(", "))
+            }
+            Value::Object(map) => {
+                let mut parts = Vec::with_capacity(map.len());
+                for (key, child) in map {
+                    let child_path = format!("{}.{}", path, key);
+                    if child.is_null() {
+                        self.null(&child_path)?;
+                        continue;
+                    }
+                    parts.push(format!(
+                        "{} = {}",
+                        format_key(key),
+                        self.inline(child, &child_path)?
+                    ));
+                }
+                if parts.is_empty() {
+                    "{}".to_string()
+                } else {
+                    format!("{{ {} }}", parts.join(", "))
+                }
+            }
+        })
+    }
+
+    /// 按配置处理 null：报错或跳过并记录警告
+    fn null(&mut self, path: &str) -> Result<(), String> {
+        match self.options.null_handling {
+            NullHandling::Error => Err(format!("{} 的值为 null，TOML 不支持 null", path)),
+            NullHandling::Skip => {
+                self.warnings.push(format!("已跳过 {} 处的 null", path));
+                Ok(())
+            }
+        }
+    }
+
+    /// 检查数组元素类型是否一致 (忽略 null)
+    fn check_array_types(&self, items: &[Value], path: &str) -> Result<(), String> {
+        if self.options.allow_mixed_arrays {
+            return Ok(());
+        }
+
+        let mut first: Option<&str> = None;
+        for (index, item) in items.iter().enumerate() {
+            if item.is_null() {
+                continue;
+            }
+            let kind = toml_kind(item);
+            match first {
+                None => first = Some(kind),
+                Some(expected) if expected != kind => {
+                    return Err(format!(
+                        "{} 是混合类型数组 ({} 与 {}，见 {}[{}])，可启用“允许混合类型数组”后重试",
+                        path, expected, kind, path, index
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 非空且所有元素均为对象的数组输出为表数组
+fn is_array_of_tables(items: &[Value]) -> bool {
+    !items.is_empty() && items.iter().all(Value::is_object)
+}
+
+/// TOML 数组元素类型名 (整数与浮点数视为不同类型)
+fn toml_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Number(n) if n.is_f64() => "浮点数",
+        Value::Number(_) => "整数",
+        other => type_name(other),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "布尔值",
+        Value::Number(_) => "数字",
+        Value::String(_) => "字符串",
+        Value::Array(_) => "数组",
+        Value::Object(_) => "对象",
+    }
+}
+
+fn format_number(n: &Number, path: &str) -> Result<String, String> {
+    if let Some(i) = n.as_i64() {
+        return Ok(i.to_string());
+    }
+    if n.is_u64() {
+        return Err(format!(
+            "{} 处的整数 {} 超出 TOML 支持的 64 位有符号整数范围",
+            path, n
+        ));
+    }
+
+    let f = n.as_f64().unwrap_or(f64::NAN);
+    // `{:?}` 对很大或很小的数使用指数形式 (如 1e300)，避免写出数百位数字
+    let text = format!("{:?}", f);
+    // TOML 浮点数必须包含小数点或指数部分
+    if text.contains(['.', 'e', 'E']) {
+        Ok(text)
+    } else {
+        Ok(format!("{}.0", text))
+    }
+}
+
+/// 可作为裸键的键名只包含 ASCII 字母、数字、`_` 和 `-`
+fn format_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if bare {
+        key.to_string()
+    } else {
+        quote(key)
+    }
+}
+
+fn dotted_key(keys: &[String]) -> String {
+    keys.iter()
+        .map(|key| format_key(key))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// 输出 TOML 基本字符串 (双引号)
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// 将 TOML 值转换为 JSON 值
+///
+/// `path` 用于在错误和警告中标明位置
+fn toml_value_to_json(
+    value: toml::Value,
+    path: &str,
+    warnings: &mut Vec<String>,
+) -> Result<Value, String> {
+    Ok(match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Number::from_f64(f)
+            .map(Value::Number)
+            .ok_or_else(|| format!("{} 处的数值 {} 无法用 JSON 表示", path, f))?,
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(datetime) => {
+            warnings.push(format!("{} 处的日期时间已转换为字符串", path));
+            Value::String(datetime.to_string())
+        }
+        toml::Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| toml_value_to_json(item, &format!("{}[{}]", path, i), warnings))
+                .collect::<Result<_, _>>()?,
+        ),
+        toml::Value::Table(table) => {
+            let mut map = Map::new();
+            for (key, child) in table {
+                let child_path = format!("{}.{}", path, key);
+                map.insert(key, toml_value_to_json(child, &child_path, warnings)?);
+            }
+            Value::Object(map)
+        }
+    })
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_toml(input: &str, options: &JsonToTomlOptions) -> String {
+        match json_to_toml(input, options) {
+            ConversionResult::Success { output, .. } => output,
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn to_toml_error(input: &str, options: &JsonToTomlOptions) -> String {
+        match json_to_toml(input, options) {
+            ConversionResult::Success { output, .. } => panic!("Expected Error: {}", output),
+            ConversionResult::Error { message, .. } => message,
+        }
+    }
+
+    fn to_json(input: &str) -> (Value, Vec<String>) {
+        match toml_to_json(input, &TomlToJsonOptions::default()) {
+            ConversionResult::Success {
+                output, warnings, ..
+            } => (serde_json::from_str(&output).unwrap(), warnings),
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    #[test]
+    fn test_standard_tables() {
+        let toml = to_toml(
+            r#"{"title":"app","server":{"host":"localhost","ports":[80,443],"tls":{"enabled":true}},"ratio":1.0}"#,
+            &JsonToTomlOptions::default(),
+        );
+        assert_eq!(
+            toml,
+            "ratio = 1.0\ntitle = \"app\"\n\n[server]\nhost = \"localhost\"\nports = [80, 443]\n\n[server.tls]\nenabled = true\n"
+        );
+    }
+
+    #[test]
+    fn test_array_of_tables() {
+        let toml = to_toml(
+            r#"{"products":[{"name":"a","sku":1},{"name":"b","dims":{"w":2}}]}"#,
+            &JsonToTomlOptions::default(),
+        );
+        assert_eq!(
+            toml,
+            "[[products]]\nname = \"a\"\nsku = 1\n\n[[products]]\nname = \"b\"\n\n[products.dims]\nw = 2\n"
+        );
+        let (value, _) = to_json(&toml);
+        assert_eq!(value["products"][1]["dims"]["w"], json!(2));
+    }
+
+    #[test]
+    fn test_inline_tables() {
+        let options = JsonToTomlOptions {
+            table_style: TomlTableStyle::Inline,
+            ..Default::default()
+        };
+        let toml = to_toml(r#"{"a":{"b":1,"c":{}},"list":[{"x":1}]}"#, &options);
+        assert_eq!(toml, "a = { b = 1, c = {} }\nlist = [{ x = 1 }]\n");
+    }
+
+    #[test]
+    fn test_key_quoting_and_string_escapes() {
+        let toml = to_toml(
+            r#"{"plain-key_1":"a\"b\\c\nd","with space":"\u0001","":1,"a.b":{"c":1}}"#,
+            &JsonToTomlOptions::default(),
+        );
+        assert!(toml.contains("plain-key_1 = \"a\\\"b\\\\c\\nd\"\n"));
+        assert!(toml.contains("\"with space\" = \"\\u0001\"\n"));
+        assert!(toml.contains("\"\" = 1\n"));
+        assert!(toml.contains("[\"a.b\"]\n"));
+
+        let (value, _) = to_json(&toml);
+        assert_eq!(value["a.b"]["c"], json!(1));
+        assert_eq!(value["with space"], json!("\u{1}"));
+    }
+
+    #[test]
+    fn test_null_rejected_with_path() {
+        let message = to_toml_error(r#"{"a":{"b":[1,null]}}"#, &JsonToTomlOptions::default());
+        assert!(message.contains("$.a.b[1]"));
+        assert!(message.contains("null"));
+    }
+
+    #[test]
+    fn test_null_skipped() {
+        let options = JsonToTomlOptions {
+            null_handling: NullHandling::Skip,
+            ..Default::default()
+        };
+        match json_to_toml(r#"{"a":null,"b":[1,null,2]}"#, &options) {
+            ConversionResult::Success {
+                output, warnings, ..
+            } => {
+                assert_eq!(output, "b = [1, 2]\n");
+                assert_eq!(warnings.len(), 2);
+            }
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    #[test]
+    fn test_mixed_array_types() {
+        let message = to_toml_error(r#"{"a":[1,"x"]}"#, &JsonToTomlOptions::default());
+        assert!(message.contains("混合类型数组"));
+        assert!(message.contains("$.a[1]"));
+
+        // 整数与浮点数也视为不同类型
+        assert!(to_toml_error(r#"{"a":[1,2.5]}"#, &JsonToTomlOptions::default()).contains("$.a"));
+
+        let options = JsonToTomlOptions {
+            allow_mixed_arrays: true,
+            ..Default::default()
+        };
+        assert_eq!(to_toml(r#"{"a":[1,"x"]}"#, &options), "a = [1, \"x\"]\n");
+    }
+
+    #[test]
+    fn test_root_must_be_object() {
+        let message = to_toml_error("[1, 2]", &JsonToTomlOptions::default());
+        assert!(message.contains("根节点必须是对象"));
+    }
+
+    #[test]
+    fn test_integer_out_of_range() {
+        let message = to_toml_error(
+            r#"{"big":18446744073709551615}"#,
+            &JsonToTomlOptions::default(),
+        );
+        assert!(message.contains("$.big"));
+    }
+
+    #[test]
+    fn test_toml_to_json() {
+        let (value, warnings) = to_json(
+            "title = \"app\"\n\n[server]\nports = [80, 443]\n\n[[users]]\nname = \"a\"\n\n[[users]]\nname = \"b\"\ncreated = 1979-05-27T07:32:00Z\n",
+        );
+        assert_eq!(
+            value,
+            json!({
+                "title": "app",
+                "server": {"ports": [80, 443]},
+                "users": [{"name": "a"}, {"name": "b", "created": "1979-05-27T07:32:00Z"}]
+            })
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("$.users[1].created"));
+    }
+
+    #[test]
+    fn test_toml_nan_rejected() {
+        match toml_to_json("a = nan", &TomlToJsonOptions::default()) {
+            ConversionResult::Error { message, .. } => assert!(message.contains("$.a")),
+            _ => panic!("Expected Error"),
+        }
+    }
+
+    #[test]
+    fn test_toml_error_has_position() {
+        match toml_to_json("a = 1\nb = \n", &TomlToJsonOptions::default()) {
+            ConversionResult::Error { message, line, .. } => {
+                assert!(message.starts_with("TOML 解析失败"));
+                assert_eq!(line, Some(2));
+            }
+            _ => panic!("Expected Error"),
+        }
+    }
+
+    #[test]
+    fn test_float_formatting() {
+        let toml = to_toml(
+            r#"{"a":1e300,"b":-2.5e-8,"c":1.5,"d":100.0}"#,
+            &JsonToTomlOptions::default(),
+        );
+        assert_eq!(toml, "a = 1e300\nb = -2.5e-8\nc = 1.5\nd = 100.0\n");
+        let (value, _) = to_json(&toml);
+        assert_eq!(
+            value,
+            json!({"a": 1e300, "b": -2.5e-8, "c": 1.5, "d": 100.0})
+        );
+    }
+
+    #[test]
+    fn test_json_toml_round_trip() {
+        let input = json!({
+            "name": "demo",
+            "version": 3,
+            "ratio": 0.25,
+            "flags": [true, false],
+            "matrix": [[1, 2], [3, 4]],
+            "deps": {"serde": {"version": "1", "features": ["derive"]}},
+            "bins": [{"name": "cli"}, {"name": "gui", "path": "src/gui.rs"}]
+        });
+        let toml = to_toml(&input.to_string(), &JsonToTomlOptions::default());
+        let (value, _) = to_json(&toml);
+        assert_eq!(value, input);
+    }
+}
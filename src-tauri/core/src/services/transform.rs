@@ -0,0 +1,577 @@
+//! 字符串编码变换服务
+//! 解码 Base64/Base64URL 与 URL 百分号编码的字符串值，`\uXXXX` 转义与字面字符互转，以及解码 JWT
+
+use crate::models::binary::BinaryTextEncoding;
+use crate::models::formatting::EscapeOptions;
+use crate::models::transform::{
+    Base64DecodeOptions, JwtDecodeResult, JwtTimestamp, PercentDecodeOptions, TransformResult,
+};
+use crate::services::binary_codec::text_to_bytes;
+use crate::services::document_store::{child_pointer, resolve_mut};
+use crate::services::json_formatter::{pretty_print, pretty_print_escaped};
+use serde_json::Value;
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 解码为文本时要求的最小 Base64 长度，避免把普通单词当作 Base64
+const MIN_BASE64_TEXT_LENGTH: usize = 8;
+
+/// 以日期形式显示的 JWT 时间声明 (RFC 7519 第 4.1 节)
+const JWT_TIME_CLAIMS: &[&str] = &["exp", "nbf", "iat"];
+
+/// 将 Base64/Base64URL 编码的字符串值替换为解码结果
+///
+/// 默认只替换解码后是 JSON 对象或数组的值
+///
+/// # Arguments
+/// * `input` - JSON 文本
+/// * `pointer` - 只处理该节点下的值 (JSON Pointer，空字符串表示整个文档)
+/// * `options` - 是否也解码为普通文本
+pub fn decode_base64_strings(
+    input: &str,
+    pointer: &str,
+    options: &Base64DecodeOptions,
+) -> TransformResult {
+    transform_strings(input, pointer, options.indent, |text| {
+        decode_base64(text, options.decode_text)
+    })
+}
+
+/// 将 URL 百分号编码 (`%E4%BD%A0`) 的字符串值解码
+pub fn percent_decode_strings(
+    input: &str,
+    pointer: &str,
+    options: &PercentDecodeOptions,
+) -> TransformResult {
+    transform_strings(input, pointer, options.indent, |text| {
+        percent_decode(text, options.plus_as_space).map(Value::String)
+    })
+}
+
+/// 将字符串值中字面的 `\uXXXX` 转义序列 (如被二次转义的文本) 还原为字符
+pub fn unescape_unicode(input: &str, pointer: &str, indent: u8) -> TransformResult {
+    transform_strings(input, pointer, indent, |text| {
+        unescape(text).map(Value::String)
+    })
+}
+
+/// 将所有非 ASCII 字符转义为 `\uXXXX` 输出
+///
+/// # Returns
+/// 变换结果，`changed` 为含有非 ASCII 字符的字符串或键所在的节点
+pub fn escape_unicode(input: &str, indent: u8) -> TransformResult {
+    let start = Instant::now();
+
+    let value = match parse_input(input) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+
+    let mut changed = Vec::new();
+    collect_non_ascii(&value, String::new(), &mut changed);
+
+    let escape = EscapeOptions {
+        ascii_only: true,
+        ..Default::default()
+    };
+    match pretty_print_escaped(&value, indent, &escape) {
+        Ok(output) => TransformResult::Success {
+            size: output.len(),
+            output,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+            changed,
+        },
+        Err(message) => TransformResult::error(message),
+    }
+}
+
+/// 解码 JWT 的头部和载荷 (不验证签名)
+///
+/// 接受带 `Bearer ` 前缀的令牌；`exp`、`nbf`、`iat` 以 RFC 3339 时间给出
+pub fn decode_jwt(token: &str, indent: u8) -> JwtDecodeResult {
+    let token = token.trim();
+    let token = match token.get(..7) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("bearer ") => token[7..].trim(),
+        _ => token,
+    };
+    if token.is_empty() {
+        return JwtDecodeResult::error("输入为空，请提供 JWT");
+    }
+
+    let parts: Vec<&str> = token.split('.').collect();
+    match parts.len() {
+        3 => {}
+        5 => {
+            return JwtDecodeResult::error("这是加密的 JWT (JWE)，没有密钥无法解码载荷");
+        }
+        _ => {
+            return JwtDecodeResult::error(
+                "JWT 必须由三段以点号分隔的 Base64URL 组成 (头部.载荷.签名)",
+            );
+        }
+    }
+
+    let header = match decode_segment(parts[0], "头部") {
+        Ok(header) => header,
+        Err(message) => return JwtDecodeResult::error(message),
+    };
+    let payload = match decode_segment(parts[1], "载荷") {
+        Ok(payload) => payload,
+        Err(message) => return JwtDecodeResult::error(message),
+    };
+
+    let mut timestamps = Vec::new();
+    for claim in JWT_TIME_CLAIMS {
+        let seconds = match payload.get(*claim).and_then(Value::as_f64) {
+            Some(seconds) => seconds as i64,
+            None => continue,
+        };
+        if let Some(datetime) = chrono::DateTime::from_timestamp(seconds, 0) {
+            timestamps.push(JwtTimestamp {
+                claim: claim.to_string(),
+                value: seconds,
+                datetime: datetime.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            });
+        }
+    }
+    let now = chrono::Utc::now().timestamp();
+    let expired = timestamps
+        .iter()
+        .find(|timestamp| timestamp.claim == "exp")
+        .map(|exp| exp.value <= now);
+
+    let header_text = match pretty_print(&header, indent) {
+        Ok(text) => text,
+        Err(message) => return JwtDecodeResult::error(message),
+    };
+    let payload_text = match pretty_print(&payload, indent) {
+        Ok(text) => text,
+        Err(message) => return JwtDecodeResult::error(message),
+    };
+
+    JwtDecodeResult::Success {
+        header: header_text,
+        payload: payload_text,
+        signature: parts[2].to_string(),
+        algorithm: header
+            .get("alg")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        timestamps,
+        expired,
+    }
+}
+
+/// 解码 JWT 的一段为 JSON 对象
+fn decode_segment(segment: &str, name: &str) -> Result<Value, String> {
+    let bytes = text_to_bytes(segment, BinaryTextEncoding::Base64)
+        .map_err(|e| format!("JWT {}不是有效的 Base64URL: {}", name, e))?;
+    let value: Value = serde_json::from_slice(&bytes)
+        .map_err(|e| format!("JWT {}不是有效的 JSON: {}", name, e))?;
+    if !value.is_object() {
+        return Err(format!("JWT {}必须是 JSON 对象", name));
+    }
+    Ok(value)
+}
+
+/// 解析 JSON，对指定节点下的每个字符串值应用变换
+fn transform_strings(
+    input: &str,
+    pointer: &str,
+    indent: u8,
+    mut f: impl FnMut(&str) -> Option<Value>,
+) -> TransformResult {
+    let start = Instant::now();
+
+    let mut value = match parse_input(input) {
+        Ok(value) => value,
+        Err(result) => return result,
+    };
+    let node = match resolve_mut(&mut value, pointer) {
+        Ok(node) => node,
+        Err(message) => return TransformResult::error(message),
+    };
+
+    let mut changed = Vec::new();
+    map_strings(node, pointer.to_string(), &mut f, &mut changed);
+
+    match pretty_print(&value, indent) {
+        Ok(output) => TransformResult::Success {
+            size: output.len(),
+            output,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+            changed,
+        },
+        Err(message) => TransformResult::error(message),
+    }
+}
+
+fn map_strings(
+    value: &mut Value,
+    pointer: String,
+    f: &mut dyn FnMut(&str) -> Option<Value>,
+    changed: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                map_strings(child, child_pointer(&pointer, key), f, changed);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter_mut().enumerate() {
+                map_strings(
+                    child,
+                    child_pointer(&pointer, &index.to_string()),
+                    f,
+                    changed,
+                );
+            }
+        }
+        Value::String(text) => {
+            if let Some(replacement) = f(text) {
+                *value = replacement;
+                changed.push(pointer);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_non_ascii(value: &Value, pointer: String, changed: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = child_pointer(&pointer, key);
+                if !key.is_ascii() {
+                    changed.push(child_path.clone());
+                }
+                collect_non_ascii(child, child_path, changed);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                collect_non_ascii(child, child_pointer(&pointer, &index.to_string()), changed);
+            }
+        }
+        // 键和值都含有非 ASCII 字符时只记录一次
+        Value::String(text) if !text.is_ascii() && changed.last() != Some(&pointer) => {
+            changed.push(pointer);
+        }
+        _ => {}
+    }
+}
+
+/// 解码看起来是 Base64/Base64URL 的字符串
+fn decode_base64(text: &str, decode_text: bool) -> Option<Value> {
+    let standard = text
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'/' || b == b'=');
+    let url_safe = text
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'=');
+    let padding_at_end = text.trim_end_matches('=').bytes().all(|b| b != b'=');
+    if text.len() < 4 || !(standard || url_safe) || !padding_at_end {
+        return None;
+    }
+
+    let bytes = text_to_bytes(text, BinaryTextEncoding::Base64).ok()?;
+    let decoded = String::from_utf8(bytes).ok()?;
+
+    let trimmed = decoded.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if let Ok(value) = serde_json::from_str::<Value>(trimmed) {
+            return Some(value);
+        }
+    }
+
+    let readable = !decoded.is_empty()
+        && decoded
+            .chars()
+            .all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t');
+    if decode_text && text.len() >= MIN_BASE64_TEXT_LENGTH && readable {
+        Some(Value::String(decoded))
+    } else {
+        None
+    }
+}
+
+/// URL 百分号解码，没有编码序列或结果不是有效的 UTF-8 时返回 `None`
+fn percent_decode(text: &str, plus_as_space: bool) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut found = false;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() && is_hex_pair(&bytes[i + 1..i + 3]) => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok()?;
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+                found = true;
+                i += 3;
+            }
+            b'+' if plus_as_space => {
+                decoded.push(b' ');
+                found = true;
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    if found {
+        String::from_utf8(decoded).ok()
+    } else {
+        None
+    }
+}
+
+fn is_hex_pair(pair: &[u8]) -> bool {
+    pair.len() == 2 && pair.iter().all(u8::is_ascii_hexdigit)
+}
+
+/// 还原字面的 `\uXXXX` 转义序列，单独的代理项保持原样
+fn unescape(text: &str) -> Option<String> {
+    if !text.contains("\\u") {
+        return None;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut found = false;
+
+    while let Some(index) = rest.find("\\u") {
+        result.push_str(&rest[..index]);
+        let after = &rest[index..];
+
+        let unit = match parse_unit(after) {
+            Some(unit) => unit,
+            None => {
+                result.push_str("\\u");
+                rest = &after[2..];
+                continue;
+            }
+        };
+
+        let (ch, consumed) = if (0xD800..0xDC00).contains(&unit) {
+            match parse_unit(&after[6..]) {
+                Some(low) if (0xDC00..0xE000).contains(&low) => {
+                    let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                    (char::from_u32(code), 12)
+                }
+                _ => (None, 6),
+            }
+        } else {
+            (char::from_u32(unit), 6)
+        };
+
+        match ch {
+            Some(ch) => {
+                result.push(ch);
+                found = true;
+            }
+            None => result.push_str(&after[..consumed]),
+        }
+        rest = &after[consumed..];
+    }
+    result.push_str(rest);
+
+    if found {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// 解析 `\uXXXX` 的码元
+fn parse_unit(text: &str) -> Option<u32> {
+    let digits = text.strip_prefix("\\u")?.get(..4)?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+fn parse_input(input: &str) -> Result<Value, TransformResult> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(TransformResult::error(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        )));
+    }
+    if input.trim().is_empty() {
+        return Err(TransformResult::error("输入为空，请提供有效的内容"));
+    }
+
+    serde_json::from_str::<Value>(input).map_err(|e| TransformResult::Error {
+        message: format!("JSON 解析失败: {}", e),
+        line: Some(e.line()),
+        column: Some(e.column()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+    use base64::Engine;
+    use serde_json::json;
+
+    fn run(result: TransformResult) -> (Value, Vec<String>) {
+        match result {
+            TransformResult::Success {
+                output, changed, ..
+            } => (serde_json::from_str(&output).unwrap(), changed),
+            TransformResult::Error { message, .. } => panic!("{}", message),
+        }
+    }
+
+    #[test]
+    fn test_decode_base64_strings() {
+        let embedded = STANDARD.encode(r#"{"id": 1}"#);
+        let url_safe = URL_SAFE_NO_PAD.encode("[\"ü?>\"]");
+        let text = STANDARD.encode("hello world");
+        let input = json!({
+            "a": embedded,
+            "b": url_safe,
+            "c": text,
+            "word": "Test",
+            "sentence": "not base64 at all"
+        });
+
+        let (value, changed) = run(decode_base64_strings(
+            &input.to_string(),
+            "",
+            &Base64DecodeOptions::default(),
+        ));
+        assert_eq!(value["a"], json!({"id": 1}));
+        assert_eq!(value["b"], json!(["ü?>"]));
+        assert_eq!(value["c"], json!(text));
+        assert_eq!(changed, vec!["/a", "/b"]);
+
+        let options = Base64DecodeOptions {
+            decode_text: true,
+            ..Default::default()
+        };
+        let (value, _) = run(decode_base64_strings(&input.to_string(), "", &options));
+        assert_eq!(value["c"], json!("hello world"));
+        assert_eq!(value["word"], json!("Test"));
+        assert_eq!(value["sentence"], json!("not base64 at all"));
+    }
+
+    #[test]
+    fn test_percent_decode_strings() {
+        let input = json!({
+            "url": "https://example.com/?q=%E4%BD%A0%E5%A5%BD&x=a+b",
+            "plain": "100% sure",
+            "bad": "%FF%FE"
+        });
+
+        let (value, changed) = run(percent_decode_strings(
+            &input.to_string(),
+            "",
+            &PercentDecodeOptions::default(),
+        ));
+        assert_eq!(value["url"], json!("https://example.com/?q=你好&x=a+b"));
+        assert_eq!(value["plain"], json!("100% sure"));
+        assert_eq!(value["bad"], json!("%FF%FE"));
+        assert_eq!(changed, vec!["/url"]);
+
+        let options = PercentDecodeOptions {
+            plus_as_space: true,
+            ..Default::default()
+        };
+        let (value, _) = run(percent_decode_strings(&input.to_string(), "/url", &options));
+        assert_eq!(value["url"], json!("https://example.com/?q=你好&x=a b"));
+    }
+
+    #[test]
+    fn test_unescape_and_escape_unicode() {
+        let input = json!({
+            "name": "caf\\u00e9 \\ud83d\\ude00",
+            "lone": "\\ud800 x",
+            "path": "C:\\users"
+        });
+        let (value, changed) = run(unescape_unicode(&input.to_string(), "", 2));
+        assert_eq!(value["name"], json!("café 😀"));
+        assert_eq!(value["lone"], json!("\\ud800 x"));
+        assert_eq!(value["path"], json!("C:\\users"));
+        assert_eq!(changed, vec!["/name"]);
+
+        let result = escape_unicode(&value.to_string(), 0);
+        let output = match &result {
+            TransformResult::Success { output, .. } => output.clone(),
+            TransformResult::Error { message, .. } => panic!("{}", message),
+        };
+        assert!(output.is_ascii());
+        assert!(output.contains(r#""caf\u00e9 \ud83d\ude00""#));
+        let (reparsed, changed) = run(result);
+        assert_eq!(reparsed, value);
+        assert_eq!(changed, vec!["/name"]);
+    }
+
+    fn token(header: &Value, payload: &Value) -> String {
+        format!(
+            "{}.{}.c2lnbmF0dXJl",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(payload.to_string())
+        )
+    }
+
+    #[test]
+    fn test_decode_jwt() {
+        let jwt = token(
+            &json!({"alg": "HS256", "typ": "JWT"}),
+            &json!({"sub": "42", "iat": 1516239022, "exp": 1516242622}),
+        );
+
+        match decode_jwt(&format!("Bearer {}", jwt), 2) {
+            JwtDecodeResult::Success {
+                header,
+                payload,
+                signature,
+                algorithm,
+                timestamps,
+                expired,
+            } => {
+                assert!(header.contains("\"typ\": \"JWT\""));
+                assert!(payload.contains("\"sub\": \"42\""));
+                assert_eq!(signature, "c2lnbmF0dXJl");
+                assert_eq!(algorithm.as_deref(), Some("HS256"));
+                assert_eq!(timestamps.len(), 2);
+                assert_eq!(timestamps[0].claim, "exp");
+                assert_eq!(timestamps[0].datetime, "2018-01-18T02:30:22Z");
+                assert_eq!(timestamps[1].claim, "iat");
+                assert_eq!(timestamps[1].datetime, "2018-01-18T01:30:22Z");
+                assert_eq!(expired, Some(true));
+            }
+            JwtDecodeResult::Error { message } => panic!("{}", message),
+        }
+    }
+
+    #[test]
+    fn test_decode_jwt_errors() {
+        let error = |input: &str| match decode_jwt(input, 2) {
+            JwtDecodeResult::Error { message } => message,
+            other => panic!("意外的结果: {:?}", other),
+        };
+
+        assert!(error("").contains("为空"));
+        assert!(error("a.b").contains("三段"));
+        assert!(error("a.b.c.d.e").contains("JWE"));
+        assert!(error("!!!.e30.x").contains("头部"));
+
+        let not_object = format!(
+            "{}.{}.",
+            URL_SAFE_NO_PAD.encode("{}"),
+            URL_SAFE_NO_PAD.encode("[1]")
+        );
+        assert!(error(&not_object).contains("载荷必须是 JSON 对象"));
+    }
+}
@@ -0,0 +1,709 @@
+//! XML 转换服务
+//! JSON 与 XML 双向转换，可配置属性前缀、文本键、数组识别规则与命名空间处理方式
+
+use crate::models::conversion::{
+    ConversionResult, JsonToXmlOptions, XmlArrayDetection, XmlNamespaceHandling, XmlToJsonOptions,
+};
+use crate::services::json_formatter::pretty_print;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 将 XML 转换为 JSON
+///
+/// # Arguments
+/// * `input` - XML 字符串 (出于安全考虑不支持 DTD)
+/// * `options` - 属性前缀、文本键、数组识别与命名空间处理方式
+///
+/// # Returns
+/// 转换结果，根元素作为 JSON 根对象的唯一键；XML 解析失败时错误包含行列位置
+pub fn xml_to_json(input: &str, options: &XmlToJsonOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let document = match roxmltree::Document::parse(input) {
+        Ok(document) => document,
+        Err(e) => {
+            let position = e.pos();
+            return ConversionResult::Error {
+                message: format!("XML 解析失败: {}", e),
+                line: Some(position.row as usize),
+                column: Some(position.col as usize),
+            };
+        }
+    };
+
+    let root = document.root_element();
+    let mut map = Map::new();
+    map.insert(element_name(root, options), element_to_json(root, options));
+
+    match pretty_print(&Value::Object(map), options.indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            ConversionResult::Success {
+                size: output.len(),
+                output,
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                warnings: Vec::new(),
+            }
+        }
+        Err(message) => ConversionResult::error(message),
+    }
+}
+
+/// 将 JSON 转换为 XML
+///
+/// # Arguments
+/// * `input` - JSON 字符串
+/// * `options` - 属性前缀、文本键、根元素名称与缩进
+///
+/// # Returns
+/// 转换结果；键名不是合法 XML 名称等问题在错误中给出路径
+pub fn json_to_xml(input: &str, options: &JsonToXmlOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    if ![0, 2, 4].contains(&options.indent) {
+        return ConversionResult::error(format!(
+            "不支持的缩进值 {}，仅支持 0、2 或 4 个空格",
+            options.indent
+        ));
+    }
+
+    let value = match serde_json::from_str::<Value>(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return ConversionResult::Error {
+                message: format!("JSON 解析失败: {}", e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            };
+        }
+    };
+
+    let mut writer = XmlWriter {
+        options,
+        output: String::new(),
+        prefix_counter: 0,
+    };
+    if options.declaration {
+        writer.line(0, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    }
+
+    let result = match &value {
+        // 只有一个普通键的对象：该键即为根元素
+        Value::Object(map)
+            if map.len() == 1
+                && map
+                    .keys()
+                    .all(|key| !writer.is_attribute(key) && *key != options.text_key) =>
+        {
+            let (name, content) = map.iter().next().unwrap();
+            match content {
+                // 数组展开为多个同名元素，需要外层根元素
+                Value::Array(_) => writer.element(&options.root_name, &value, 0, "$", None),
+                _ => writer.element(name, content, 0, &format!("$.{}", name), None),
+            }
+        }
+        Value::Array(_) => {
+            let mut wrapper = Map::new();
+            wrapper.insert(options.item_name.clone(), value.clone());
+            writer.element(&options.root_name, &Value::Object(wrapper), 0, "$", None)
+        }
+        _ => writer.element(&options.root_name, &value, 0, "$", None),
+    };
+    if let Err(message) = result {
+        return ConversionResult::error(message);
+    }
+
+    let output = writer.output;
+    ConversionResult::Success {
+        size: output.len(),
+        output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings: Vec::new(),
+    }
+}
+
+/// 将 XML 元素转换为 JSON 值
+///
+/// 没有属性和子元素的元素转换为文本 (空元素为 null)，否则转换为对象
+fn element_to_json(node: roxmltree::Node, options: &XmlToJsonOptions) -> Value {
+    let mut map = Map::new();
+
+    if options.namespaces == XmlNamespaceHandling::Prefix {
+        // 只保留在当前元素上声明的命名空间
+        let inherited: Vec<(Option<&str>, &str)> = node
+            .parent_element()
+            .map(|parent| {
+                parent
+                    .namespaces()
+                    .map(|ns| (ns.name(), ns.uri()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for namespace in node.namespaces() {
+            if inherited.contains(&(namespace.name(), namespace.uri())) {
+                continue;
+            }
+            let name = match namespace.name() {
+                Some(prefix) => format!("xmlns:{}", prefix),
+                None => "xmlns".to_string(),
+            };
+            map.insert(
+                format!("{}{}", options.attribute_prefix, name),
+                Value::String(namespace.uri().to_string()),
+            );
+        }
+    }
+
+    for attribute in node.attributes() {
+        let name = qualified_name(node, attribute.namespace(), attribute.name(), options);
+        map.insert(
+            format!("{}{}", options.attribute_prefix, name),
+            text_value(attribute.value(), options.infer_types),
+        );
+    }
+
+    let mut order = Vec::new();
+    let mut children: HashMap<String, Vec<Value>> = HashMap::new();
+    let mut text = String::new();
+    for child in node.children() {
+        if child.is_element() {
+            let name = element_name(child, options);
+            if !children.contains_key(&name) {
+                order.push(name.clone());
+            }
+            children
+                .entry(name)
+                .or_default()
+                .push(element_to_json(child, options));
+        } else if child.is_text() {
+            text.push_str(child.text().unwrap_or_default());
+        }
+    }
+    let text = text.trim();
+
+    if map.is_empty() && order.is_empty() {
+        return if text.is_empty() {
+            Value::Null
+        } else {
+            text_value(text, options.infer_types)
+        };
+    }
+
+    for name in order {
+        let mut values = children.remove(&name).unwrap_or_default();
+        let as_array = values.len() > 1
+            || options.array_detection == XmlArrayDetection::Always
+            || options.force_array.contains(&name);
+        let value = if as_array {
+            Value::Array(values)
+        } else {
+            values.swap_remove(0)
+        };
+        map.insert(name, value);
+    }
+
+    if !text.is_empty() {
+        map.insert(
+            options.text_key.clone(),
+            text_value(text, options.infer_types),
+        );
+    }
+
+    Value::Object(map)
+}
+
+fn element_name(node: roxmltree::Node, options: &XmlToJsonOptions) -> String {
+    let tag = node.tag_name();
+    qualified_name(node, tag.namespace(), tag.name(), options)
+}
+
+/// 按命名空间处理方式生成元素或属性的名称
+fn qualified_name(
+    node: roxmltree::Node,
+    namespace: Option<&str>,
+    local: &str,
+    options: &XmlToJsonOptions,
+) -> String {
+    // `xmlns=""` 取消默认命名空间，视为没有命名空间
+    let namespace = namespace.filter(|uri| !uri.is_empty());
+    match (options.namespaces, namespace) {
+        (XmlNamespaceHandling::Strip, _) | (_, None) => local.to_string(),
+        (XmlNamespaceHandling::Expand, Some(uri)) => format!("{{{}}}{}", uri, local),
+        (XmlNamespaceHandling::Prefix, Some(uri)) => match node.lookup_prefix(uri) {
+            Some(prefix) => format!("{}:{}", prefix, local),
+            None => local.to_string(),
+        },
+    }
+}
+
+/// 文本转换为 JSON 值，按需推断数字和布尔值
+fn text_value(text: &str, infer_types: bool) -> Value {
+    if infer_types {
+        match text {
+            "true" => return Value::Bool(true),
+            "false" => return Value::Bool(false),
+            _ => {}
+        }
+        if text.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+            if let Ok(Value::Number(n)) = serde_json::from_str::<Value>(text) {
+                // 超出 64 位范围的整数保留为字符串，避免丢失精度
+                if !n.is_f64() || text.contains(['.', 'e', 'E']) {
+                    return Value::Number(n);
+                }
+            }
+        }
+    }
+    Value::String(text.to_string())
+}
+
+/// XML 输出器
+struct XmlWriter<'a> {
+    options: &'a JsonToXmlOptions,
+    output: String,
+    prefix_counter: usize,
+}
+
+impl XmlWriter<'_> {
+    fn is_attribute(&self, key: &str) -> bool {
+        !self.options.attribute_prefix.is_empty() && key.starts_with(&self.options.attribute_prefix)
+    }
+
+    fn line(&mut self, depth: usize, text: &str) {
+        let indent = self.options.indent as usize;
+        if indent > 0 {
+            self.output.push_str(&" ".repeat(depth * indent));
+        }
+        self.output.push_str(text);
+        if indent > 0 {
+            self.output.push('\n');
+        }
+    }
+
+    /// 输出一个字段：数组展开为多个同名元素
+    fn field(
+        &mut self,
+        name: &str,
+        value: &Value,
+        depth: usize,
+        path: &str,
+        default_ns: Option<&str>,
+    ) -> Result<(), String> {
+        match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, index);
+                    if item.is_array() {
+                        // 嵌套数组无法用同名元素表达，额外包裹一层
+                        let mut wrapper = Map::new();
+                        wrapper.insert(self.options.item_name.clone(), item.clone());
+                        self.element(name, &Value::Object(wrapper), depth, &item_path, default_ns)?;
+                    } else {
+                        self.element(name, item, depth, &item_path, default_ns)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => self.element(name, value, depth, path, default_ns),
+        }
+    }
+
+    /// 输出一个元素
+    ///
+    /// `default_ns` 为当前生效的默认命名空间，支持 `{uri}local` 形式的展开名称
+    fn element(
+        &mut self,
+        name: &str,
+        value: &Value,
+        depth: usize,
+        path: &str,
+        default_ns: Option<&str>,
+    ) -> Result<(), String> {
+        let (namespace, local) = split_expanded(name);
+        check_name(local, path)?;
+
+        let mut start_tag = format!("<{}", local);
+        let element_ns = match namespace {
+            Some(uri) => {
+                if default_ns != Some(uri) {
+                    start_tag.push_str(&format!(" xmlns=\"{}\"", escape(uri, true, path)?));
+                }
+                Some(uri)
+            }
+            None => {
+                // 无前缀的普通名称在默认命名空间之外
+                if default_ns.is_some_and(|uri| !uri.is_empty()) && !local.contains(':') {
+                    start_tag.push_str(" xmlns=\"\"");
+                    None
+                } else {
+                    default_ns
+                }
+            }
+        };
+
+        let map = match value {
+            Value::Object(map) => map,
+            Value::Null => {
+                start_tag.push_str("/>");
+                self.line(depth, &start_tag);
+                return Ok(());
+            }
+            scalar => {
+                let text = escape(&scalar_text(scalar), false, path)?;
+                self.line(depth, &format!("{}>{}</{}>", start_tag, text, local));
+                return Ok(());
+            }
+        };
+
+        let mut text = None;
+        let mut children = Vec::new();
+        for (key, child) in map {
+            let child_path = format!("{}.{}", path, key);
+            if self.is_attribute(key) {
+                let attribute = &key[self.options.attribute_prefix.len()..];
+                let attribute_value = match child {
+                    Value::Object(_) | Value::Array(_) => {
+                        return Err(format!(
+                            "{} 是属性，值必须是字符串、数字、布尔值或 null",
+                            child_path
+                        ));
+                    }
+                    scalar => escape(&scalar_text(scalar), true, &child_path)?,
+                };
+                let attribute = match split_expanded(attribute) {
+                    (Some(uri), attribute_local) => {
+                        check_name(attribute_local, &child_path)?;
+                        let prefix = format!("ns{}", self.prefix_counter);
+                        self.prefix_counter += 1;
+                        start_tag.push_str(&format!(
+                            " xmlns:{}=\"{}\"",
+                            prefix,
+                            escape(uri, true, &child_path)?
+                        ));
+                        format!("{}:{}", prefix, attribute_local)
+                    }
+                    (None, attribute) => {
+                        check_name(attribute, &child_path)?;
+                        attribute.to_string()
+                    }
+                };
+                start_tag.push_str(&format!(" {}=\"{}\"", attribute, attribute_value));
+            } else if *key == self.options.text_key {
+                match child {
+                    Value::Object(_) | Value::Array(_) => {
+                        return Err(format!(
+                            "{} 是文本内容，值必须是字符串、数字、布尔值或 null",
+                            child_path
+                        ));
+                    }
+                    Value::Null => {}
+                    scalar => text = Some(escape(&scalar_text(scalar), false, &child_path)?),
+                }
+            } else {
+                children.push((key, child, child_path));
+            }
+        }
+
+        match (children.is_empty(), text) {
+            (true, None) => {
+                start_tag.push_str("/>");
+                self.line(depth, &start_tag);
+            }
+            (true, Some(text)) => {
+                self.line(depth, &format!("{}>{}</{}>", start_tag, text, local));
+            }
+            (false, text) => {
+                start_tag.push('>');
+                self.line(depth, &start_tag);
+                if let Some(text) = text {
+                    self.line(depth + 1, &text);
+                }
+                for (key, child, child_path) in children {
+                    self.field(key, child, depth + 1, &child_path, element_ns)?;
+                }
+                self.line(depth, &format!("</{}>", local));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 拆分 `{uri}local` 形式的展开名称
+fn split_expanded(name: &str) -> (Option<&str>, &str) {
+    if let Some(rest) = name.strip_prefix('{') {
+        if let Some((uri, local)) = rest.split_once('}') {
+            return (Some(uri), local);
+        }
+    }
+    (None, name)
+}
+
+/// 检查是否为合法的 XML 名称
+fn check_name(name: &str, path: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(first) => {
+            (first.is_alphabetic() || first == '_' || first == ':')
+                && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} 处的名称 \"{}\" 不是合法的 XML 名称",
+            path, name
+        ))
+    }
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// 转义文本或属性值，拒绝 XML 1.0 不允许的字符
+fn escape(text: &str, attribute: bool, path: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if attribute => out.push_str("&quot;"),
+            '\n' if attribute => out.push_str("&#10;"),
+            '\r' => out.push_str("&#13;"),
+            '\t' if attribute => out.push_str("&#9;"),
+            '\t' | '\n' => out.push(c),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {
+                return Err(format!(
+                    "{} 包含 XML 1.0 不允许的字符 U+{:04X}",
+                    path, c as u32
+                ));
+            }
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SOAP: &str = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <m:GetPriceResponse xmlns:m="https://example.com/prices">
+      <m:Price currency="EUR">1.90</m:Price>
+      <m:Item>apple</m:Item>
+      <m:Item>pear</m:Item>
+    </m:GetPriceResponse>
+  </soap:Body>
+</soap:Envelope>"#;
+
+    fn to_json(input: &str, options: &XmlToJsonOptions) -> Value {
+        match xml_to_json(input, options) {
+            ConversionResult::Success { output, .. } => serde_json::from_str(&output).unwrap(),
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn to_xml(input: &str, options: &JsonToXmlOptions) -> String {
+        match json_to_xml(input, options) {
+            ConversionResult::Success { output, .. } => output,
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn compact() -> JsonToXmlOptions {
+        JsonToXmlOptions {
+            declaration: false,
+            indent: 0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_soap_with_prefixes() {
+        let value = to_json(SOAP, &XmlToJsonOptions::default());
+        assert_eq!(
+            value,
+            json!({
+                "soap:Envelope": {
+                    "@xmlns:soap": "http://schemas.xmlsoap.org/soap/envelope/",
+                    "soap:Body": {
+                        "m:GetPriceResponse": {
+                            "@xmlns:m": "https://example.com/prices",
+                            "m:Price": {"@currency": "EUR", "#text": "1.90"},
+                            "m:Item": ["apple", "pear"]
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_namespace_strip_and_expand() {
+        let options = XmlToJsonOptions {
+            namespaces: XmlNamespaceHandling::Strip,
+            ..Default::default()
+        };
+        let value = to_json(SOAP, &options);
+        assert_eq!(
+            value["Envelope"]["Body"]["GetPriceResponse"]["Item"],
+            json!(["apple", "pear"])
+        );
+        assert!(value["Envelope"].get("@xmlns:soap").is_none());
+
+        let options = XmlToJsonOptions {
+            namespaces: XmlNamespaceHandling::Expand,
+            ..Default::default()
+        };
+        let value = to_json(r#"<a xmlns="urn:x"><b>1</b></a>"#, &options);
+        assert_eq!(value, json!({"{urn:x}a": {"{urn:x}b": "1"}}));
+    }
+
+    #[test]
+    fn test_custom_conventions_and_arrays() {
+        let options = XmlToJsonOptions {
+            attribute_prefix: "-".to_string(),
+            text_key: "$".to_string(),
+            array_detection: XmlArrayDetection::Always,
+            infer_types: true,
+            ..Default::default()
+        };
+        let value = to_json(r#"<r><n id="7">true</n><empty/></r>"#, &options);
+        assert_eq!(
+            value,
+            json!({"r": {"n": [{"-id": 7, "$": true}], "empty": [null]}})
+        );
+
+        let options = XmlToJsonOptions {
+            force_array: vec!["item".to_string()],
+            ..Default::default()
+        };
+        let value = to_json("<list><item>a</item></list>", &options);
+        assert_eq!(value, json!({"list": {"item": ["a"]}}));
+    }
+
+    #[test]
+    fn test_xml_error_has_position() {
+        match xml_to_json("<a>\n  <b></c>\n</a>", &XmlToJsonOptions::default()) {
+            ConversionResult::Error { message, line, .. } => {
+                assert!(message.starts_with("XML 解析失败"));
+                assert_eq!(line, Some(2));
+            }
+            _ => panic!("Expected Error"),
+        }
+    }
+
+    #[test]
+    fn test_dtd_rejected() {
+        let input = r#"<!DOCTYPE a [<!ENTITY x "y">]><a>&x;</a>"#;
+        assert!(matches!(
+            xml_to_json(input, &XmlToJsonOptions::default()),
+            ConversionResult::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn test_json_to_xml_basic() {
+        let xml = to_xml(
+            r##"{"order":{"@id":"42","customer":"A & B","items":[{"@sku":"x"},{"#text":"y"}],"note":null}}"##,
+            &JsonToXmlOptions::default(),
+        );
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<order id=\"42\">\n  <customer>A &amp; B</customer>\n  <items sku=\"x\"/>\n  <items>y</items>\n  <note/>\n</order>\n"
+        );
+    }
+
+    #[test]
+    fn test_json_to_xml_wraps_root() {
+        assert_eq!(
+            to_xml(r#"{"a":1,"b":2}"#, &compact()),
+            "<root><a>1</a><b>2</b></root>"
+        );
+        assert_eq!(
+            to_xml("[1,[2,3]]", &compact()),
+            "<root><item>1</item><item><item>2</item><item>3</item></item></root>"
+        );
+        assert_eq!(to_xml(r#""x<y""#, &compact()), "<root>x&lt;y</root>");
+    }
+
+    #[test]
+    fn test_json_to_xml_namespaces() {
+        let xml = to_xml(
+            r#"{"{urn:a}doc":{"{urn:a}child":1,"plain":2,"@{urn:b}attr":"v"}}"#,
+            &compact(),
+        );
+        assert_eq!(
+            xml,
+            "<doc xmlns=\"urn:a\" xmlns:ns0=\"urn:b\" ns0:attr=\"v\"><plain xmlns=\"\">2</plain><child>1</child></doc>"
+        );
+
+        let options = XmlToJsonOptions {
+            namespaces: XmlNamespaceHandling::Expand,
+            ..Default::default()
+        };
+        let value = to_json(&xml, &options);
+        assert_eq!(value["{urn:a}doc"]["@{urn:b}attr"], json!("v"));
+        assert_eq!(value["{urn:a}doc"]["plain"], json!("2"));
+    }
+
+    #[test]
+    fn test_json_to_xml_errors() {
+        for (input, expected) in [
+            (r#"{"a":{"1bad":1}}"#, "$.a.1bad"),
+            (r#"{"a":{"@x":{"y":1}}}"#, "$.a.@x"),
+            (r#"{"a":"\u0001"}"#, "U+0001"),
+        ] {
+            match json_to_xml(input, &JsonToXmlOptions::default()) {
+                ConversionResult::Error { message, .. } => {
+                    assert!(message.contains(expected), "{}", message)
+                }
+                _ => panic!("Expected Error for {}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn test_xml_json_round_trip() {
+        let value = to_json(SOAP, &XmlToJsonOptions::default());
+        let xml = to_xml(&value.to_string(), &JsonToXmlOptions::default());
+        assert_eq!(to_json(&xml, &XmlToJsonOptions::default()), value);
+    }
+}
@@ -0,0 +1,628 @@
+//! YAML 转换服务
+//! JSON 与 YAML 双向转换，支持块/流风格、引号策略以及锚点、标签、多文档流等 YAML 特性
+
+use crate::models::conversion::{
+    ConversionResult, JsonToYamlOptions, MultiDocumentHandling, TagHandling, YamlQuoting,
+    YamlStyle, YamlToJsonOptions,
+};
+use crate::services::json_formatter::pretty_print;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+use std::time::Instant;
+
+/// 输入最大允许大小：5 MB
+const MAX_INPUT_SIZE: usize = 5 * 1024 * 1024;
+
+/// 在 YAML 中有特殊含义、作为纯量开头时必须加引号的字符
+const INDICATOR_CHARS: &[char] = &[
+    '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%', '@', '`',
+];
+
+/// 会被 YAML 解析器识别为非字符串类型的纯量 (含 YAML 1.1 的布尔写法和合并键 `<<`)
+const RESERVED_SCALARS: &[&str] = &[
+    "null", "~", "true", "false", "yes", "no", "on", "off", "y", "n", ".inf", "-.inf", "+.inf",
+    ".nan", "<<",
+];
+
+/// 带引号的 `<<` 是普通字符串，但 serde_yaml 合并时不区分引号；
+/// 解析前在其中插入该私用区字符作为标记，转换为 JSON 后再移除
+const MERGE_KEY_MARKER: char = '\u{F8FF}';
+
+/// 将 JSON 转换为 YAML
+///
+/// # Arguments
+/// * `input` - JSON 字符串
+/// * `options` - 输出风格、引号策略与缩进
+///
+/// # Returns
+/// 转换结果；JSON 解析失败时错误包含行列位置
+pub fn json_to_yaml(input: &str, options: &JsonToYamlOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    if options.indent != 2 && options.indent != 4 {
+        return ConversionResult::error(format!(
+            "不支持的缩进值 {}，仅支持 2 或 4 个空格",
+            options.indent
+        ));
+    }
+
+    let value = match serde_json::from_str::<Value>(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return ConversionResult::Error {
+                message: format!("JSON 解析失败: {}", e),
+                line: Some(e.line()),
+                column: Some(e.column()),
+            };
+        }
+    };
+
+    let emitter = YamlEmitter {
+        quoting: options.quoting,
+        indent: options.indent as usize,
+    };
+    let mut output = String::new();
+    match options.style {
+        YamlStyle::Flow => output.push_str(&emitter.flow(&value)),
+        YamlStyle::Block if is_nonempty_container(&value) => emitter.block(&value, 0, &mut output),
+        YamlStyle::Block => output.push_str(&emitter.flow(&value)),
+    }
+    if !output.ends_with('\n') {
+        output.push('\n');
+    }
+
+    ConversionResult::Success {
+        size: output.len(),
+        output,
+        processing_time_ms: start.elapsed().as_millis() as u64,
+        warnings: Vec::new(),
+    }
+}
+
+/// 将 YAML 转换为 JSON
+///
+/// # Arguments
+/// * `input` - YAML 字符串 (可包含多个文档)
+/// * `options` - 多文档、标签、合并键的处理方式与输出缩进
+///
+/// # Returns
+/// 转换结果；YAML 解析失败时错误包含行列位置
+pub fn yaml_to_json(input: &str, options: &YamlToJsonOptions) -> ConversionResult {
+    let start = Instant::now();
+
+    if let Err(message) = check_input(input) {
+        return ConversionResult::error(message);
+    }
+
+    let marked = options.merge_keys && !input.contains(MERGE_KEY_MARKER);
+    let source = if marked {
+        mark_quoted_merge_keys(input)
+    } else {
+        input.to_string()
+    };
+
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(&source) {
+        match serde_yaml::Value::deserialize(document) {
+            Ok(value) => documents.push(value),
+            Err(e) => return yaml_error(&e),
+        }
+    }
+
+    if documents.len() > 1 && options.multi_document == MultiDocumentHandling::Error {
+        return ConversionResult::error(format!(
+            "输入包含 {} 个 YAML 文档，当前设置只允许单个文档",
+            documents.len()
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    let mut converted = Vec::with_capacity(documents.len());
+    for (index, mut document) in documents.into_iter().enumerate() {
+        if options.merge_keys {
+            if let Err(e) = document.apply_merge() {
+                return ConversionResult::error(format!(
+                    "第 {} 个文档合并键无效: {}",
+                    index + 1,
+                    e
+                ));
+            }
+        }
+
+        match yaml_value_to_json(document, options.tags, "$", &mut warnings) {
+            Ok(value) => converted.push(value),
+            Err(message) => return ConversionResult::error(message),
+        }
+
+        if options.multi_document == MultiDocumentHandling::First {
+            break;
+        }
+    }
+
+    let mut value = match (options.multi_document, converted.len()) {
+        (_, 0) => Value::Null,
+        (MultiDocumentHandling::Array, n) if n > 1 => Value::Array(converted),
+        _ => converted.swap_remove(0),
+    };
+    if marked {
+        value = unmark_merge_keys(value);
+        for warning in &mut warnings {
+            *warning = warning.replace(MERGE_KEY_MARKER, "");
+        }
+    }
+
+    match pretty_print(&value, options.indent) {
+        Ok(mut output) => {
+            output.push('\n');
+            ConversionResult::Success {
+                size: output.len(),
+                output,
+                processing_time_ms: start.elapsed().as_millis() as u64,
+                warnings,
+            }
+        }
+        Err(message) => ConversionResult::error(message),
+    }
+}
+
+/// YAML 输出器
+struct YamlEmitter {
+    quoting: YamlQuoting,
+    indent: usize,
+}
+
+impl YamlEmitter {
+    /// 以块风格输出非空容器，每行以 `level` 级缩进开头
+    fn block(&self, value: &Value, level: usize, out: &mut String) {
+        let pad = " ".repeat(level * self.indent);
+
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    out.push_str(&pad);
+                    out.push_str(&self.string(key));
+                    out.push(':');
+                    if is_nonempty_container(child) {
+                        out.push('\n');
+                        self.block(child, level + 1, out);
+                    } else {
+                        out.push(' ');
+                        out.push_str(&self.flow(child));
+                        out.push('\n');
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    out.push_str(&pad);
+                    out.push('-');
+                    if is_nonempty_container(item) {
+                        // 子容器的第一行与 "- " 同行，其余行对齐到下一级缩进
+                        let mut child = String::new();
+                        self.block(item, level + 1, &mut child);
+                        out.push_str(&" ".repeat(self.indent - 1));
+                        out.push_str(&child[(level + 1) * self.indent..]);
+                    } else {
+                        out.push(' ');
+                        out.push_str(&self.flow(item));
+                        out.push('\n');
+                    }
+                }
+            }
+            scalar => {
+                out.push_str(&pad);
+                out.push_str(&self.flow(scalar));
+                out.push('\n');
+            }
+        }
+    }
+
+    /// 以流风格输出任意值 (单行)
+    fn flow(&self, value: &Value) -> String {
+        match value {
+            Value::Null => "null".to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => self.string(s),
+            Value::Array(items) => {
+                let items: Vec<String> = items.iter().map(|v| self.flow(v)).collect();
+                format!("[{}]", items.join(", "))
+            }
+            Value::Object(map) => {
+                let entries: Vec<String> = map
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", self.string(k), self.flow(v)))
+                    .collect();
+                format!("{{{}}}", entries.join(", "))
+            }
+        }
+    }
+
+    /// 按引号策略输出字符串
+    fn string(&self, s: &str) -> String {
+        let has_control = s.chars().any(|c| c.is_control());
+        match self.quoting {
+            YamlQuoting::Auto if is_plain_safe(s) => s.to_string(),
+            YamlQuoting::Auto | YamlQuoting::Single if !has_control => {
+                format!("'{}'", s.replace('\'', "''"))
+            }
+            // JSON 字符串转义与 YAML 双引号转义兼容
+            _ => Value::String(s.to_string()).to_string(),
+        }
+    }
+}
+
+/// 判断字符串能否不加引号输出且仍被解析为同一个字符串
+fn is_plain_safe(s: &str) -> bool {
+    let Some(first) = s.chars().next() else {
+        return false;
+    };
+
+    if first.is_whitespace() || s.ends_with(char::is_whitespace) {
+        return false;
+    }
+
+    // 以数字或符号开头的字符串可能被识别为数字、日期等类型
+    if INDICATOR_CHARS.contains(&first) || first.is_ascii_digit() || first == '+' || first == '.' {
+        return false;
+    }
+
+    if s.chars()
+        .any(|c| c.is_control() || matches!(c, ',' | '[' | ']' | '{' | '}'))
+        || s.contains(": ")
+        || s.contains(" #")
+        || s.ends_with(':')
+    {
+        return false;
+    }
+
+    !RESERVED_SCALARS.contains(&s.to_ascii_lowercase().as_str())
+}
+
+fn is_nonempty_container(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+/// 将 YAML 值转换为 JSON 值
+///
+/// `path` 用于在错误和警告中标明位置
+fn yaml_value_to_json(
+    value: serde_yaml::Value,
+    tags: TagHandling,
+    path: &str,
+    warnings: &mut Vec<String>,
+) -> Result<Value, String> {
+    Ok(match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::from(i)
+            } else if let Some(u) = n.as_u64() {
+                Value::from(u)
+            } else {
+                let f = n.as_f64().unwrap_or(f64::NAN);
+                Number::from_f64(f)
+                    .map(Value::Number)
+                    .ok_or_else(|| format!("{} 处的数值 {} 无法用 JSON 表示", path, n))?
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    yaml_value_to_json(item, tags, &format!("{}[{}]", path, i), warnings)
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_yaml::Value::Mapping(mapping) => {
+            let mut map = Map::new();
+            for (key, child) in mapping {
+                let key = yaml_key_to_string(key, path)?;
+                let child_path = format!("{}.{}", path, key);
+                map.insert(key, yaml_value_to_json(child, tags, &child_path, warnings)?);
+            }
+            Value::Object(map)
+        }
+        serde_yaml::Value::Tagged(tagged) => {
+            let tag = tagged.tag.to_string();
+            let inner = yaml_value_to_json(tagged.value, tags, path, warnings)?;
+            match tags {
+                TagHandling::Drop => {
+                    warnings.push(format!("已丢弃 {} 处的标签 {}", path, tag));
+                    inner
+                }
+                TagHandling::Wrap => {
+                    let mut map = Map::new();
+                    map.insert(tag, inner);
+                    Value::Object(map)
+                }
+                TagHandling::Error => {
+                    return Err(format!("{} 处包含 YAML 标签 {}，JSON 无法表示", path, tag));
+                }
+            }
+        }
+    })
+}
+
+/// 在带引号的 `'<<'`/`"<<"` 中插入标记，使其不被当作合并键
+///
+/// 标记只会出现在字符串内容中 (引号包围的 `<<` 也可能位于其他字符串或块纯量内)，
+/// 转换后由 [`unmark_merge_keys`] 从所有字符串中移除，因此不会改变任何值
+fn mark_quoted_merge_keys(input: &str) -> String {
+    let marked = format!("{}<<", MERGE_KEY_MARKER);
+    input
+        .replace("'<<'", &format!("'{}'", marked))
+        .replace("\"<<\"", &format!("\"{}\"", marked))
+}
+
+/// 移除 [`mark_quoted_merge_keys`] 插入的标记
+fn unmark_merge_keys(value: Value) -> Value {
+    let unmark = |s: String| {
+        if s.contains(MERGE_KEY_MARKER) {
+            s.replace(MERGE_KEY_MARKER, "")
+        } else {
+            s
+        }
+    };
+    match value {
+        Value::String(s) => Value::String(unmark(s)),
+        Value::Array(items) => Value::Array(items.into_iter().map(unmark_merge_keys).collect()),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, child)| (unmark(key), unmark_merge_keys(child)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// JSON 对象的键只能是字符串，标量键转换为字符串，复合键报错
+fn yaml_key_to_string(key: serde_yaml::Value, path: &str) -> Result<String, String> {
+    match key {
+        serde_yaml::Value::String(s) => Ok(s),
+        serde_yaml::Value::Number(n) => Ok(n.to_string()),
+        serde_yaml::Value::Bool(b) => Ok(b.to_string()),
+        serde_yaml::Value::Null => Ok("null".to_string()),
+        serde_yaml::Value::Tagged(tagged) => yaml_key_to_string(tagged.value, path),
+        _ => Err(format!("{} 处的映射使用了复合键，JSON 无法表示", path)),
+    }
+}
+
+fn yaml_error(error: &serde_yaml::Error) -> ConversionResult {
+    let location = error.location();
+    ConversionResult::Error {
+        message: format!("YAML 解析失败: {}", error),
+        line: location.as_ref().map(|l| l.line()),
+        column: location.as_ref().map(|l| l.column()),
+    }
+}
+
+fn check_input(input: &str) -> Result<(), String> {
+    if input.len() > MAX_INPUT_SIZE {
+        return Err(format!(
+            "输入大小 ({:.2} MB) 超过最大限制 5 MB",
+            input.len() as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if input.trim().is_empty() {
+        return Err("输入为空，请提供有效的内容".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_yaml(input: &str, options: &JsonToYamlOptions) -> String {
+        match json_to_yaml(input, options) {
+            ConversionResult::Success { output, .. } => output,
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    fn to_json(input: &str, options: &YamlToJsonOptions) -> (Value, Vec<String>) {
+        match yaml_to_json(input, options) {
+            ConversionResult::Success {
+                output, warnings, ..
+            } => (serde_json::from_str(&output).unwrap(), warnings),
+            ConversionResult::Error { message, .. } => panic!("Expected Success: {}", message),
+        }
+    }
+
+    #[test]
+    fn test_block_style() {
+        let yaml = to_yaml(
+            r#"{"name":"app","ports":[80,443],"env":{"debug":true},"tags":[]}"#,
+            &JsonToYamlOptions::default(),
+        );
+        assert_eq!(
+            yaml,
+            "env:\n  debug: true\nname: app\nports:\n  - 80\n  - 443\ntags: []\n"
+        );
+    }
+
+    #[test]
+    fn test_objects_in_sequence() {
+        let yaml = to_yaml(r#"[{"a":1,"b":2},[1,2]]"#, &JsonToYamlOptions::default());
+        assert_eq!(yaml, "- a: 1\n  b: 2\n- - 1\n  - 2\n");
+    }
+
+    #[test]
+    fn test_flow_style() {
+        let options = JsonToYamlOptions {
+            style: YamlStyle::Flow,
+            ..Default::default()
+        };
+        let yaml = to_yaml(r#"{"a":[1,"x"],"b":null}"#, &options);
+        assert_eq!(yaml, "{a: [1, x], b: null}\n");
+    }
+
+    #[test]
+    fn test_auto_quoting_ambiguous_strings() {
+        let yaml = to_yaml(
+            r#"["yes","123","","a: b","plain text","it's"]"#,
+            &JsonToYamlOptions::default(),
+        );
+        assert_eq!(
+            yaml,
+            "- 'yes'\n- '123'\n- ''\n- 'a: b'\n- plain text\n- it's\n"
+        );
+    }
+
+    #[test]
+    fn test_double_quoting_with_escapes() {
+        let options = JsonToYamlOptions {
+            quoting: YamlQuoting::Double,
+            ..Default::default()
+        };
+        let yaml = to_yaml(r#"{"k":"line1\nline2"}"#, &options);
+        assert_eq!(yaml, "\"k\": \"line1\\nline2\"\n");
+    }
+
+    #[test]
+    fn test_json_yaml_round_trip() {
+        let input = json!({
+            "name": "svc",
+            "replicas": 3,
+            "ratio": 0.5,
+            "labels": {"app": "web", "tier": "1"},
+            "containers": [{"image": "nginx:1.25", "args": ["-c", "# not a comment"]}],
+            "empty": {},
+            "nothing": null
+        });
+
+        for indent in [2, 4] {
+            for quoting in [YamlQuoting::Auto, YamlQuoting::Single, YamlQuoting::Double] {
+                for style in [YamlStyle::Block, YamlStyle::Flow] {
+                    let options = JsonToYamlOptions {
+                        style,
+                        quoting,
+                        indent,
+                    };
+                    let yaml = to_yaml(&input.to_string(), &options);
+                    let (back, _) = to_json(&yaml, &YamlToJsonOptions::default());
+                    assert_eq!(back, input, "round trip failed for:\n{}", yaml);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_key_literal_round_trip() {
+        let input = json!({"<<": {"x": 1}, "y": "<<", "z": "<<a"});
+
+        for style in [YamlStyle::Block, YamlStyle::Flow] {
+            let options = JsonToYamlOptions {
+                style,
+                ..Default::default()
+            };
+            let yaml = to_yaml(&input.to_string(), &options);
+            assert!(yaml.contains("'<<'"), "merge key not quoted:\n{}", yaml);
+            let (back, _) = to_json(&yaml, &YamlToJsonOptions::default());
+            assert_eq!(back, input, "round trip failed for:\n{}", yaml);
+        }
+
+        // 不带引号的 << 仍然是合并键
+        let yaml = "base: &b {x: 1}\nm:\n  <<: *b\n  \"<<\": lit\n  s: 'a ''<<'' b'\n";
+        let (value, _) = to_json(yaml, &YamlToJsonOptions::default());
+        assert_eq!(value["m"], json!({"x": 1, "<<": "lit", "s": "a '<<' b"}));
+    }
+
+    #[test]
+    fn test_yaml_anchors_and_merge_keys() {
+        let input = "base: &base\n  image: nginx\n  port: 80\nweb:\n  <<: *base\n  port: 8080\nalias: *base\n";
+        let (value, _) = to_json(input, &YamlToJsonOptions::default());
+
+        assert_eq!(value["web"], json!({"image": "nginx", "port": 8080}));
+        assert_eq!(value["alias"]["image"], "nginx");
+    }
+
+    #[test]
+    fn test_yaml_multi_document() {
+        let input = "a: 1\n---\nb: 2\n";
+
+        let (value, _) = to_json(input, &YamlToJsonOptions::default());
+        assert_eq!(value, json!([{"a": 1}, {"b": 2}]));
+
+        let first = YamlToJsonOptions {
+            multi_document: MultiDocumentHandling::First,
+            ..Default::default()
+        };
+        assert_eq!(to_json(input, &first).0, json!({"a": 1}));
+
+        let strict = YamlToJsonOptions {
+            multi_document: MultiDocumentHandling::Error,
+            ..Default::default()
+        };
+        assert!(matches!(
+            yaml_to_json(input, &strict),
+            ConversionResult::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn test_yaml_tags() {
+        let input = "value: !secret abc\n";
+
+        let (dropped, warnings) = to_json(input, &YamlToJsonOptions::default());
+        assert_eq!(dropped, json!({"value": "abc"}));
+        assert_eq!(warnings.len(), 1);
+
+        let wrap = YamlToJsonOptions {
+            tags: TagHandling::Wrap,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_json(input, &wrap).0,
+            json!({"value": {"!secret": "abc"}})
+        );
+    }
+
+    #[test]
+    fn test_yaml_non_string_keys() {
+        let (value, _) = to_json("1: one\ntrue: yes\n", &YamlToJsonOptions::default());
+        assert_eq!(value, json!({"1": "one", "true": "yes"}));
+    }
+
+    #[test]
+    fn test_yaml_error_has_position() {
+        let result = yaml_to_json("a: 1\nb: [1, 2\n", &YamlToJsonOptions::default());
+        if let ConversionResult::Error { line, column, .. } = result {
+            assert!(line.is_some());
+            assert!(column.is_some());
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+
+    #[test]
+    fn test_yaml_infinity_rejected() {
+        let result = yaml_to_json("x: .inf\n", &YamlToJsonOptions::default());
+        assert!(matches!(result, ConversionResult::Error { .. }));
+    }
+
+    #[test]
+    fn test_invalid_json_has_position() {
+        let result = json_to_yaml("{\n  \"a\": }", &JsonToYamlOptions::default());
+        if let ConversionResult::Error { line, .. } = result {
+            assert_eq!(line, Some(2));
+        } else {
+            panic!("Expected Error result");
+        }
+    }
+}
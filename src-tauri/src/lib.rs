@@ -1,18 +1,59 @@
-mod models;
-mod services;
+use json_tool_core::{models, services};
 
-use models::formatting::{FormattingOptions, FormattingResult};
-use models::validation::ValidationResult;
+use models::formatting::{EscapeOptions, FormattingOptions, FormattingResult};
+use models::validation::{DuplicateKeyPolicy, ValidationOptions, ValidationResult};
 use models::log_entry::{LogEntry, LogStatistics, OperationType, OperationResult};
-use services::{json_formatter, json_parser, file_io, logger};
+use models::operation::OperationProgress;
+use models::document::{ChildrenPage, DocumentInfo, NodeSummary};
+use models::ndjson::{NdjsonOutputMode, NdjsonValidationResult};
+use models::sequence::{SequenceFormat, SequenceValidationResult};
+use models::binary::{BinaryDecodeResult, BinaryFormat, BinaryTextEncoding, ProtobufJsonOptions};
+use models::transform::{
+    Base64DecodeOptions, ExpandOptions, FlattenOptions, JwtDecodeResult, PercentDecodeOptions,
+    TransformResult,
+};
+use models::analysis::{AnalysisOptions, AnalysisResult};
+use models::lint::LintConfigFile;
+use models::batch::{BatchFileResult, BatchOptions, BatchReport};
+use models::file::{
+    EncodingOptions, FileSnapshot, FileTypeOptions, FileWriteResult, TextEncoding, WatchOptions,
+    WriteOptions,
+};
+use models::conversion::{
+    ConversionResult, CsvToJsonOptions, JsonToCsvOptions, JsonToTomlOptions, JsonToXmlOptions,
+    JsonToYamlOptions, TomlToJsonOptions, XmlToJsonOptions, YamlToJsonOptions,
+};
+use services::operation::{OperationContext, OperationRegistry};
+use services::document_store::DocumentStore;
+use services::file_watcher::FileWatcherRegistry;
+use services::{
+    batch, binary_codec, csv_converter, embedded_json, file_io, flatten, json_analyzer,
+    json_formatter, json_parser, json_sequence, linter, logger, ndjson, protobuf_codec,
+    toml_converter, transform, xml_converter, yaml_converter,
+};
 use serde::Serialize;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::path::PathBuf;
-use tauri::Manager;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 操作进度事件名称
+const PROGRESS_EVENT: &str = "operation-progress";
+
+/// 文件变化事件名称
+const FILE_CHANGED_EVENT: &str = "file-changed";
 
 /// 全局日志管理器
 static LOGGER: OnceLock<logger::Logger> = OnceLock::new();
 
+/// 全局运行中操作注册表
+static OPERATIONS: OnceLock<OperationRegistry> = OnceLock::new();
+
+/// 全局大文档存储
+static DOCUMENTS: OnceLock<DocumentStore> = OnceLock::new();
+
+/// 全局文件监视注册表
+static WATCHERS: OnceLock<FileWatcherRegistry> = OnceLock::new();
+
 /// 获取日志管理器实例
 fn get_logger() -> &'static logger::Logger {
     LOGGER.get().expect("Logger not initialized")
@@ -28,16 +69,72 @@ fn init_logger(app_data_dir: PathBuf) -> Result<(), String> {
     Ok(())
 }
 
+/// 获取操作注册表实例
+fn get_operations() -> &'static OperationRegistry {
+    OPERATIONS.get_or_init(OperationRegistry::new)
+}
+
+/// 获取文档存储实例
+fn get_documents() -> &'static DocumentStore {
+    DOCUMENTS.get_or_init(DocumentStore::new)
+}
+
+/// 获取文件监视注册表实例
+fn get_watchers() -> &'static FileWatcherRegistry {
+    WATCHERS.get_or_init(FileWatcherRegistry::new)
+}
+
+/// 注册一个可取消的操作，并创建向前端发送进度事件的上下文
+///
+/// 前端可以自行指定 `operation_id`，以便在命令返回前调用 `cancel_operation`；
+/// 未指定时自动生成，并通过首个 0% 进度事件告知前端
+fn start_operation(
+    app: &AppHandle,
+    operation: OperationType,
+    operation_id: Option<String>,
+) -> (String, OperationContext) {
+    let (operation_id, token) = get_operations().register(operation_id);
+
+    let emit = {
+        let app = app.clone();
+        let operation_id = operation_id.clone();
+        move |percent: u8| {
+            let progress = OperationProgress {
+                operation_id: operation_id.clone(),
+                operation,
+                percent,
+            };
+            if let Err(e) = app.emit(PROGRESS_EVENT, progress) {
+                eprintln!("发送进度事件失败: {}", e);
+            }
+        }
+    };
+    emit(0);
+
+    (operation_id, OperationContext::new(token, Some(Arc::new(emit))))
+}
+
 /// Tauri command: 验证 JSON 字符串
 #[tauri::command]
-async fn validate_json(input: String) -> Result<ValidationResult, String> {
+async fn validate_json(
+    app: AppHandle,
+    input: String,
+    options: Option<ValidationOptions>,
+    operation_id: Option<String>,
+) -> Result<ValidationResult, String> {
     let input_size = input.len();
+    let options = options.unwrap_or_default();
     let start = std::time::Instant::now();
+    let (operation_id, context) = start_operation(&app, OperationType::Validate, operation_id);
 
     // 在异步任务中执行 JSON 解析，避免阻塞 UI
-    let result = tokio::task::spawn_blocking(move || json_parser::validate_json(&input))
-        .await
-        .map_err(|e| format!("Task execution error: {}", e))?;
+    let result = tokio::task::spawn_blocking(move || {
+        json_parser::validate_json_with_context(&input, &options, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
 
     let processing_time = start.elapsed().as_millis() as u64;
 
@@ -47,6 +144,7 @@ async fn validate_json(input: String) -> Result<ValidationResult, String> {
         ValidationResult::Error { message, .. } => {
             (OperationResult::Error, Some(message.clone()))
         }
+        ValidationResult::Cancelled => (OperationResult::Cancelled, None),
     };
 
     if let Err(e) = get_logger().log_operation(
@@ -65,16 +163,23 @@ async fn validate_json(input: String) -> Result<ValidationResult, String> {
 /// Tauri command: 格式化 JSON 字符串
 #[tauri::command]
 async fn format_json(
+    app: AppHandle,
     input: String,
     options: FormattingOptions,
+    operation_id: Option<String>,
 ) -> Result<FormattingResult, String> {
     let input_size = input.len();
     let start = std::time::Instant::now();
+    let (operation_id, context) = start_operation(&app, OperationType::Format, operation_id);
 
     // 在异步任务中执行 JSON 格式化，避免阻塞 UI
-    let result = tokio::task::spawn_blocking(move || json_formatter::format_json(&input, &options))
-        .await
-        .map_err(|e| format!("Task execution error: {}", e))?;
+    let result = tokio::task::spawn_blocking(move || {
+        json_formatter::format_json_with_context(&input, &options, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
 
     let processing_time = start.elapsed().as_millis() as u64;
 
@@ -84,6 +189,7 @@ async fn format_json(
         FormattingResult::Error { message, .. } => {
             (OperationResult::Error, Some(message.clone()))
         }
+        FormattingResult::Cancelled => (OperationResult::Cancelled, None),
     };
 
     if let Err(e) = get_logger().log_operation(
@@ -101,14 +207,30 @@ async fn format_json(
 
 /// Tauri command: 压缩 JSON 字符串
 #[tauri::command]
-async fn minify_json(input: String) -> Result<FormattingResult, String> {
+async fn minify_json(
+    app: AppHandle,
+    input: String,
+    options: Option<EscapeOptions>,
+    operation_id: Option<String>,
+) -> Result<FormattingResult, String> {
     let input_size = input.len();
+    let escape = options.unwrap_or_default();
     let start = std::time::Instant::now();
+    let (operation_id, context) = start_operation(&app, OperationType::Minify, operation_id);
 
     // 在异步任务中执行 JSON 压缩，避免阻塞 UI
-    let result = tokio::task::spawn_blocking(move || json_formatter::minify_json(&input))
-        .await
-        .map_err(|e| format!("Task execution error: {}", e))?;
+    let result = tokio::task::spawn_blocking(move || {
+        json_formatter::minify_json_with_context(
+            &input,
+            &escape,
+            DuplicateKeyPolicy::default(),
+            &context,
+        )
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
 
     let processing_time = start.elapsed().as_millis() as u64;
 
@@ -118,6 +240,7 @@ async fn minify_json(input: String) -> Result<FormattingResult, String> {
         FormattingResult::Error { message, .. } => {
             (OperationResult::Error, Some(message.clone()))
         }
+        FormattingResult::Cancelled => (OperationResult::Cancelled, None),
     };
 
     if let Err(e) = get_logger().log_operation(
@@ -133,22 +256,798 @@ async fn minify_json(input: String) -> Result<FormattingResult, String> {
     Ok(result)
 }
 
+/// 记录一次操作日志，失败时仅打印错误
+fn record_operation(
+    operation: OperationType,
+    result: OperationResult,
+    input_size: usize,
+    processing_time_ms: u64,
+    error_message: Option<String>,
+) {
+    if let Err(e) = get_logger().log_operation(
+        operation,
+        result,
+        input_size,
+        processing_time_ms,
+        error_message,
+    ) {
+        eprintln!("记录日志失败: {}", e);
+    }
+}
+
+/// Tauri command: 逐行验证 NDJSON 文本
+#[tauri::command]
+async fn validate_ndjson(
+    app: AppHandle,
+    input: String,
+    operation_id: Option<String>,
+) -> Result<NdjsonValidationResult, String> {
+    let input_size = input.len();
+    let start = std::time::Instant::now();
+    let (operation_id, context) = start_operation(&app, OperationType::Validate, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || ndjson::validate_ndjson(&input, &context))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
+
+    let processing_time = start.elapsed().as_millis() as u64;
+    match &result {
+        Ok(r) if r.errors.is_empty() => record_operation(
+            OperationType::Validate,
+            OperationResult::Success,
+            input_size,
+            processing_time,
+            None,
+        ),
+        Ok(r) => record_operation(
+            OperationType::Validate,
+            OperationResult::Error,
+            input_size,
+            processing_time,
+            Some(format!("NDJSON 中有 {} 行无效", r.errors.len())),
+        ),
+        Err(e) if e.is_cancelled() => record_operation(
+            OperationType::Validate,
+            OperationResult::Cancelled,
+            input_size,
+            processing_time,
+            None,
+        ),
+        Err(e) => record_operation(
+            OperationType::Validate,
+            OperationResult::Error,
+            input_size,
+            processing_time,
+            Some(e.to_string()),
+        ),
+    }
+
+    result.map_err(String::from)
+}
+
+/// Tauri command: 流式验证 NDJSON 文件
+#[tauri::command]
+async fn validate_ndjson_file(
+    app: AppHandle,
+    file_path: String,
+    operation_id: Option<String>,
+) -> Result<NdjsonValidationResult, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Validate, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        ndjson::validate_ndjson_file(&file_path, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 格式化 NDJSON 文本 (逐条美化、逐行压缩或转换为 JSON 数组)
+#[tauri::command]
+async fn format_ndjson(
+    app: AppHandle,
+    input: String,
+    options: FormattingOptions,
+    mode: NdjsonOutputMode,
+    operation_id: Option<String>,
+) -> Result<FormattingResult, String> {
+    let input_size = input.len();
+    let start = std::time::Instant::now();
+    let operation = match mode {
+        NdjsonOutputMode::Minify => OperationType::Minify,
+        _ => OperationType::Format,
+    };
+    let (operation_id, context) = start_operation(&app, operation, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        ndjson::format_ndjson(&input, &options, mode, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
+
+    let processing_time = start.elapsed().as_millis() as u64;
+    match &result {
+        FormattingResult::Success { .. } => {
+            record_operation(operation, OperationResult::Success, input_size, processing_time, None)
+        }
+        FormattingResult::Error { message } => record_operation(
+            operation,
+            OperationResult::Error,
+            input_size,
+            processing_time,
+            Some(message.clone()),
+        ),
+        FormattingResult::Cancelled => record_operation(
+            operation,
+            OperationResult::Cancelled,
+            input_size,
+            processing_time,
+            None,
+        ),
+    }
+
+    Ok(result)
+}
+
+/// Tauri command: 将 JSON 数组转换为 NDJSON
+#[tauri::command]
+async fn json_array_to_ndjson(input: String) -> Result<FormattingResult, String> {
+    tokio::task::spawn_blocking(move || ndjson::json_array_to_ndjson(&input))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 流式转换 NDJSON 文件，返回写入的记录数
+#[tauri::command]
+async fn convert_ndjson_file(
+    app: AppHandle,
+    input_path: String,
+    output_path: String,
+    mode: NdjsonOutputMode,
+    options: FormattingOptions,
+    operation_id: Option<String>,
+) -> Result<usize, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Format, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        ndjson::convert_ndjson_file(&input_path, &output_path, mode, &options, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 流式将 JSON 数组文件转换为 NDJSON 文件，返回写入的记录数
+#[tauri::command]
+async fn convert_array_file_to_ndjson(
+    app: AppHandle,
+    input_path: String,
+    output_path: String,
+    operation_id: Option<String>,
+) -> Result<usize, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Format, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        ndjson::convert_array_file_to_ndjson(&input_path, &output_path, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 验证多文档 JSON (首尾相接或 RFC 7464 序列)
+#[tauri::command]
+async fn validate_json_sequence(
+    app: AppHandle,
+    input: String,
+    format: Option<SequenceFormat>,
+    operation_id: Option<String>,
+) -> Result<SequenceValidationResult, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Validate, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        json_sequence::validate_sequence(&input, format, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 格式化多文档 JSON
+#[tauri::command]
+async fn format_json_sequence(
+    app: AppHandle,
+    input: String,
+    options: FormattingOptions,
+    format: Option<SequenceFormat>,
+    operation_id: Option<String>,
+) -> Result<FormattingResult, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Format, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || {
+        json_sequence::format_sequence(&input, &options, format, &context)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result
+}
+
+/// Tauri command: 将 JSON 转换为 YAML
+#[tauri::command]
+async fn json_to_yaml(
+    input: String,
+    options: Option<JsonToYamlOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || yaml_converter::json_to_yaml(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 YAML 转换为 JSON
+#[tauri::command]
+async fn yaml_to_json(
+    input: String,
+    options: Option<YamlToJsonOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || yaml_converter::yaml_to_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 JSON 转换为 TOML
+#[tauri::command]
+async fn json_to_toml(
+    input: String,
+    options: Option<JsonToTomlOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || toml_converter::json_to_toml(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 TOML 转换为 JSON
+#[tauri::command]
+async fn toml_to_json(
+    input: String,
+    options: Option<TomlToJsonOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || toml_converter::toml_to_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 JSON 对象数组转换为 CSV/TSV
+#[tauri::command]
+async fn json_to_csv(
+    input: String,
+    options: Option<JsonToCsvOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || csv_converter::json_to_csv(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 CSV/TSV 转换为 JSON
+#[tauri::command]
+async fn csv_to_json(
+    input: String,
+    options: Option<CsvToJsonOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || csv_converter::csv_to_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 JSON 转换为 XML
+#[tauri::command]
+async fn json_to_xml(
+    input: String,
+    options: Option<JsonToXmlOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || xml_converter::json_to_xml(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 XML 转换为 JSON
+#[tauri::command]
+async fn xml_to_json(
+    input: String,
+    options: Option<XmlToJsonOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || xml_converter::xml_to_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 解码 Base64/十六进制文本形式的 MessagePack/CBOR/BSON 数据
+#[tauri::command]
+async fn decode_binary(
+    input: String,
+    format: BinaryFormat,
+    encoding: Option<BinaryTextEncoding>,
+    indent: Option<u8>,
+) -> Result<BinaryDecodeResult, String> {
+    let encoding = encoding.unwrap_or_default();
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || {
+        binary_codec::decode_text(&input, encoding, format, indent)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 读取并解码 MessagePack/CBOR/BSON 文件
+#[tauri::command]
+async fn decode_binary_file(
+    file_path: String,
+    format: BinaryFormat,
+    indent: Option<u8>,
+) -> Result<BinaryDecodeResult, String> {
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || binary_codec::decode_file(&file_path, format, indent))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 JSON 编码为 MessagePack/CBOR/BSON，以 Base64/十六进制文本返回
+#[tauri::command]
+async fn encode_binary(
+    input: String,
+    format: BinaryFormat,
+    encoding: Option<BinaryTextEncoding>,
+) -> Result<ConversionResult, String> {
+    let encoding = encoding.unwrap_or_default();
+    tokio::task::spawn_blocking(move || binary_codec::encode_text(&input, format, encoding))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 列出 FileDescriptorSet 中定义的消息类型
+#[tauri::command]
+async fn list_protobuf_messages(descriptor_path: String) -> Result<Vec<String>, String> {
+    tokio::task::spawn_blocking(move || protobuf_codec::list_messages(&descriptor_path))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 解码 Base64/十六进制文本形式的 Protobuf 消息为 proto3 JSON
+#[tauri::command]
+async fn decode_protobuf(
+    input: String,
+    descriptor_path: String,
+    message_type: String,
+    encoding: Option<BinaryTextEncoding>,
+    options: Option<ProtobufJsonOptions>,
+) -> Result<BinaryDecodeResult, String> {
+    let encoding = encoding.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        protobuf_codec::decode_text(&input, encoding, &descriptor_path, &message_type, &options)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 读取并解码 Protobuf 二进制文件为 proto3 JSON
+#[tauri::command]
+async fn decode_protobuf_file(
+    file_path: String,
+    descriptor_path: String,
+    message_type: String,
+    options: Option<ProtobufJsonOptions>,
+) -> Result<BinaryDecodeResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        protobuf_codec::decode_file(&file_path, &descriptor_path, &message_type, &options)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 proto3 JSON 编码为 Protobuf 二进制，以 Base64/十六进制文本返回
+#[tauri::command]
+async fn encode_protobuf(
+    input: String,
+    descriptor_path: String,
+    message_type: String,
+    encoding: Option<BinaryTextEncoding>,
+) -> Result<ConversionResult, String> {
+    let encoding = encoding.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        protobuf_codec::encode_text(&input, &descriptor_path, &message_type, encoding)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 验证 JSON 是否符合 Protobuf 消息类型
+#[tauri::command]
+async fn validate_protobuf_json(
+    input: String,
+    descriptor_path: String,
+    message_type: String,
+) -> Result<ValidationResult, String> {
+    tokio::task::spawn_blocking(move || {
+        protobuf_codec::validate_json(&input, &descriptor_path, &message_type)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将嵌套 JSON 扁平化为路径键映射
+#[tauri::command]
+async fn flatten_json(
+    input: String,
+    options: Option<FlattenOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || flatten::flatten_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将路径键映射还原为嵌套 JSON
+#[tauri::command]
+async fn unflatten_json(
+    input: String,
+    options: Option<FlattenOptions>,
+) -> Result<ConversionResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || flatten::unflatten_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 展开值为 JSON 的字符串
+#[tauri::command]
+async fn expand_embedded_json(
+    input: String,
+    pointer: Option<String>,
+    options: Option<ExpandOptions>,
+) -> Result<TransformResult, String> {
+    let pointer = pointer.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || embedded_json::expand_text(&input, &pointer, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将子树序列化为 JSON 字符串
+#[tauri::command]
+async fn stringify_json_subtree(
+    input: String,
+    pointer: String,
+    indent: Option<u8>,
+) -> Result<TransformResult, String> {
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || embedded_json::stringify_text(&input, &pointer, indent))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 Base64/Base64URL 编码的字符串值替换为解码结果
+#[tauri::command]
+async fn decode_base64_strings(
+    input: String,
+    pointer: Option<String>,
+    options: Option<Base64DecodeOptions>,
+) -> Result<TransformResult, String> {
+    let pointer = pointer.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        transform::decode_base64_strings(&input, &pointer, &options)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将 URL 百分号编码的字符串值解码
+#[tauri::command]
+async fn percent_decode_strings(
+    input: String,
+    pointer: Option<String>,
+    options: Option<PercentDecodeOptions>,
+) -> Result<TransformResult, String> {
+    let pointer = pointer.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        transform::percent_decode_strings(&input, &pointer, &options)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将字符串值中字面的 `\uXXXX` 转义还原为字符
+#[tauri::command]
+async fn unescape_unicode(
+    input: String,
+    pointer: Option<String>,
+    indent: Option<u8>,
+) -> Result<TransformResult, String> {
+    let pointer = pointer.unwrap_or_default();
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || transform::unescape_unicode(&input, &pointer, indent))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 将非 ASCII 字符转义为 `\uXXXX` 输出
+#[tauri::command]
+async fn escape_unicode(input: String, indent: Option<u8>) -> Result<TransformResult, String> {
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || transform::escape_unicode(&input, indent))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 解码 JWT 的头部和载荷 (不验证签名)
+#[tauri::command]
+async fn decode_jwt(token: String, indent: Option<u8>) -> Result<JwtDecodeResult, String> {
+    let indent = indent.unwrap_or(2);
+    tokio::task::spawn_blocking(move || transform::decode_jwt(&token, indent))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 分析 JSON 的结构 (深度、类型分布、最大数组、最长字符串、重复键路径等)
+#[tauri::command]
+async fn analyze_json(
+    input: String,
+    options: Option<AnalysisOptions>,
+) -> Result<AnalysisResult, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || json_analyzer::analyze_json(&input, &options))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))
+}
+
+/// Tauri command: 从 JSON 文件所在目录向上查找项目检查配置 (`.jsonlint.json`)
+#[tauri::command]
+async fn find_lint_config(file_path: String) -> Result<Option<LintConfigFile>, String> {
+    tokio::task::spawn_blocking(move || linter::find_config(&file_path))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 监视已导入的文件
+///
+/// 文件内容变化或被删除时发送 `file-changed` 事件；启用自动验证时事件中包含重新验证的结果
+///
+/// # Returns
+/// 监视 ID，用于 `unwatch_file`
+#[tauri::command]
+async fn watch_file(
+    app: AppHandle,
+    file_path: String,
+    options: Option<WatchOptions>,
+) -> Result<String, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        let on_change = move |event| {
+            if let Err(e) = app.emit(FILE_CHANGED_EVENT, event) {
+                eprintln!("发送文件变化事件失败: {}", e);
+            }
+        };
+        get_watchers().watch(&file_path, options, Arc::new(on_change))
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 停止监视文件
+///
+/// 返回 `false` 表示监视不存在或已经停止
+#[tauri::command]
+async fn unwatch_file(watch_id: String) -> Result<bool, String> {
+    Ok(get_watchers().unwatch(&watch_id))
+}
+
+/// Tauri command: 批量处理文件夹中的 JSON 文件
+///
+/// 按已完成的文件数发送进度事件；单个文件的错误记录在报告中
+#[tauri::command]
+async fn batch_process(
+    app: AppHandle,
+    options: BatchOptions,
+    operation_id: Option<String>,
+) -> Result<BatchReport, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Batch, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || batch::batch_process(&options, &context))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    let result = result?;
+
+    // 记录日志
+    let (op_result, input_size, processing_time, error_msg) = match &result {
+        Ok(report) => {
+            let input_size = report
+                .files
+                .iter()
+                .map(|file| match file {
+                    BatchFileResult::Success { size, .. } => *size,
+                    BatchFileResult::Error { .. } => 0,
+                })
+                .sum();
+            let (op_result, error_msg) = if report.failed == 0 {
+                (OperationResult::Success, None)
+            } else {
+                (
+                    OperationResult::Error,
+                    Some(format!("{} 个文件处理失败", report.failed)),
+                )
+            };
+            (op_result, input_size, report.processing_time_ms, error_msg)
+        }
+        Err(error) if error.is_cancelled() => (OperationResult::Cancelled, 0, 0, None),
+        Err(error) => (OperationResult::Error, 0, 0, Some(error.to_string())),
+    };
+    if let Err(e) = get_logger().log_operation(
+        OperationType::Batch,
+        op_result,
+        input_size,
+        processing_time,
+        error_msg,
+    ) {
+        eprintln!("记录日志失败: {}", e);
+    }
+
+    result.map_err(String::from)
+}
+
+/// Tauri command: 取消正在运行的操作
+///
+/// 返回 `false` 表示操作不存在或已经结束
+#[tauri::command]
+async fn cancel_operation(operation_id: String) -> Result<bool, String> {
+    Ok(get_operations().cancel(&operation_id))
+}
+
+/// Tauri command: 将 JSON 文本加载到后端文档存储
+#[tauri::command]
+async fn open_document(
+    app: AppHandle,
+    content: String,
+    operation_id: Option<String>,
+) -> Result<DocumentInfo, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Load, operation_id);
+
+    let result = tokio::task::spawn_blocking(move || get_documents().open_text(&content, &context))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 将 JSON 文件加载到后端文档存储 (用于超大文件)
+#[tauri::command]
+async fn open_document_file(
+    app: AppHandle,
+    file_path: String,
+    operation_id: Option<String>,
+) -> Result<DocumentInfo, String> {
+    let (operation_id, context) = start_operation(&app, OperationType::Load, operation_id);
+
+    let result =
+        tokio::task::spawn_blocking(move || get_documents().open_file(&file_path, &context))
+            .await
+            .map_err(|e| format!("Task execution error: {}", e));
+    get_operations().finish(&operation_id);
+    result?.map_err(String::from)
+}
+
+/// Tauri command: 获取文档中某个节点的摘要
+#[tauri::command]
+async fn get_node(doc_id: String, pointer: String) -> Result<NodeSummary, String> {
+    tokio::task::spawn_blocking(move || get_documents().get_node(&doc_id, &pointer))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 分页获取文档节点的子节点
+#[tauri::command]
+async fn get_children(
+    doc_id: String,
+    pointer: String,
+    offset: usize,
+    limit: usize,
+) -> Result<ChildrenPage, String> {
+    // 首次访问大节点时需要遍历全部子节点建立索引
+    tokio::task::spawn_blocking(move || {
+        get_documents().get_children(&doc_id, &pointer, offset, limit)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 关闭文档并释放内存
+#[tauri::command]
+async fn close_document(doc_id: String) -> Result<bool, String> {
+    Ok(get_documents().close(&doc_id))
+}
+
+/// Tauri command: 展开已加载文档中值为 JSON 的字符串
+///
+/// 返回被替换的节点 (JSON Pointer)
+#[tauri::command]
+async fn expand_document_strings(
+    doc_id: String,
+    pointer: String,
+    options: Option<ExpandOptions>,
+) -> Result<Vec<String>, String> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        get_documents()
+            .modify(&doc_id, &pointer, |document| {
+                embedded_json::expand_strings(document, &pointer, &options)
+            })
+            .map(|(changed, _)| changed)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 将已加载文档中的节点序列化为 JSON 字符串
+#[tauri::command]
+async fn stringify_document_node(doc_id: String, pointer: String) -> Result<NodeSummary, String> {
+    tokio::task::spawn_blocking(move || {
+        get_documents()
+            .modify(&doc_id, &pointer, |document| {
+                embedded_json::stringify_node(document, &pointer)
+            })
+            .map(|(_, summary)| summary)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
 /// 文件读取结果
 #[derive(Debug, Serialize)]
 pub struct FileReadResult {
     pub content: String,
     pub file_name: String,
+    pub encoding: TextEncoding,
+    pub bom: bool,
+    pub warnings: Vec<String>,
+    pub snapshot: FileSnapshot,
 }
 
 /// Tauri command: 从文件导入 JSON
 #[tauri::command]
-async fn import_json_file(file_path: String) -> Result<FileReadResult, String> {
+async fn import_json_file(
+    file_path: String,
+    file_types: Option<FileTypeOptions>,
+) -> Result<FileReadResult, String> {
+    let file_types = file_types.unwrap_or_default();
     // 在异步任务中执行文件读取，避免阻塞 UI
     tokio::task::spawn_blocking(move || {
-        match file_io::read_json_file(&file_path) {
+        match file_io::read_json_file(&file_path, &file_types) {
             Ok(result) => Ok(FileReadResult {
                 content: result.content,
                 file_name: result.file_name,
+                encoding: result.encoding,
+                bom: result.bom,
+                warnings: result.warnings,
+                snapshot: result.snapshot,
             }),
             Err(e) => Err(e),
         }
@@ -158,14 +1057,63 @@ async fn import_json_file(file_path: String) -> Result<FileReadResult, String> {
 }
 
 /// Tauri command: 导出 JSON 到文件
+///
+/// 可选输出编码和 BOM (默认无 BOM 的 UTF-8)；原子写入，可选备份已有文件，
+/// 传入导入时的文件状态时，若文件已被其他程序修改则拒绝覆盖
 #[tauri::command]
-async fn export_json_file(file_path: String, content: String) -> Result<String, String> {
+async fn export_json_file(
+    file_path: String,
+    content: String,
+    file_types: Option<FileTypeOptions>,
+    encoding: Option<EncodingOptions>,
+    write: Option<WriteOptions>,
+) -> Result<FileWriteResult, String> {
+    let file_types = file_types.unwrap_or_default();
+    let encoding = encoding.unwrap_or_default();
+    let write = write.unwrap_or_default();
     // 在异步任务中执行文件写入，避免阻塞 UI
-    tokio::task::spawn_blocking(move || file_io::write_json_file(&file_path, &content))
+    tokio::task::spawn_blocking(move || {
+        file_io::write_json_file(&file_path, &content, &file_types, &encoding, &write)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 导出 CSV/TSV 文件
+#[tauri::command]
+async fn export_csv_file(file_path: String, content: String) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || file_io::write_csv_file(&file_path, &content))
         .await
         .map_err(|e| format!("Task execution error: {}", e))?
 }
 
+/// Tauri command: 将 JSON 编码为 MessagePack/CBOR/BSON 并导出为文件
+#[tauri::command]
+async fn export_binary_file(
+    file_path: String,
+    content: String,
+    format: BinaryFormat,
+) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || binary_codec::encode_file(&content, format, &file_path))
+        .await
+        .map_err(|e| format!("Task execution error: {}", e))?
+}
+
+/// Tauri command: 将 proto3 JSON 编码为 Protobuf 二进制并导出为文件
+#[tauri::command]
+async fn export_protobuf_file(
+    file_path: String,
+    content: String,
+    descriptor_path: String,
+    message_type: String,
+) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || {
+        protobuf_codec::encode_file(&content, &descriptor_path, &message_type, &file_path)
+    })
+    .await
+    .map_err(|e| format!("Task execution error: {}", e))?
+}
+
 /// Tauri command: 获取日志统计
 #[tauri::command]
 async fn get_log_statistics() -> Result<LogStatistics, String> {
@@ -238,8 +1186,57 @@ pub fn run() {
             validate_json,
             format_json,
             minify_json,
+            cancel_operation,
+            validate_ndjson,
+            validate_ndjson_file,
+            format_ndjson,
+            json_array_to_ndjson,
+            convert_ndjson_file,
+            convert_array_file_to_ndjson,
+            validate_json_sequence,
+            format_json_sequence,
+            json_to_yaml,
+            yaml_to_json,
+            json_to_toml,
+            toml_to_json,
+            json_to_csv,
+            csv_to_json,
+            json_to_xml,
+            xml_to_json,
+            decode_binary,
+            decode_binary_file,
+            encode_binary,
+            list_protobuf_messages,
+            decode_protobuf,
+            decode_protobuf_file,
+            encode_protobuf,
+            validate_protobuf_json,
+            flatten_json,
+            unflatten_json,
+            expand_embedded_json,
+            stringify_json_subtree,
+            decode_base64_strings,
+            percent_decode_strings,
+            unescape_unicode,
+            escape_unicode,
+            decode_jwt,
+            analyze_json,
+            find_lint_config,
+            watch_file,
+            unwatch_file,
+            batch_process,
+            open_document,
+            open_document_file,
+            get_node,
+            get_children,
+            close_document,
+            expand_document_strings,
+            stringify_document_node,
             import_json_file,
             export_json_file,
+            export_csv_file,
+            export_binary_file,
+            export_protobuf_file,
             get_log_statistics,
             get_recent_logs,
             clear_logs,
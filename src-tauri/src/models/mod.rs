@@ -1,3 +0,0 @@
-pub mod validation;
-pub mod formatting;
-pub mod log_entry;
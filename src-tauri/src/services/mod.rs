@@ -1,4 +0,0 @@
-pub mod json_parser;
-pub mod json_formatter;
-pub mod file_io;
-pub mod logger;
@@ -1,4 +1,4 @@
-import { useCallback, useEffect, useMemo, useState, lazy, Suspense } from 'react'
+import { useCallback, useEffect, useMemo, useRef, useState, lazy, Suspense } from 'react'
 import { JsonPanel } from './components/JsonPanel'
 import { Toolbar } from './components/Toolbar'
 import { LoadingOverlay } from './components/LoadingOverlay'
@@ -10,8 +10,10 @@ import { ErrorLocation } from './hooks/useErrorHighlight'
 import { usePreferences } from './hooks/usePreferences'
 import { jsonService } from './services/jsonService'
 import { fileService } from './services/fileService'
-import { isFormattingSuccess } from './types/formatting'
-import { isError, isSuccess } from './types/validation'
+import type { FileSnapshot } from './services/fileService'
+import { isFormattingCancelled, isFormattingSuccess } from './types/formatting'
+import type { AnalysisResult } from './types/analysis'
+import { isCancelled, isError, isSuccess } from './types/validation'
 import { extractErrorContext } from './utils/errorParser'
 import { calculateJsonMetrics } from './utils/metricsCalculator'
 import { migrateFromLocalStorage } from './utils/migration'
@@ -69,6 +71,9 @@ function App() {
   const [errorLocation, setErrorLocation] = useState<ErrorLocation | undefined>(undefined)
   const [loadingMessage, setLoadingMessage] = useState<string>()
   const [processingTimeMs, setProcessingTimeMs] = useState<number>()
+  // 正在运行的验证/格式化/压缩操作，用于匹配进度事件和取消
+  const operationIdRef = useRef<string>()
+  const [operationProgress, setOperationProgress] = useState<number>()
 
   // 剪贴板功能
   const {
@@ -96,6 +101,127 @@ function App() {
 
   // 注意: inputStats 和 outputStats 已移除,直接使用 inputMetrics 和 outputMetrics
 
+  // 输入的结构分析结果 (按需加载，输入变化后失效)
+  const [inputAnalysis, setInputAnalysis] = useState<AnalysisResult | undefined>()
+  // 导入文件时的状态，导出到同一文件时用于检测冲突
+  const [fileSnapshot, setFileSnapshot] = useState<FileSnapshot | undefined>()
+  const fileHashRef = useRef<string>()
+  fileHashRef.current = fileSnapshot?.hash
+  // 最近一次从文件加载 (或导出到该文件) 的内容，用于判断输入是否有未保存的编辑
+  const loadedContentRef = useRef<string>()
+  const inputJsonRef = useRef(inputJson)
+  inputJsonRef.current = inputJson
+
+  // 监视导入的文件：在磁盘上被修改时自动重新加载 (输入有未保存的编辑时只提示)，被删除时提示
+  const watchedPath = fileSnapshot?.path
+  useEffect(() => {
+    if (!watchedPath) {
+      return
+    }
+    let watchId: string | undefined
+    let disposed = false
+    const unlistenPromise = fileService.onFileChanged((event) => {
+      if (event.watch_id !== watchId) {
+        return
+      }
+      if (event.kind === 'removed') {
+        setErrorMessage('导入的文件已在磁盘上被删除')
+        return
+      }
+      // 本程序导出到同一文件时哈希与当前记录一致，不处理
+      if (event.snapshot?.hash === fileHashRef.current) {
+        return
+      }
+
+      const validation = event.validation
+      if (inputJsonRef.current !== loadedContentRef.current) {
+        const invalid =
+          validation && isError(validation) ? `(磁盘上的内容无效: ${validation.message})` : ''
+        setErrorMessage(`导入的文件已在磁盘上被修改，重新导入以加载最新内容${invalid}`)
+        return
+      }
+
+      fileService
+        .readJsonFile(event.path)
+        .then((result) => {
+          loadedContentRef.current = result.content
+          setInputJson(result.content)
+          setFileSnapshot(result.snapshot)
+          setErrorLocation(undefined)
+          if (validation && isError(validation)) {
+            setValidationStatus('error')
+            setErrorMessage(`已重新加载磁盘上修改的文件，内容无效: ${validation.message}`)
+            if (validation.line) {
+              setErrorLocation({ line: validation.line, column: validation.column })
+            }
+          } else {
+            setValidationStatus('idle')
+            setErrorMessage('')
+          }
+        })
+        .catch((error) => setErrorMessage(`重新加载文件失败: ${error}`))
+    })
+    fileService
+      .watchFile(watchedPath, { revalidate: true })
+      .then((id) => {
+        watchId = id
+        if (disposed) {
+          fileService.unwatchFile(id)
+        }
+      })
+      .catch((error) => console.error('监视文件失败:', error))
+
+    return () => {
+      disposed = true
+      unlistenPromise.then((unlisten) => unlisten())
+      if (watchId) {
+        fileService.unwatchFile(watchId)
+      }
+    }
+  }, [watchedPath])
+
+  useEffect(() => {
+    setInputAnalysis(undefined)
+  }, [inputJson])
+
+  // 订阅当前操作的进度
+  useEffect(() => {
+    const unlistenPromise = jsonService.onOperationProgress((progress) => {
+      if (progress.operation_id === operationIdRef.current) {
+        setOperationProgress(progress.percent)
+      }
+    })
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten())
+    }
+  }, [])
+
+  // 开始新操作，返回传给后端的操作 ID
+  const startOperation = () => {
+    const operationId = crypto.randomUUID()
+    operationIdRef.current = operationId
+    setOperationProgress(undefined)
+    return operationId
+  }
+
+  const finishOperation = (operationId: string) => {
+    if (operationIdRef.current === operationId) {
+      operationIdRef.current = undefined
+      setOperationProgress(undefined)
+    }
+  }
+
+  // 取消正在运行的操作，结果以 Cancelled 返回
+  const handleCancelOperation = useCallback(() => {
+    if (operationIdRef.current) {
+      jsonService.cancelOperation(operationIdRef.current)
+    }
+  }, [])
+
+  const handleAnalyze = useCallback(async () => {
+    setInputAnalysis(await jsonService.analyzeJson(inputJson))
+  }, [inputJson])
+
   // 验证函数
   const handleValidate = async () => {
     if (!inputJson.trim()) {
@@ -116,9 +242,10 @@ function App() {
     setErrorMessage('')
     setErrorLocation(undefined)
     setProcessingTimeMs(undefined)
+    const operationId = startOperation()
 
     try {
-      const result = await jsonService.validateJson(inputJson)
+      const result = await jsonService.validateJson(inputJson, operationId)
 
       if (isSuccess(result)) {
         // 验证成功,更新输出
@@ -131,7 +258,11 @@ function App() {
         })
         setErrorMessage('')
         setErrorLocation(undefined)
-              } else if (isError(result)) {
+      } else if (isCancelled(result)) {
+        // 操作被取消，不视为验证失败
+        setValidationStatus('idle')
+        setErrorMessage('操作已取消')
+      } else if (isError(result)) {
         // 验证失败
         setValidationStatus('error')
 
@@ -177,6 +308,7 @@ function App() {
         })
       }
     } finally {
+      finishOperation(operationId)
       setIsProcessing(false)
       setLoadingMessage(undefined)
     }
@@ -209,9 +341,10 @@ function App() {
     setErrorMessage('')
     setErrorLocation(undefined)
     setProcessingTimeMs(undefined)
+    const operationId = startOperation()
 
     try {
-      const result = await jsonService.formatJson(inputJson, formattingOptions)
+      const result = await jsonService.formatJson(inputJson, formattingOptions, operationId)
 
       if (isFormattingSuccess(result)) {
         setValidationStatus('success')
@@ -223,7 +356,10 @@ function App() {
         })
         setErrorMessage('')
         setErrorLocation(undefined)
-              } else {
+      } else if (isFormattingCancelled(result)) {
+        setValidationStatus('idle')
+        setErrorMessage('操作已取消')
+      } else {
         setValidationStatus('error')
         setErrorMessage(result.message)
 
@@ -247,6 +383,7 @@ function App() {
         })
       }
     } finally {
+      finishOperation(operationId)
       setIsProcessing(false)
       setLoadingMessage(undefined)
     }
@@ -271,9 +408,10 @@ function App() {
     setErrorMessage('')
     setErrorLocation(undefined)
     setProcessingTimeMs(undefined)
+    const operationId = startOperation()
 
     try {
-      const result = await jsonService.minifyJson(inputJson)
+      const result = await jsonService.minifyJson(inputJson, operationId)
 
       if (isFormattingSuccess(result)) {
         setValidationStatus('success')
@@ -285,7 +423,10 @@ function App() {
         })
         setErrorMessage('')
         setErrorLocation(undefined)
-              } else {
+      } else if (isFormattingCancelled(result)) {
+        setValidationStatus('idle')
+        setErrorMessage('操作已取消')
+      } else {
         setValidationStatus('error')
         setErrorMessage(result.message)
 
@@ -309,6 +450,7 @@ function App() {
         })
       }
     } finally {
+      finishOperation(operationId)
       setIsProcessing(false)
       setLoadingMessage(undefined)
     }
@@ -348,7 +490,9 @@ function App() {
 
       if (result) {
         // 用户选择了文件
+        loadedContentRef.current = result.content
         setInputJson(result.content)
+        setFileSnapshot(result.snapshot)
         setErrorMessage('')
         setErrorLocation(undefined)
         setValidationStatus('idle')
@@ -378,16 +522,21 @@ function App() {
 
     try {
       setIsProcessing(true)
-      const savedPath = await fileService.exportJsonFile(contentToExport, {
+      const saved = await fileService.exportJsonFile(contentToExport, {
         defaultFileName: 'formatted.json',
+        expected: fileSnapshot,
       })
 
-      if (savedPath) {
+      if (saved) {
         // 导出成功,可以显示成功消息
         // 暂时通过重置错误消息来表示成功
         setErrorMessage('')
+        if (fileSnapshot?.path === saved.snapshot.path) {
+          loadedContentRef.current = contentToExport
+          setFileSnapshot(saved.snapshot)
+        }
       }
-      // 如果 savedPath 为 null,表示用户取消了保存
+      // 如果 saved 为 null,表示用户取消了保存
     } catch (error) {
       setErrorMessage(`导出失败: ${error}`)
       setValidationStatus('error')
@@ -524,6 +673,8 @@ function App() {
             error={validationStatus === 'error' ? errorMessage : undefined}
             errorLocation={errorLocation}
             metrics={inputMetrics}
+            analysis={inputAnalysis}
+            onAnalyze={handleAnalyze}
             onCopy={handleCopyInput}
             copyState={inputCopyState}
             showEmptyState={true}
@@ -581,7 +732,12 @@ function App() {
       </Suspense>
 
       {/* 全局加载遮罩 */}
-      <LoadingOverlay isLoading={isProcessing && !!loadingMessage} message={loadingMessage} />
+      <LoadingOverlay
+        isLoading={isProcessing && !!loadingMessage}
+        message={loadingMessage}
+        progress={operationProgress}
+        onCancel={operationIdRef.current ? handleCancelOperation : undefined}
+      />
     </div>
   )
 }
@@ -4,6 +4,7 @@ import { CopyState } from '../hooks/useCopyToClipboard'
 import { EmptyState } from './EmptyState'
 import { MetricsDisplay } from './MetricsDisplay'
 import { JsonMetrics } from '../types/metrics'
+import type { AnalysisResult } from '../types/analysis'
 
 interface JsonPanelProps {
   title: string
@@ -18,6 +19,8 @@ interface JsonPanelProps {
   lineCount?: number // 已弃用，使用 metrics
   charCount?: number // 已弃用，使用 metrics
   metrics?: JsonMetrics // 新增：完整的指标对象
+  analysis?: AnalysisResult // 后端结构分析结果
+  onAnalyze?: () => void // 请求结构分析
   onCopy?: () => void
   copyState?: CopyState
   showEmptyState?: boolean
@@ -37,6 +40,8 @@ export function JsonPanel({
   lineCount, // 向后兼容
   charCount, // 向后兼容
   metrics,
+  analysis,
+  onAnalyze,
   onCopy,
   copyState = 'idle',
   showEmptyState = false,
@@ -91,7 +96,12 @@ export function JsonPanel({
         <div className="panel-header-right">
           {/* 显示指标（优先使用新的 metrics，向后兼容旧的 lineCount/charCount）*/}
           {metrics ? (
-            <MetricsDisplay metrics={metrics} mode="compact" />
+            <MetricsDisplay
+              metrics={metrics}
+              mode="compact"
+              analysis={analysis}
+              onAnalyze={onAnalyze}
+            />
           ) : (
             lineCount !== undefined && charCount !== undefined && (
               <div className="panel-meta">
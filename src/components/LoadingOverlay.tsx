@@ -1,14 +1,16 @@
 /**
  * LoadingOverlay 组件
- * 显示全局加载遮罩和加载消息
+ * 显示全局加载遮罩和加载消息，可显示进度并取消操作
  */
 
 interface LoadingOverlayProps {
   isLoading: boolean
   message?: string
+  progress?: number      // 完成百分比 (0-100)，未知时不显示
+  onCancel?: () => void  // 提供时显示取消按钮
 }
 
-export function LoadingOverlay({ isLoading, message }: LoadingOverlayProps) {
+export function LoadingOverlay({ isLoading, message, progress, onCancel }: LoadingOverlayProps) {
   if (!isLoading) return null
 
   return (
@@ -16,6 +18,16 @@ export function LoadingOverlay({ isLoading, message }: LoadingOverlayProps) {
       <div className="loading-content">
         <div className="loading-spinner" />
         {message && <p className="loading-message">{message}</p>}
+        {progress !== undefined && (
+          <div className="loading-progress">
+            <div className="loading-progress-bar" style={{ width: `${progress}%` }} />
+          </div>
+        )}
+        {onCancel && (
+          <button className="btn-secondary" onClick={onCancel}>
+            取消
+          </button>
+        )}
       </div>
     </div>
   )
@@ -132,7 +132,7 @@ export function LogViewer({ isOpen, onClose }: LogViewerProps) {
             logs.map((log, index) => (
               <div
                 key={index}
-                className={`log-entry ${log.result === 'error' ? 'log-error' : 'log-success'}`}
+                className={`log-entry log-${log.result}`}
               >
                 <div className="log-row-main">
                   <div className="log-timestamp">
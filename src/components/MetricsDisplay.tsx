@@ -1,10 +1,13 @@
 import { useState } from 'react'
 import { JsonMetrics } from '../types/metrics'
+import type { AnalysisResult } from '../types/analysis'
 import { formatBytes, formatNumber } from '../utils/metricsCalculator'
 
 interface MetricsDisplayProps {
   metrics: JsonMetrics
   mode?: 'compact' | 'detailed' // 显示模式
+  analysis?: AnalysisResult // 后端结构分析结果
+  onAnalyze?: () => void // 请求结构分析
 }
 
 /**
@@ -12,11 +15,13 @@ interface MetricsDisplayProps {
  *
  * 提供两种显示模式：
  * - compact: 紧凑模式，只显示行数和大小
- * - detailed: 详细模式，显示所有可用指标
+ * - detailed: 详细模式，显示所有可用指标；提供 onAnalyze 时可加载结构分析
  */
 export function MetricsDisplay({
   metrics,
   mode = 'compact',
+  analysis,
+  onAnalyze,
 }: MetricsDisplayProps) {
   const [isExpanded, setIsExpanded] = useState(false)
 
@@ -108,6 +113,83 @@ export function MetricsDisplay({
         </div>
       )}
 
+      {/* 结构分析（后端）*/}
+      {metrics.depth > 0 && onAnalyze && !analysis && (
+        <div className="metrics-section">
+          <button className="metrics-expand-btn" onClick={onAnalyze} title="分析负载构成">
+            结构分析
+          </button>
+        </div>
+      )}
+      {analysis?.type === 'Error' && (
+        <div className="metrics-section">
+          <h4 className="metrics-section-title">结构分析</h4>
+          <span className="metric-value">{analysis.message}</span>
+        </div>
+      )}
+      {analysis?.type === 'Success' && (
+        <div className="metrics-section">
+          <h4 className="metrics-section-title">结构分析</h4>
+          <div className="metrics-grid">
+            <div className="metric-row">
+              <span className="metric-label">节点数:</span>
+              <span className="metric-value">{formatNumber(analysis.node_count)}</span>
+            </div>
+            <div className="metric-row">
+              <span className="metric-label">类型分布:</span>
+              <span className="metric-value">
+                {Object.entries(analysis.type_counts)
+                  .filter(([, count]) => count > 0)
+                  .map(([type, count]) => `${type} ${formatNumber(count)}`)
+                  .join(' / ')}
+              </span>
+            </div>
+            <div className="metric-row">
+              <span className="metric-label">不同键名:</span>
+              <span className="metric-value">
+                {formatNumber(analysis.distinct_keys)} / {formatNumber(analysis.total_keys)}
+              </span>
+            </div>
+            <div className="metric-row">
+              <span className="metric-label">键名占用:</span>
+              <span className="metric-value">{formatBytes(analysis.key_bytes)}</span>
+            </div>
+            <div className="metric-row">
+              <span className="metric-label">估算内存:</span>
+              <span className="metric-value">
+                {formatBytes(analysis.estimated_memory_bytes)}
+              </span>
+            </div>
+            {analysis.largest_arrays.slice(0, 3).map((stat) => (
+              <div className="metric-row" key={`array-${stat.pointer}`}>
+                <span className="metric-label" title={stat.pointer}>
+                  数组 {stat.pointer || '/'}:
+                </span>
+                <span className="metric-value">{formatNumber(stat.length)} 项</span>
+              </div>
+            ))}
+            {analysis.longest_strings.slice(0, 3).map((stat) => (
+              <div className="metric-row" key={`string-${stat.pointer}`}>
+                <span className="metric-label" title={stat.preview}>
+                  字符串 {stat.pointer || '/'}:
+                </span>
+                <span className="metric-value">{formatBytes(stat.bytes)}</span>
+              </div>
+            ))}
+            {analysis.repeated_paths.slice(0, 3).map((stat) => (
+              <div className="metric-row" key={`path-${stat.path}`}>
+                <span className="metric-label" title={stat.path}>
+                  重复键 {stat.path}:
+                </span>
+                <span className="metric-value">
+                  {formatNumber(stat.count)} 次 / {formatBytes(stat.key_bytes)}
+                </span>
+              </div>
+            ))}
+          </div>
+        </div>
+      )}
+
       {/* 性能信息（如果有）*/}
       {metrics.processingTime !== undefined && (
         <div className="metrics-section">
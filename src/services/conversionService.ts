@@ -0,0 +1,229 @@
+/**
+ * 格式转换服务
+ * JSON 与其他数据格式之间的互相转换
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  BinaryDecodeResult,
+  BinaryFormat,
+  BinaryTextEncoding,
+  ProtobufJsonOptions,
+} from '../types/binary'
+import type {
+  ConversionResult,
+  CsvToJsonOptions,
+  JsonToCsvOptions,
+  JsonToTomlOptions,
+  JsonToXmlOptions,
+  JsonToYamlOptions,
+  TomlToJsonOptions,
+  XmlToJsonOptions,
+  YamlToJsonOptions,
+} from '../types/conversion'
+import type { ValidationResult } from '../types/validation'
+
+export class ConversionService {
+  /**
+   * 调用转换命令，将 IPC 错误包装为转换错误
+   */
+  private async convert(
+    command: string,
+    args: Record<string, unknown>
+  ): Promise<ConversionResult> {
+    try {
+      return await invoke<ConversionResult>(command, args)
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` }
+    }
+  }
+
+  /**
+   * JSON 转 YAML
+   */
+  async jsonToYaml(input: string, options?: JsonToYamlOptions): Promise<ConversionResult> {
+    return this.convert('json_to_yaml', { input, options })
+  }
+
+  /**
+   * YAML 转 JSON
+   */
+  async yamlToJson(input: string, options?: YamlToJsonOptions): Promise<ConversionResult> {
+    return this.convert('yaml_to_json', { input, options })
+  }
+
+  /**
+   * JSON 转 TOML
+   */
+  async jsonToToml(input: string, options?: JsonToTomlOptions): Promise<ConversionResult> {
+    return this.convert('json_to_toml', { input, options })
+  }
+
+  /**
+   * TOML 转 JSON
+   */
+  async tomlToJson(input: string, options?: TomlToJsonOptions): Promise<ConversionResult> {
+    return this.convert('toml_to_json', { input, options })
+  }
+
+  /**
+   * JSON 对象数组转 CSV/TSV
+   */
+  async jsonToCsv(input: string, options?: JsonToCsvOptions): Promise<ConversionResult> {
+    return this.convert('json_to_csv', { input, options })
+  }
+
+  /**
+   * CSV/TSV 转 JSON
+   */
+  async csvToJson(input: string, options?: CsvToJsonOptions): Promise<ConversionResult> {
+    return this.convert('csv_to_json', { input, options })
+  }
+
+  /**
+   * JSON 转 XML
+   */
+  async jsonToXml(input: string, options?: JsonToXmlOptions): Promise<ConversionResult> {
+    return this.convert('json_to_xml', { input, options })
+  }
+
+  /**
+   * XML 转 JSON
+   */
+  async xmlToJson(input: string, options?: XmlToJsonOptions): Promise<ConversionResult> {
+    return this.convert('xml_to_json', { input, options })
+  }
+
+  /**
+   * 解码 Base64/十六进制形式的二进制数据为 JSON
+   */
+  async decodeBinary(
+    input: string,
+    format: BinaryFormat,
+    encoding?: BinaryTextEncoding,
+    indent?: number
+  ): Promise<BinaryDecodeResult> {
+    return this.decode('decode_binary', { input, format, encoding, indent })
+  }
+
+  /**
+   * 解码二进制文件为 JSON
+   */
+  async decodeBinaryFile(
+    filePath: string,
+    format: BinaryFormat,
+    indent?: number
+  ): Promise<BinaryDecodeResult> {
+    return this.decode('decode_binary_file', { filePath, format, indent })
+  }
+
+  /**
+   * 将 JSON 编码为二进制格式，输出 Base64/十六进制文本
+   */
+  async encodeBinary(
+    input: string,
+    format: BinaryFormat,
+    encoding?: BinaryTextEncoding
+  ): Promise<ConversionResult> {
+    return this.convert('encode_binary', { input, format, encoding })
+  }
+
+  /**
+   * 列出描述符集 (FileDescriptorSet) 中的消息类型
+   */
+  async listProtobufMessages(descriptorPath: string): Promise<string[]> {
+    return invoke<string[]>('list_protobuf_messages', { descriptorPath })
+  }
+
+  /**
+   * 解码 Base64/十六进制形式的 Protobuf 消息为 proto3 JSON
+   */
+  async decodeProtobuf(
+    input: string,
+    descriptorPath: string,
+    messageType: string,
+    encoding?: BinaryTextEncoding,
+    options?: ProtobufJsonOptions
+  ): Promise<BinaryDecodeResult> {
+    return this.decode('decode_protobuf', {
+      input,
+      descriptorPath,
+      messageType,
+      encoding,
+      options,
+    })
+  }
+
+  /**
+   * 解码 Protobuf 二进制文件为 proto3 JSON
+   */
+  async decodeProtobufFile(
+    filePath: string,
+    descriptorPath: string,
+    messageType: string,
+    options?: ProtobufJsonOptions
+  ): Promise<BinaryDecodeResult> {
+    return this.decode('decode_protobuf_file', {
+      filePath,
+      descriptorPath,
+      messageType,
+      options,
+    })
+  }
+
+  /**
+   * 将 proto3 JSON 编码为 Protobuf 二进制，输出 Base64/十六进制文本
+   */
+  async encodeProtobuf(
+    input: string,
+    descriptorPath: string,
+    messageType: string,
+    encoding?: BinaryTextEncoding
+  ): Promise<ConversionResult> {
+    return this.convert('encode_protobuf', {
+      input,
+      descriptorPath,
+      messageType,
+      encoding,
+    })
+  }
+
+  /**
+   * 验证 JSON 是否符合 Protobuf 消息类型
+   */
+  async validateProtobufJson(
+    input: string,
+    descriptorPath: string,
+    messageType: string
+  ): Promise<ValidationResult> {
+    try {
+      return await invoke<ValidationResult>('validate_protobuf_json', {
+        input,
+        descriptorPath,
+        messageType,
+      })
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` }
+    }
+  }
+
+  /**
+   * 调用二进制解码命令，将 IPC 错误包装为解码错误
+   */
+  private async decode(
+    command: string,
+    args: Record<string, unknown>
+  ): Promise<BinaryDecodeResult> {
+    try {
+      return await invoke<BinaryDecodeResult>(command, args)
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` }
+    }
+  }
+}
+
+// 导出单例
+export const conversionService = new ConversionService()
@@ -0,0 +1,93 @@
+/**
+ * 大文档服务
+ * 文档保存在后端，前端按需分页加载子节点
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ChildrenPage, DocumentInfo, NodeSummary } from '../types/document'
+import type { ExpandOptions } from '../types/transform'
+
+/**
+ * 默认每页加载的子节点数量
+ */
+export const DEFAULT_PAGE_SIZE = 200
+
+export class DocumentService {
+  /**
+   * 加载 JSON 文本到后端
+   * @param content - JSON 文本
+   * @param operationId - 可选的操作 ID，用于取消加载
+   */
+  async openDocument(content: string, operationId?: string): Promise<DocumentInfo> {
+    return invoke<DocumentInfo>('open_document', { content, operationId })
+  }
+
+  /**
+   * 直接从磁盘加载 JSON 文件 (适用于超大文件)
+   * @param filePath - 文件路径
+   * @param operationId - 可选的操作 ID，用于取消加载
+   */
+  async openDocumentFile(filePath: string, operationId?: string): Promise<DocumentInfo> {
+    return invoke<DocumentInfo>('open_document_file', { filePath, operationId })
+  }
+
+  /**
+   * 获取节点摘要
+   * @param docId - 文档 ID
+   * @param pointer - JSON Pointer，根节点为空字符串
+   */
+  async getNode(docId: string, pointer: string): Promise<NodeSummary> {
+    return invoke<NodeSummary>('get_node', { docId, pointer })
+  }
+
+  /**
+   * 分页获取子节点
+   * @param docId - 文档 ID
+   * @param pointer - 父节点的 JSON Pointer
+   * @param offset - 起始偏移
+   * @param limit - 本页数量
+   */
+  async getChildren(
+    docId: string,
+    pointer: string,
+    offset = 0,
+    limit = DEFAULT_PAGE_SIZE
+  ): Promise<ChildrenPage> {
+    return invoke<ChildrenPage>('get_children', { docId, pointer, offset, limit })
+  }
+
+  /**
+   * 关闭文档并释放后端内存
+   * @param docId - 文档 ID
+   */
+  async closeDocument(docId: string): Promise<boolean> {
+    return invoke<boolean>('close_document', { docId })
+  }
+
+  /**
+   * 展开文档中值为 JSON 的字符串
+   * @param docId - 文档 ID
+   * @param pointer - 只处理该节点下的值，根节点为空字符串
+   * @returns 被替换的节点 (JSON Pointer)
+   */
+  async expandStrings(
+    docId: string,
+    pointer: string,
+    options?: ExpandOptions
+  ): Promise<string[]> {
+    return invoke<string[]>('expand_document_strings', { docId, pointer, options })
+  }
+
+  /**
+   * 将节点序列化为 JSON 字符串
+   * @param docId - 文档 ID
+   * @param pointer - 要序列化的节点
+   * @returns 修改后节点的摘要
+   */
+  async stringifyNode(docId: string, pointer: string): Promise<NodeSummary> {
+    return invoke<NodeSummary>('stringify_document_node', { docId, pointer })
+  }
+}
+
+// 导出单例
+export const documentService = new DocumentService()
@@ -4,7 +4,49 @@
  */
 
 import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 import { open, save } from '@tauri-apps/plugin-dialog'
+import type { BatchOptions, BatchReport } from '../types/batch'
+import type { BinaryFormat } from '../types/binary'
+import type { ValidationOptions, ValidationResult } from '../types/validation'
+
+/**
+ * 文本文件的字符编码
+ */
+export type TextEncoding =
+  | 'utf-8'
+  | 'utf-16le'
+  | 'utf-16be'
+  | 'utf-32le'
+  | 'utf-32be'
+
+/**
+ * 导出文件的编码选项
+ */
+export interface EncodingOptions {
+  encoding?: TextEncoding  // 输出编码，默认 utf-8
+  bom?: boolean            // 是否写入字节顺序标记，默认 false
+}
+
+/**
+ * 文件在某一时刻的状态，导出时用于检测文件是否已被其他程序修改
+ */
+export interface FileSnapshot {
+  path: string                // 规范化路径
+  modified_ms: number | null  // 修改时间 (Unix 毫秒时间戳)
+  size: number
+  hash: string                // 内容哈希 (64 位 FNV-1a)，可跨会话比较
+}
+
+/**
+ * 文件写入结果
+ */
+export interface FileWriteResult {
+  path: string                // 实际写入的文件路径
+  backup_path: string | null  // 备份文件路径
+  snapshot: FileSnapshot      // 写入后的文件状态，供下一次导出检测冲突
+  warnings: string[]          // 导出警告，如扩展名不在允许列表中
+}
 
 /**
  * 文件读取结果
@@ -12,6 +54,50 @@ import { open, save } from '@tauri-apps/plugin-dialog'
 export interface FileReadResult {
   content: string
   file_name: string
+  encoding: TextEncoding  // 识别出的文件编码，内容已转码为 UTF-8
+  bom: boolean            // 原文件是否带有 BOM
+  warnings: string[]      // 导入警告，如扩展名不在允许列表中但内容是 JSON
+  snapshot: FileSnapshot  // 导入时的文件状态
+}
+
+/**
+ * 默认允许导入导出的文件扩展名 (与后端内置列表一致)
+ */
+export const JSON_EXTENSIONS = [
+  'json',
+  'jsonl',
+  'ndjson',
+  'geojson',
+  'json5',
+  'jsonc',
+  'har',
+  'ipynb',
+  'webmanifest',
+]
+
+/**
+ * 文件变化事件名称
+ */
+export const FILE_CHANGED_EVENT = 'file-changed'
+
+/**
+ * 文件监视选项
+ */
+export interface WatchOptions {
+  revalidate?: boolean            // 文件变化后自动重新读取并验证
+  validation?: ValidationOptions  // 重新验证使用的验证选项
+  file_types?: { extensions: string[] }
+}
+
+/**
+ * 文件变化事件
+ */
+export interface FileChangeEvent {
+  watch_id: string
+  path: string
+  kind: 'modified' | 'removed'
+  snapshot: FileSnapshot | null          // 变化后的文件状态，文件被删除时为 null
+  validation: ValidationResult | null    // 自动重新验证的结果
 }
 
 /**
@@ -29,6 +115,12 @@ export interface ImportOptions {
    * @default '选择 JSON 文件'
    */
   title?: string
+
+  /**
+   * 后端接受的文件扩展名，其他扩展名 (或无扩展名) 的文件按内容判断
+   * @default JSON_EXTENSIONS
+   */
+  extensions?: string[]
 }
 
 /**
@@ -52,6 +144,29 @@ export interface ExportOptions {
    * @default '保存 JSON 文件'
    */
   title?: string
+
+  /**
+   * 后端接受的文件扩展名 (仅 JSON 导出)
+   * @default JSON_EXTENSIONS
+   */
+  extensions?: string[]
+
+  /**
+   * 输出编码及 BOM (仅 JSON 导出)
+   * @default { encoding: 'utf-8', bom: false }
+   */
+  encoding?: EncodingOptions
+
+  /**
+   * 覆盖已有文件前将其复制为 `<文件名>.bak` (仅 JSON 导出)
+   * @default false
+   */
+  backup?: boolean
+
+  /**
+   * 导入时记录的文件状态；导出到同一文件时，若文件已被其他程序修改则拒绝覆盖 (仅 JSON 导出)
+   */
+  expected?: FileSnapshot
 }
 
 /**
@@ -73,7 +188,11 @@ class FileService {
       filters: options?.filters || [
         {
           name: 'JSON',
-          extensions: ['json'],
+          extensions: options?.extensions || JSON_EXTENSIONS,
+        },
+        {
+          name: '所有文件',
+          extensions: ['*'],
         },
       ],
     })
@@ -87,6 +206,7 @@ class FileService {
     try {
       const result = await invoke<FileReadResult>('import_json_file', {
         filePath,
+        fileTypes: options?.extensions && { extensions: options.extensions },
       })
       return result
     } catch (error) {
@@ -94,18 +214,34 @@ class FileService {
     }
   }
 
+  /**
+   * 按路径读取 JSON 文件 (不显示对话框)，用于重新加载已导入的文件
+   *
+   * @throws 读取失败时抛出错误
+   */
+  async readJsonFile(filePath: string, extensions?: string[]): Promise<FileReadResult> {
+    try {
+      return await invoke<FileReadResult>('import_json_file', {
+        filePath,
+        fileTypes: extensions && { extensions },
+      })
+    } catch (error) {
+      throw new Error(`文件读取失败: ${error}`)
+    }
+  }
+
   /**
    * 导出 JSON 文件
    *
    * @param content - 要导出的 JSON 内容
    * @param options - 导出选项
-   * @returns 保存的文件路径,如果用户取消则返回 null
-   * @throws 写入失败时抛出错误
+   * @returns 写入结果 (路径、备份路径和写入后的文件状态),如果用户取消则返回 null
+   * @throws 写入失败或文件已被其他程序修改时抛出错误
    */
   async exportJsonFile(
     content: string,
     options?: ExportOptions
-  ): Promise<string | null> {
+  ): Promise<FileWriteResult | null> {
     // 显示文件保存对话框
     const filePath = await save({
       title: options?.title || '保存 JSON 文件',
@@ -125,15 +261,191 @@ class FileService {
 
     // 调用 Tauri 命令写入文件
     try {
-      const savedPath = await invoke<string>('export_json_file', {
+      const result = await invoke<FileWriteResult>('export_json_file', {
         filePath,
         content,
+        fileTypes: options?.extensions && { extensions: options.extensions },
+        encoding: options?.encoding,
+        write: { backup: options?.backup, expected: options?.expected },
+      })
+      return result
+    } catch (error) {
+      throw new Error(`文件保存失败: ${error}`)
+    }
+  }
+
+  /**
+   * 导出 CSV/TSV 文件
+   *
+   * @param content - 要导出的 CSV/TSV 内容
+   * @param options - 导出选项
+   * @returns 保存的文件路径,如果用户取消则返回 null
+   * @throws 写入失败时抛出错误
+   */
+  async exportCsvFile(
+    content: string,
+    options?: ExportOptions
+  ): Promise<string | null> {
+    const filePath = await save({
+      title: options?.title || '保存表格文件',
+      defaultPath: options?.defaultFileName || 'output.csv',
+      filters: options?.filters || [
+        {
+          name: 'CSV',
+          extensions: ['csv'],
+        },
+        {
+          name: 'TSV',
+          extensions: ['tsv'],
+        },
+      ],
+    })
+
+    if (!filePath) {
+      return null
+    }
+
+    try {
+      const savedPath = await invoke<string>('export_csv_file', {
+        filePath,
+        content,
+      })
+      return savedPath
+    } catch (error) {
+      throw new Error(`文件保存失败: ${error}`)
+    }
+  }
+
+  /**
+   * 将 JSON 编码为二进制格式并导出
+   * @returns 保存的文件路径，用户取消返回 null
+   */
+  async exportBinaryFile(
+    content: string,
+    format: BinaryFormat,
+    options?: ExportOptions
+  ): Promise<string | null> {
+    const filePath = await save({
+      title: options?.title || '保存二进制文件',
+      defaultPath: options?.defaultFileName || `output.${format}`,
+      filters: options?.filters || [
+        {
+          name: 'MessagePack',
+          extensions: ['msgpack', 'mpk'],
+        },
+        {
+          name: 'CBOR',
+          extensions: ['cbor'],
+        },
+        {
+          name: 'BSON',
+          extensions: ['bson'],
+        },
+      ],
+    })
+
+    if (!filePath) {
+      return null
+    }
+
+    try {
+      const savedPath = await invoke<string>('export_binary_file', {
+        filePath,
+        content,
+        format,
+      })
+      return savedPath
+    } catch (error) {
+      throw new Error(`文件保存失败: ${error}`)
+    }
+  }
+
+  /**
+   * 将 proto3 JSON 编码为 Protobuf 二进制并导出
+   * @returns 保存的文件路径，用户取消返回 null
+   */
+  async exportProtobufFile(
+    content: string,
+    descriptorPath: string,
+    messageType: string,
+    options?: ExportOptions
+  ): Promise<string | null> {
+    const filePath = await save({
+      title: options?.title || '保存 Protobuf 文件',
+      defaultPath: options?.defaultFileName || 'output.pb',
+      filters: options?.filters || [
+        {
+          name: 'Protobuf',
+          extensions: ['pb', 'binpb', 'bin'],
+        },
+      ],
+    })
+
+    if (!filePath) {
+      return null
+    }
+
+    try {
+      const savedPath = await invoke<string>('export_protobuf_file', {
+        filePath,
+        content,
+        descriptorPath,
+        messageType,
       })
       return savedPath
     } catch (error) {
       throw new Error(`文件保存失败: ${error}`)
     }
   }
+
+  /**
+   * 监视文件，内容变化或被删除时发送 `file-changed` 事件
+   * @returns 监视 ID
+   */
+  async watchFile(filePath: string, options?: WatchOptions): Promise<string> {
+    return invoke<string>('watch_file', { filePath, options })
+  }
+
+  /**
+   * 停止监视文件
+   * @returns 监视不存在或已停止时返回 false
+   */
+  async unwatchFile(watchId: string): Promise<boolean> {
+    return invoke<boolean>('unwatch_file', { watchId })
+  }
+
+  /**
+   * 订阅文件变化事件
+   * @returns 取消订阅函数
+   */
+  async onFileChanged(
+    callback: (event: FileChangeEvent) => void
+  ): Promise<UnlistenFn> {
+    return listen<FileChangeEvent>(FILE_CHANGED_EVENT, (event) =>
+      callback(event.payload)
+    )
+  }
+
+  /**
+   * 选择批量处理的文件夹
+   * @returns 文件夹路径，取消选择时返回 null
+   */
+  async selectFolder(title = '选择文件夹'): Promise<string | null> {
+    const selected = await open({ directory: true, multiple: false, title })
+    return typeof selected === 'string' ? selected : null
+  }
+
+  /**
+   * 批量验证、格式化或压缩文件夹中的 JSON 文件
+   * 进度通过 `operation-progress` 事件发送，可用 operationId 取消
+   */
+  async batchProcess(options: BatchOptions, operationId?: string): Promise<BatchReport> {
+    try {
+      return await invoke<BatchReport>('batch_process', { options, operationId })
+    } catch (error) {
+      throw new Error(`批量处理失败: ${error}`)
+    }
+  }
 }
 
 // 导出单例实例
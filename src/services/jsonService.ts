@@ -1,6 +1,21 @@
 import { invoke } from '@tauri-apps/api/core'
-import type { ValidationResult } from '../types/validation'
-import type { FormattingOptions, FormattingResult } from '../types/formatting'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import type { ValidationOptions, ValidationResult } from '../types/validation'
+import type { AnalysisOptions, AnalysisResult } from '../types/analysis'
+import type { LintConfigFile } from '../types/lint'
+import type {
+  EscapeOptions,
+  FormattingOptions,
+  FormattingResult,
+} from '../types/formatting'
+import type {
+  SequenceFormat,
+  SequenceValidationResult,
+} from '../types/sequence'
+import {
+  OPERATION_PROGRESS_EVENT,
+  type OperationProgress,
+} from '../types/operation'
 
 /**
  * JSON 验证与格式化服务
@@ -9,12 +24,20 @@ export class JsonValidationService {
   /**
    * 验证 JSON 字符串
    * @param input - 待验证的 JSON 字符串
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   * @param options - 验证选项（重复键策略、风格检查、严格模式）
    * @returns 验证结果
    */
-  async validateJson(input: string): Promise<ValidationResult> {
+  async validateJson(
+    input: string,
+    operationId?: string,
+    options?: ValidationOptions
+  ): Promise<ValidationResult> {
     try {
       const result = await invoke<ValidationResult>('validate_json', {
         input,
+        options,
+        operationId,
       })
       return result
     } catch (error) {
@@ -66,16 +89,19 @@ export class JsonValidationService {
    * 格式化 JSON 字符串
    * @param input - 待格式化的 JSON 字符串
    * @param options - 格式化选项
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
    * @returns 格式化结果
    */
   async formatJson(
     input: string,
-    options: FormattingOptions
+    options: FormattingOptions,
+    operationId?: string
   ): Promise<FormattingResult> {
     try {
       const result = await invoke<FormattingResult>('format_json', {
         input,
         options,
+        operationId,
       })
       return result
     } catch (error) {
@@ -90,12 +116,20 @@ export class JsonValidationService {
   /**
    * 压缩 JSON 字符串
    * @param input - 待压缩的 JSON 字符串
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   * @param options - 字符串转义选项
    * @returns 格式化结果
    */
-  async minifyJson(input: string): Promise<FormattingResult> {
+  async minifyJson(
+    input: string,
+    operationId?: string,
+    options?: EscapeOptions
+  ): Promise<FormattingResult> {
     try {
       const result = await invoke<FormattingResult>('minify_json', {
         input,
+        options,
+        operationId,
       })
       return result
     } catch (error) {
@@ -106,6 +140,107 @@ export class JsonValidationService {
       }
     }
   }
+
+  /**
+   * 验证多文档 JSON (首尾相接或 RFC 7464 序列)
+   * @param input - 待验证的文本
+   * @param format - 输入格式，不指定时自动检测
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   * @returns 各文档的验证结果
+   */
+  async validateSequence(
+    input: string,
+    format?: SequenceFormat,
+    operationId?: string
+  ): Promise<SequenceValidationResult> {
+    return invoke<SequenceValidationResult>('validate_json_sequence', {
+      input,
+      format,
+      operationId,
+    })
+  }
+
+  /**
+   * 格式化多文档 JSON
+   * @param input - 待格式化的文本
+   * @param options - 格式化选项
+   * @param format - 输入格式，不指定时自动检测
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   * @returns 格式化结果
+   */
+  async formatSequence(
+    input: string,
+    options: FormattingOptions,
+    format?: SequenceFormat,
+    operationId?: string
+  ): Promise<FormattingResult> {
+    try {
+      return await invoke<FormattingResult>('format_json_sequence', {
+        input,
+        options,
+        format,
+        operationId,
+      })
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return {
+        type: 'Error',
+        message: `系统错误: ${message}`,
+      }
+    }
+  }
+
+  /**
+   * 分析 JSON 结构（深度、类型分布、最大数组、最长字符串、重复键路径等）
+   * @param input - 待分析的 JSON 字符串
+   * @param options - 分析选项
+   * @returns 分析结果
+   */
+  async analyzeJson(input: string, options?: AnalysisOptions): Promise<AnalysisResult> {
+    try {
+      return await invoke<AnalysisResult>('analyze_json', { input, options })
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return {
+        type: 'Error',
+        message: `系统错误: ${message}`,
+      }
+    }
+  }
+
+  /**
+   * 从 JSON 文件所在目录向上查找项目检查配置（.jsonlint.json）
+   * @param filePath - JSON 文件路径
+   * @returns 找到的配置，没有配置文件时返回 null
+   */
+  async findLintConfig(filePath: string): Promise<LintConfigFile | null> {
+    return invoke<LintConfigFile | null>('find_lint_config', { filePath })
+  }
+
+  /**
+   * 取消正在运行的操作
+   * @param operationId - 操作 ID
+   * @returns 操作存在并已请求取消时返回 true
+   */
+  async cancelOperation(operationId: string): Promise<boolean> {
+    try {
+      return await invoke<boolean>('cancel_operation', { operationId })
+    } catch {
+      return false
+    }
+  }
+
+  /**
+   * 订阅操作进度事件
+   * @returns 取消订阅函数
+   */
+  async onOperationProgress(
+    callback: (progress: OperationProgress) => void
+  ): Promise<UnlistenFn> {
+    return listen<OperationProgress>(OPERATION_PROGRESS_EVENT, (event) =>
+      callback(event.payload)
+    )
+  }
 }
 
 // 导出单例
@@ -0,0 +1,105 @@
+/**
+ * JSON Lines / NDJSON 服务
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { FormattingOptions, FormattingResult } from '../types/formatting'
+import type { NdjsonOutputMode, NdjsonValidationResult } from '../types/ndjson'
+
+export class NdjsonService {
+  /**
+   * 逐行验证 NDJSON 文本
+   * @param input - NDJSON 文本
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   */
+  async validate(input: string, operationId?: string): Promise<NdjsonValidationResult> {
+    return invoke<NdjsonValidationResult>('validate_ndjson', { input, operationId })
+  }
+
+  /**
+   * 流式验证 NDJSON 文件
+   * @param filePath - 文件路径
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   */
+  async validateFile(filePath: string, operationId?: string): Promise<NdjsonValidationResult> {
+    return invoke<NdjsonValidationResult>('validate_ndjson_file', { filePath, operationId })
+  }
+
+  /**
+   * 格式化 NDJSON 文本
+   * @param input - NDJSON 文本
+   * @param options - 格式化选项
+   * @param mode - 输出模式
+   * @param operationId - 可选的操作 ID，用于取消操作和匹配进度事件
+   */
+  async format(
+    input: string,
+    options: FormattingOptions,
+    mode: NdjsonOutputMode,
+    operationId?: string
+  ): Promise<FormattingResult> {
+    try {
+      return await invoke<FormattingResult>('format_ndjson', {
+        input,
+        options,
+        mode,
+        operationId,
+      })
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` }
+    }
+  }
+
+  /**
+   * 将 JSON 数组转换为 NDJSON
+   * @param input - JSON 数组文本
+   */
+  async fromJsonArray(input: string): Promise<FormattingResult> {
+    try {
+      return await invoke<FormattingResult>('json_array_to_ndjson', { input })
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` }
+    }
+  }
+
+  /**
+   * 流式转换 NDJSON 文件
+   * @returns 写入的记录数
+   */
+  async convertFile(
+    inputPath: string,
+    outputPath: string,
+    mode: NdjsonOutputMode,
+    options: FormattingOptions,
+    operationId?: string
+  ): Promise<number> {
+    return invoke<number>('convert_ndjson_file', {
+      inputPath,
+      outputPath,
+      mode,
+      options,
+      operationId,
+    })
+  }
+
+  /**
+   * 流式将 JSON 数组文件转换为 NDJSON 文件 (每个元素一行)
+   * @returns 写入的记录数
+   */
+  async convertArrayFile(
+    inputPath: string,
+    outputPath: string,
+    operationId?: string
+  ): Promise<number> {
+    return invoke<number>('convert_array_file_to_ndjson', {
+      inputPath,
+      outputPath,
+      operationId,
+    })
+  }
+}
+
+// 导出单例
+export const ndjsonService = new NdjsonService()
@@ -0,0 +1,118 @@
+/**
+ * JSON 结构变换服务
+ * 扁平化、还原等不改变数据格式的结构变换
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ConversionResult } from '../types/conversion'
+import type {
+  Base64DecodeOptions,
+  ExpandOptions,
+  FlattenOptions,
+  JwtDecodeResult,
+  PercentDecodeOptions,
+  TransformResult,
+} from '../types/transform'
+
+export class TransformService {
+  /**
+   * 调用变换命令，将 IPC 错误包装为转换错误
+   */
+  private async transform<T extends ConversionResult | TransformResult | JwtDecodeResult>(
+    command: string,
+    args: Record<string, unknown>
+  ): Promise<T> {
+    try {
+      return await invoke<T>(command, args)
+    } catch (error) {
+      const message = error instanceof Error ? error.message : '未知错误'
+      return { type: 'Error', message: `系统错误: ${message}` } as T
+    }
+  }
+
+  /**
+   * 将嵌套 JSON 扁平化为路径键映射
+   */
+  async flattenJson(input: string, options?: FlattenOptions): Promise<ConversionResult> {
+    return this.transform<ConversionResult>('flatten_json', { input, options })
+  }
+
+  /**
+   * 将路径键映射还原为嵌套 JSON
+   */
+  async unflattenJson(input: string, options?: FlattenOptions): Promise<ConversionResult> {
+    return this.transform<ConversionResult>('unflatten_json', { input, options })
+  }
+
+  /**
+   * 展开值为 JSON 的字符串
+   * @param pointer - 只处理该节点下的值，默认整个文档
+   */
+  async expandEmbeddedJson(
+    input: string,
+    pointer?: string,
+    options?: ExpandOptions
+  ): Promise<TransformResult> {
+    return this.transform<TransformResult>('expand_embedded_json', { input, pointer, options })
+  }
+
+  /**
+   * 将子树序列化为 JSON 字符串
+   * @param pointer - 要序列化的节点
+   */
+  async stringifySubtree(
+    input: string,
+    pointer: string,
+    indent?: number
+  ): Promise<TransformResult> {
+    return this.transform<TransformResult>('stringify_json_subtree', { input, pointer, indent })
+  }
+
+  /**
+   * 将 Base64 / Base64URL 编码的字符串值解码
+   * @param pointer - 只处理该节点下的值，默认整个文档
+   */
+  async decodeBase64Strings(
+    input: string,
+    pointer?: string,
+    options?: Base64DecodeOptions
+  ): Promise<TransformResult> {
+    return this.transform<TransformResult>('decode_base64_strings', { input, pointer, options })
+  }
+
+  /**
+   * 将 URL 百分号编码的字符串值解码
+   * @param pointer - 只处理该节点下的值，默认整个文档
+   */
+  async percentDecodeStrings(
+    input: string,
+    pointer?: string,
+    options?: PercentDecodeOptions
+  ): Promise<TransformResult> {
+    return this.transform<TransformResult>('percent_decode_strings', { input, pointer, options })
+  }
+
+  /**
+   * 将字符串值中字面的 `\uXXXX` 转义还原为字符
+   */
+  async unescapeUnicode(input: string, pointer?: string, indent?: number): Promise<TransformResult> {
+    return this.transform<TransformResult>('unescape_unicode', { input, pointer, indent })
+  }
+
+  /**
+   * 将非 ASCII 字符转义为 `\uXXXX`
+   */
+  async escapeUnicode(input: string, indent?: number): Promise<TransformResult> {
+    return this.transform<TransformResult>('escape_unicode', { input, indent })
+  }
+
+  /**
+   * 解码 JWT 的头部和载荷 (不验证签名)
+   */
+  async decodeJwt(token: string, indent?: number): Promise<JwtDecodeResult> {
+    return this.transform<JwtDecodeResult>('decode_jwt', { token, indent })
+  }
+}
+
+// 导出单例
+export const transformService = new TransformService()
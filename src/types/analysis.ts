@@ -0,0 +1,76 @@
+/**
+ * JSON 结构分析类型定义
+ * 对应 Rust src-tauri/core/src/models/analysis.rs
+ */
+
+import type { TypeCounts } from './document'
+
+/**
+ * 结构分析选项
+ */
+export interface AnalysisOptions {
+  /** 各排行榜保留的条目数，默认 10 */
+  top_n?: number
+}
+
+/**
+ * 数组长度统计
+ */
+export interface ArrayStat {
+  pointer: string
+  length: number
+}
+
+/**
+ * 字符串长度统计
+ */
+export interface StringStat {
+  pointer: string
+  length: number
+  bytes: number
+  preview: string
+}
+
+/**
+ * 对象键出现次数统计
+ */
+export interface KeyStat {
+  key: string
+  count: number
+}
+
+/**
+ * 重复出现的键路径统计 (数组下标替换为 `*`)
+ */
+export interface KeyPathStat {
+  path: string
+  count: number
+  key_bytes: number
+}
+
+/**
+ * JSON 结构分析结果
+ */
+export type AnalysisResult =
+  | {
+      type: 'Success'
+      size: number
+      max_depth: number
+      node_count: number
+      type_counts: TypeCounts
+      total_keys: number
+      distinct_keys: number
+      top_keys: KeyStat[]
+      largest_arrays: ArrayStat[]
+      longest_strings: StringStat[]
+      repeated_paths: KeyPathStat[]
+      key_bytes: number
+      estimated_memory_bytes: number
+      processing_time_ms: number
+    }
+  | {
+      type: 'Error'
+      message: string
+      line?: number
+      column?: number
+    }
@@ -0,0 +1,66 @@
+/**
+ * 批量处理类型定义
+ * 对应 Rust src-tauri/core/src/models/batch.rs
+ */
+
+import type { FormattingOptions } from './formatting'
+import type { LintWarning } from './lint'
+import type { DuplicateKey, ValidationOptions } from './validation'
+
+/**
+ * 批量处理的操作
+ */
+export type BatchOperation = 'validate' | 'format' | 'minify'
+
+/**
+ * 批量处理选项
+ */
+export interface BatchOptions {
+  /** 要处理的文件夹 */
+  root: string
+  /** 包含的文件 (相对于 root 的 glob 模式)，为空时处理所有 .json 文件 */
+  patterns?: string[]
+  /** 排除的文件或目录 (glob 模式，如 node_modules) */
+  exclude?: string[]
+  operation: BatchOperation
+  /** 输出文件夹，按相对路径镜像输出；为空时原地覆盖 */
+  output_dir?: string | null
+  formatting?: Partial<FormattingOptions>
+  validation?: ValidationOptions
+  /** 并行线程数，默认使用 CPU 核数 */
+  threads?: number | null
+}
+
+/**
+ * 单个文件的处理结果
+ */
+export type BatchFileResult =
+  | {
+      type: 'Success'
+      path: string
+      output_path: string | null
+      changed: boolean
+      size: number
+      duplicate_keys: DuplicateKey[]
+      lint_warnings: LintWarning[]
+      warnings: string[]
+    }
+  | {
+      type: 'Error'
+      path: string
+      message: string
+      line: number | null
+      column: number | null
+    }
+
+/**
+ * 批量处理报告
+ */
+export interface BatchReport {
+  files: BatchFileResult[]
+  total: number
+  succeeded: number
+  failed: number
+  changed: number
+  processing_time_ms: number
+}
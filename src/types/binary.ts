@@ -0,0 +1,61 @@
+/**
+ * 二进制格式类型定义
+ * 对应 Rust src-tauri/core/src/models/binary.rs
+ */
+
+/**
+ * 二进制 JSON 等价格式
+ */
+export type BinaryFormat = 'msgpack' | 'cbor' | 'bson'
+
+/**
+ * 二进制数据的文本编码
+ */
+export type BinaryTextEncoding = 'base64' | 'hex'
+
+/**
+ * 无法用 JSON 精确表示的值的类别
+ */
+export type LossyKind =
+  | 'binary'
+  | 'extension'
+  | 'date'
+  | 'number'
+  | 'non_finite_float'
+  | 'key'
+  | 'unknown_field'
+
+/**
+ * 转换中有损的值
+ */
+export interface LossyValue {
+  path: string
+  kind: LossyKind
+  description: string
+}
+
+/**
+ * 二进制解码结果
+ */
+export type BinaryDecodeResult =
+  | {
+      type: 'Success'
+      output: string
+      size: number
+      processing_time_ms: number
+      lossy: LossyValue[]
+    }
+  | {
+      type: 'Error'
+      message: string
+    }
+
+/**
+ * Protobuf 转 JSON 选项 (proto3 规范 JSON 映射)
+ */
+export interface ProtobufJsonOptions {
+  use_proto_field_names?: boolean
+  use_enum_numbers?: boolean
+  emit_default_fields?: boolean
+  indent?: 2 | 4
+}
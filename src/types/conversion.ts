@@ -0,0 +1,178 @@
+/**
+ * 格式转换类型定义
+ * 对应 Rust src-tauri/core/src/models/conversion.rs
+ */
+
+/**
+ * 格式转换结果
+ */
+export type ConversionResult =
+  | {
+      type: 'Success'
+      output: string
+      size: number
+      processing_time_ms: number
+      warnings: string[]
+    }
+  | {
+      type: 'Error'
+      message: string
+      line?: number
+      column?: number
+    }
+
+/**
+ * YAML 输出风格
+ */
+export type YamlStyle = 'block' | 'flow'
+
+/**
+ * YAML 字符串引号策略
+ */
+export type YamlQuoting = 'auto' | 'single' | 'double'
+
+/**
+ * JSON 转 YAML 选项
+ */
+export interface JsonToYamlOptions {
+  style?: YamlStyle
+  quoting?: YamlQuoting
+  indent?: 2 | 4
+}
+
+/**
+ * YAML 多文档流的处理方式
+ */
+export type MultiDocumentHandling = 'array' | 'first' | 'error'
+
+/**
+ * YAML 标签的处理方式
+ */
+export type TagHandling = 'drop' | 'wrap' | 'error'
+
+/**
+ * YAML 转 JSON 选项
+ */
+export interface YamlToJsonOptions {
+  indent?: 2 | 4
+  multi_document?: MultiDocumentHandling
+  tags?: TagHandling
+  merge_keys?: boolean
+}
+
+/**
+ * TOML 嵌套对象的输出方式
+ */
+export type TomlTableStyle = 'standard' | 'inline'
+
+/**
+ * JSON null 的处理方式 (TOML 没有 null)
+ */
+export type NullHandling = 'error' | 'skip'
+
+/**
+ * JSON 转 TOML 选项
+ */
+export interface JsonToTomlOptions {
+  table_style?: TomlTableStyle
+  null_handling?: NullHandling
+  allow_mixed_arrays?: boolean
+}
+
+/**
+ * TOML 转 JSON 选项
+ */
+export interface TomlToJsonOptions {
+  indent?: 2 | 4
+}
+
+/**
+ * CSV 字段分隔符
+ */
+export type CsvDelimiter = 'comma' | 'tab' | 'semicolon' | 'pipe'
+
+/**
+ * CSV 字段引号策略
+ */
+export type CsvQuoting = 'necessary' | 'always' | 'non_numeric' | 'never'
+
+/**
+ * 列的排列顺序
+ */
+export type CsvHeaderOrder = 'first_seen' | 'alphabetical'
+
+/**
+ * 嵌套数组的展开方式
+ */
+export type CsvArrayHandling = 'json' | 'index'
+
+/**
+ * JSON 转 CSV 选项
+ */
+export interface JsonToCsvOptions {
+  delimiter?: CsvDelimiter
+  quoting?: CsvQuoting
+  header_order?: CsvHeaderOrder
+  /** 显式指定输出的列及顺序 (点号路径) */
+  columns?: string[]
+  array_handling?: CsvArrayHandling
+  include_header?: boolean
+}
+
+/**
+ * CSV 转 JSON 选项
+ */
+export interface CsvToJsonOptions {
+  delimiter?: CsvDelimiter
+  has_header?: boolean
+  infer_types?: boolean
+  unflatten?: boolean
+  indent?: 2 | 4
+}
+
+/**
+ * XML 子元素转为 JSON 数组的规则
+ */
+export type XmlArrayDetection = 'auto' | 'always'
+
+/**
+ * XML 命名空间的处理方式
+ */
+export type XmlNamespaceHandling = 'prefix' | 'strip' | 'expand'
+
+/**
+ * XML 转 JSON 选项
+ */
+export interface XmlToJsonOptions {
+  /** 属性键前缀，默认 `@` */
+  attribute_prefix?: string
+  /** 文本内容键，默认 `#text` */
+  text_key?: string
+  array_detection?: XmlArrayDetection
+  /** 始终转为数组的元素名 */
+  force_array?: string[]
+  namespaces?: XmlNamespaceHandling
+  infer_types?: boolean
+  indent?: 2 | 4
+}
+
+/**
+ * JSON 转 XML 选项
+ */
+export interface JsonToXmlOptions {
+  attribute_prefix?: string
+  text_key?: string
+  /** 根元素名称，默认 `root` */
+  root_name?: string
+  /** 嵌套数组元素名称，默认 `item` */
+  item_name?: string
+  declaration?: boolean
+  indent?: 0 | 2 | 4
+}
+
+// 类型守卫
+export function isConversionSuccess(
+  result: ConversionResult
+): result is Extract<ConversionResult, { type: 'Success' }> {
+  return result.type === 'Success'
+}
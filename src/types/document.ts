@@ -0,0 +1,83 @@
+/**
+ * 大文档树类型定义
+ * 对应 Rust src-tauri/core/src/models/document.rs
+ */
+
+/**
+ * JSON 节点类型
+ */
+export type JsonNodeType =
+  | 'object'
+  | 'array'
+  | 'string'
+  | 'number'
+  | 'boolean'
+  | 'null'
+
+/**
+ * 节点类型计数
+ */
+export type TypeCounts = Record<JsonNodeType, number>
+
+/**
+ * 树节点摘要 (不包含子节点内容)
+ */
+export interface NodeSummary {
+  /** 对象键 (父节点为对象时) */
+  key?: string
+
+  /** 数组下标 (父节点为数组时) */
+  index?: number
+
+  /** 节点的 JSON Pointer (RFC 6901) */
+  pointer: string
+
+  /** 节点类型 */
+  node_type: JsonNodeType
+
+  /** 子节点数量 (仅对象和数组) */
+  child_count?: number
+
+  /** 值预览 (标量为 JSON 文本，过长时截断) */
+  preview?: string
+
+  /** 预览是否被截断 */
+  truncated: boolean
+}
+
+/**
+ * 已加载文档的信息
+ */
+export interface DocumentInfo {
+  /** 文档 ID */
+  doc_id: string
+
+  /** 原始大小（字节） */
+  size: number
+
+  /** 根节点摘要 */
+  root: NodeSummary
+
+  /** 处理时间（毫秒） */
+  processing_time_ms: number
+}
+
+/**
+ * 子节点分页结果
+ */
+export interface ChildrenPage {
+  /** 父节点的 JSON Pointer */
+  pointer: string
+
+  /** 子节点总数 */
+  total: number
+
+  /** 本页起始偏移 */
+  offset: number
+
+  /** 本页子节点 */
+  children: NodeSummary[]
+
+  /** 全部直接子节点的类型统计 */
+  child_types: TypeCounts
+}
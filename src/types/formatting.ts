@@ -1,7 +1,18 @@
+import type { DuplicateKeyPolicy } from './validation'
+
+// 字符串转义选项（格式化与压缩共用）
+export interface EscapeOptions {
+  ascii_only?: boolean
+  escape_slash?: boolean
+  escape_html?: boolean
+  uppercase_hex?: boolean
+}
+
 // 格式化选项
-export interface FormattingOptions {
+export interface FormattingOptions extends EscapeOptions {
   indent: 2 | 4
   trailing_newline: boolean
+  duplicate_keys?: DuplicateKeyPolicy
 }
 
 // 格式化结果
@@ -16,6 +27,9 @@ export type FormattingResult =
       type: 'Error'
       message: string
     }
+  | {
+      type: 'Cancelled'
+    }
 
 // 类型守卫
 export function isFormattingSuccess(
@@ -29,3 +43,9 @@ export function isFormattingError(
 ): result is Extract<FormattingResult, { type: 'Error' }> {
   return result.type === 'Error'
 }
+
+export function isFormattingCancelled(
+  result: FormattingResult
+): result is Extract<FormattingResult, { type: 'Cancelled' }> {
+  return result.type === 'Cancelled'
+}
@@ -0,0 +1,51 @@
+/**
+ * JSON 风格检查类型定义
+ * 对应 Rust src-tauri/core/src/models/lint.rs
+ */
+
+/**
+ * 对象键的命名风格
+ */
+export type KeyNamingConvention = 'camel_case' | 'snake_case'
+
+/**
+ * 检查规则配置（可写在项目目录的 .jsonlint.json 中），未配置的规则不启用
+ */
+export interface LintConfig {
+  key_naming?: KeyNamingConvention
+  max_depth?: number
+  empty_containers?: boolean
+  mixed_type_arrays?: boolean
+  numbers_as_strings?: boolean
+  max_string_length?: number
+  unicode_nfc?: boolean
+}
+
+/**
+ * 检查规则
+ */
+export type LintRule =
+  | 'key_naming'
+  | 'max_depth'
+  | 'empty_container'
+  | 'mixed_type_array'
+  | 'number_as_string'
+  | 'long_string'
+  | 'unicode_nfc'
+
+/**
+ * 检查警告
+ */
+export interface LintWarning {
+  rule: LintRule
+  pointer: string
+  message: string
+}
+
+/**
+ * 从项目目录找到的检查配置
+ */
+export interface LintConfigFile {
+  path: string
+  config: LintConfig
+}
@@ -1,17 +1,17 @@
 /**
  * 日志类型定义
- * 对应 Rust src-tauri/src/models/log_entry.rs
+ * 对应 Rust src-tauri/core/src/models/log_entry.rs
  */
 
 /**
  * 操作类型
  */
-export type OperationType = 'validate' | 'format' | 'minify'
+export type OperationType = 'validate' | 'format' | 'minify' | 'load' | 'batch'
 
 /**
  * 操作结果
  */
-export type OperationResult = 'success' | 'error'
+export type OperationResult = 'success' | 'error' | 'cancelled'
 
 /**
  * 日志条目
@@ -52,7 +52,10 @@ export interface LogStatistics {
   /** 失败数 */
   error_count: number
 
-  /** 成功率 (0-100) */
+  /** 取消数 */
+  cancelled_count: number
+
+  /** 成功率 (0-100，不计入已取消的操作) */
   success_rate: number
 
   /** 验证操作数 */
@@ -81,6 +84,8 @@ export const OPERATION_TEXT: Record<OperationType, string> = {
   validate: '验证',
   format: '格式化',
   minify: '压缩',
+  load: '加载文档',
+  batch: '批量处理',
 }
 
 /**
@@ -89,4 +94,5 @@ export const OPERATION_TEXT: Record<OperationType, string> = {
 export const RESULT_TEXT: Record<OperationResult, string> = {
   success: '成功',
   error: '失败',
+  cancelled: '已取消',
 }
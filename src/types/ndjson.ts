@@ -0,0 +1,49 @@
+/**
+ * JSON Lines / NDJSON 类型定义
+ * 对应 Rust src-tauri/core/src/models/ndjson.rs
+ */
+
+/**
+ * NDJSON 单行错误
+ */
+export interface LineError {
+  /** 行号（从 1 开始） */
+  line: number
+
+  /** 列号（从 1 开始） */
+  column?: number
+
+  /** 错误消息 */
+  message: string
+}
+
+/**
+ * NDJSON 验证结果
+ */
+export interface NdjsonValidationResult {
+  /** 记录总数 (不含空行) */
+  total_records: number
+
+  /** 有效记录数 */
+  valid_records: number
+
+  /** 各行的错误 (最多保留前 1000 条) */
+  errors: LineError[]
+
+  /** 错误是否因数量过多被截断 */
+  errors_truncated: boolean
+
+  /** 输入大小（字节） */
+  size: number
+
+  /** 处理时间（毫秒） */
+  processing_time_ms: number
+}
+
+/**
+ * NDJSON 输出模式
+ * - pretty: 逐条美化输出
+ * - minify: 每条记录压缩为一行
+ * - json_array: 转换为 JSON 数组
+ */
+export type NdjsonOutputMode = 'pretty' | 'minify' | 'json_array'
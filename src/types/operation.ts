@@ -0,0 +1,25 @@
+/**
+ * 长耗时操作类型定义
+ * 对应 Rust src-tauri/core/src/models/operation.rs
+ */
+
+import type { OperationType } from './logging'
+
+/**
+ * 操作进度事件名称
+ */
+export const OPERATION_PROGRESS_EVENT = 'operation-progress'
+
+/**
+ * 操作进度事件
+ */
+export interface OperationProgress {
+  /** 操作 ID */
+  operation_id: string
+
+  /** 操作类型 */
+  operation: OperationType
+
+  /** 完成百分比 (0-100) */
+  percent: number
+}
@@ -0,0 +1,53 @@
+/**
+ * 多文档 JSON 类型定义
+ * 对应 Rust src-tauri/core/src/models/sequence.rs
+ */
+
+import type { ValidationResult } from './validation'
+
+/**
+ * 多文档输入格式
+ * - concatenated: 首尾相接的多个 JSON 值
+ * - json_seq: RFC 7464 JSON 文本序列
+ */
+export type SequenceFormat = 'concatenated' | 'json_seq'
+
+/**
+ * 单个文档的验证结果
+ */
+export interface SequenceDocument {
+  /** 文档序号（从 0 开始） */
+  index: number
+
+  /** 文档起始行号（从 1 开始） */
+  line: number
+
+  /** 文档起始列号（从 1 开始） */
+  column: number
+
+  /** 验证结果 (错误位置相对于整个输入) */
+  result: ValidationResult
+}
+
+/**
+ * 多文档验证结果
+ */
+export interface SequenceValidationResult {
+  /** 实际使用的输入格式 */
+  format: SequenceFormat
+
+  /** 文档总数 */
+  total_documents: number
+
+  /** 有效文档数 */
+  valid_documents: number
+
+  /** 各文档的验证结果 */
+  documents: SequenceDocument[]
+
+  /** 输入大小（字节） */
+  size: number
+
+  /** 处理时间（毫秒） */
+  processing_time_ms: number
+}
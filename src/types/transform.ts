@@ -0,0 +1,90 @@
+/**
+ * JSON 结构变换类型定义
+ * 对应 Rust src-tauri/core/src/models/transform.rs
+ */
+
+/**
+ * 扁平化路径中数组下标的表示方式
+ * - brackets: `a.b[0].c`
+ * - separator: `a.b.0.c`
+ */
+export type ArrayNotation = 'brackets' | 'separator'
+
+/**
+ * 扁平化 / 还原选项
+ */
+export interface FlattenOptions {
+  separator?: string
+  array_notation?: ArrayNotation
+  indent?: 2 | 4
+}
+
+/**
+ * 展开嵌入 JSON 字符串的选项
+ */
+export interface ExpandOptions {
+  recursive?: boolean
+  only_containers?: boolean
+  indent?: 2 | 4
+}
+
+/**
+ * 结构变换结果
+ */
+export type TransformResult =
+  | {
+      type: 'Success'
+      output: string
+      size: number
+      processing_time_ms: number
+      changed: string[]
+    }
+  | {
+      type: 'Error'
+      message: string
+      line?: number
+      column?: number
+    }
+
+/**
+ * Base64 字符串解码选项
+ */
+export interface Base64DecodeOptions {
+  decode_text?: boolean
+  indent?: 2 | 4
+}
+
+/**
+ * URL 百分号解码选项
+ */
+export interface PercentDecodeOptions {
+  plus_as_space?: boolean
+  indent?: 2 | 4
+}
+
+/**
+ * JWT 中的时间声明 (exp / iat / nbf)
+ */
+export interface JwtTimestamp {
+  claim: string
+  value: number
+  datetime: string
+}
+
+/**
+ * JWT 解码结果 (不验证签名)
+ */
+export type JwtDecodeResult =
+  | {
+      type: 'Success'
+      header: string
+      payload: string
+      signature: string
+      algorithm?: string
+      timestamps: JwtTimestamp[]
+      expired?: boolean
+    }
+  | {
+      type: 'Error'
+      message: string
+    }
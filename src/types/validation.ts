@@ -1,3 +1,5 @@
+import type { LintConfig, LintWarning } from './lint'
+
 /**
  * 错误上下文信息
  */
@@ -9,6 +11,43 @@ export interface ErrorContext {
   suggestion?: string    // 修复建议
 }
 
+/**
+ * 重复键的处理策略
+ * - error: 视为错误
+ * - keep_first: 保留第一次出现的值
+ * - keep_last: 保留最后一次出现的值（默认）
+ */
+export type DuplicateKeyPolicy = 'error' | 'keep_first' | 'keep_last'
+
+/**
+ * 重复的对象键及两次出现的位置
+ */
+export interface DuplicateKey {
+  key: string
+  pointer: string
+  first_line: number
+  first_column: number
+  line: number
+  column: number
+}
+
+/**
+ * 严格模式
+ * - off: 只按解析器的规则验证（默认）
+ * - rfc8259: 拒绝 BOM、孤立代理项和超出双精度范围的数字
+ * - ijson: 在 rfc8259 的基础上拒绝 Unicode 非字符、超出 ±(2^53-1) 的整数和重复键 (RFC 7493)
+ */
+export type StrictMode = 'off' | 'rfc8259' | 'ijson'
+
+/**
+ * 验证选项
+ */
+export interface ValidationOptions {
+  duplicate_keys?: DuplicateKeyPolicy  // 重复键的处理策略，默认 keep_last
+  lint?: LintConfig                    // 风格检查配置，不指定时不检查
+  strict?: StrictMode                  // 严格模式，默认 off
+}
+
 /**
  * JSON 验证结果类型
  */
@@ -18,6 +57,8 @@ export type ValidationResult =
       data: unknown
       size: number
       processing_time_ms: number
+      duplicate_keys?: DuplicateKey[]
+      warnings?: LintWarning[]
     }
   | {
       type: 'Error'
@@ -26,6 +67,9 @@ export type ValidationResult =
       column?: number
       context?: ErrorContext
     }
+  | {
+      type: 'Cancelled'
+    }
 
 /**
  * JSON 验证错误
@@ -55,3 +99,12 @@ export function isError(
 ): result is Extract<ValidationResult, { type: 'Error' }> {
   return result.type === 'Error'
 }
+
+/**
+ * 判断验证是否被取消
+ */
+export function isCancelled(
+  result: ValidationResult
+): result is Extract<ValidationResult, { type: 'Cancelled' }> {
+  return result.type === 'Cancelled'
+}